@@ -0,0 +1,202 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter},
+    path::{Path, PathBuf},
+};
+
+use elves::{input::workspace_dir, DayId, Part, Report};
+use serde::Deserialize;
+
+/// The answers we've submitted and had accepted, keyed by year and then day, as in
+/// `[2024.17]`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    years: BTreeMap<String, BTreeMap<String, DayAnswers>>,
+}
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DayAnswers {
+    one: Option<String>,
+    two: Option<String>,
+}
+impl DayAnswers {
+    fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.one.as_deref(),
+            Part::Two => self.two.as_deref(),
+        }
+    }
+}
+impl Answers {
+    /// `answers.toml` at the root of the workspace.
+    pub fn default_path() -> PathBuf {
+        workspace_dir().join("answers.toml")
+    }
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|error| format!("could not read {}: {}", path.display(), error))?;
+        Answers::parse(&contents).map_err(|error| format!("{}: {}", path.display(), error))
+    }
+    pub fn parse(contents: &str) -> Result<Answers, String> {
+        let answers: Answers = toml::from_str(contents).map_err(|error| error.to_string())?;
+        for (year, days) in &answers.years {
+            if year.parse::<u16>().is_err() {
+                return Err(format!("`{}` is not a year", year));
+            }
+            if let Some(day) = days
+                .keys()
+                .find(|day| !matches!(day.parse::<u8>(), Ok(1..=25)))
+            {
+                return Err(format!("`{}.{}` is not a day between 1 and 25", year, day));
+            }
+        }
+        Ok(answers)
+    }
+    pub fn get(&self, id: DayId, part: Part) -> Option<&str> {
+        self.years
+            .get(&id.year.to_string())?
+            .get(&id.day.to_string())?
+            .get(part)
+    }
+    /// Compares every part in the report against what was recorded for it.
+    pub fn check(&self, report: &Report) -> Vec<Check> {
+        report
+            .parts
+            .iter()
+            .map(|part| {
+                let status = match self.get(report.id, part.part) {
+                    None => Status::Missing,
+                    Some(expected) if expected == part.answer => Status::Pass,
+                    Some(expected) => Status::Fail {
+                        expected: expected.to_string(),
+                    },
+                };
+                Check {
+                    day: report.id,
+                    part: Some(part.part),
+                    answer: part.answer.clone(),
+                    status,
+                }
+            })
+            .collect()
+    }
+}
+
+/// One row of the `aoc verify` table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub day: DayId,
+    /// `None` when the whole day was skipped.
+    pub part: Option<Part>,
+    pub answer: String,
+    pub status: Status,
+}
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// Nothing recorded for this part yet.
+    Missing,
+    /// The day couldn't run, e.g. because its input isn't on disk.
+    Skipped(String),
+    /// The solution rejected its input or found no answer.
+    Error(String),
+    /// The solution panicked before giving an answer.
+    Panicked(String),
+}
+impl Check {
+    pub fn header() -> String {
+        format!("{:<7}  {:<4}  {:<7}  {}", "Day", "Part", "Status", "Answer")
+    }
+}
+impl Display for Check {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let part = self.part.map(|part| part.to_string()).unwrap_or_default();
+        let (status, detail) = match &self.status {
+            Status::Pass => ("pass", self.answer.clone()),
+            Status::Fail { expected } => {
+                ("FAIL", format!("{} (expected {})", self.answer, expected))
+            }
+            Status::Missing => ("missing", self.answer.clone()),
+            Status::Skipped(reason) => ("skipped", reason.clone()),
+            Status::Error(message) => ("ERROR", message.clone()),
+            Status::Panicked(message) => ("PANIC", message.clone()),
+        };
+        write!(
+            f,
+            "{:<7}  {:<4}  {:<7}  {}",
+            self.day.to_string(),
+            part,
+            status,
+            detail
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use elves::solution::PartReport;
+    use std::time::Duration;
+
+    const ANSWERS: &str = r#"
+[2024.1]
+one = "11"
+two = "31"
+
+[2024.17]
+one = "4,6,3,5,6,3,5,2,1,0"
+"#;
+
+    fn report(day: u8, answers: &[(Part, &str)]) -> Report {
+        Report {
+            id: DayId::new(2024, day),
+            parse: Duration::ZERO,
+            parts: answers
+                .iter()
+                .map(|(part, answer)| PartReport {
+                    part: *part,
+                    answer: answer.to_string(),
+                    elapsed: Duration::ZERO,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn reads_answers() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        let day = |day| DayId::new(2024, day);
+        assert_eq!(answers.get(day(1), Part::Two), Some("31"));
+        assert_eq!(answers.get(day(17), Part::Two), None);
+        assert_eq!(answers.get(day(2), Part::One), None);
+        assert_eq!(answers.get(DayId::new(2023, 1), Part::One), None);
+        assert!(Answers::parse("[2024.1]\nthree = \"3\"").is_err());
+        assert!(Answers::parse("[2024.one]\none = \"3\"").is_err());
+        assert!(Answers::parse("[this.1]\none = \"3\"").is_err());
+        assert!(Answers::parse("[1]\none = \"3\"").is_err());
+    }
+
+    #[test]
+    fn checks_reports() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        let checks = answers.check(&report(1, &[(Part::One, "11"), (Part::Two, "30")]));
+        assert_eq!(checks[0].status, Status::Pass);
+        assert_eq!(
+            checks[1].status,
+            Status::Fail {
+                expected: "31".to_string()
+            }
+        );
+        assert_eq!(
+            checks[1].to_string(),
+            "2024/01  two   FAIL     30 (expected 31)"
+        );
+
+        let checks = answers.check(&report(17, &[(Part::Two, "117440")]));
+        assert_eq!(checks[0].status, Status::Missing);
+    }
+}
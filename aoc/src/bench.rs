@@ -0,0 +1,267 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use elves::{input::workspace_dir, DayId, Part, Runnable};
+use serde::{Deserialize, Serialize};
+
+/// How much slower than the baseline, in percent, a phase may get before it's flagged.
+pub const DEFAULT_THRESHOLD_PERCENT: f64 = 10.0;
+
+/// Timings summarised over every sample of one phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    #[serde(with = "nanos")]
+    pub median: Duration,
+    #[serde(with = "nanos")]
+    pub p95: Duration,
+    pub samples: usize,
+}
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort();
+        let percentile = |p: usize| samples[(samples.len() * p).div_ceil(100).max(1) - 1];
+        Some(Stats {
+            median: percentile(50),
+            p95: percentile(95),
+            samples: samples.len(),
+        })
+    }
+}
+
+/// Stats for a day's parse, part one and part two, keyed by phase name.
+pub type DayTimings = BTreeMap<String, Stats>;
+
+/// Runs the day `warmup` times without measuring, then `samples` more times.
+pub fn bench(
+    solution: &dyn Runnable,
+    input: &str,
+    warmup: usize,
+    samples: usize,
+) -> Result<DayTimings, elves::Error> {
+    for _ in 0..warmup {
+        solution.run(input, None)?;
+    }
+    let mut phases: BTreeMap<String, Vec<Duration>> = BTreeMap::new();
+    for _ in 0..samples {
+        let report = solution.run(input, None)?;
+        phases
+            .entry("parse".to_string())
+            .or_default()
+            .push(report.parse);
+        for part in report.parts {
+            phases
+                .entry(phase_name(part.part))
+                .or_default()
+                .push(part.elapsed);
+        }
+    }
+    Ok(phases
+        .into_iter()
+        .filter_map(|(phase, samples)| Some((phase, Stats::from_samples(samples)?)))
+        .collect())
+}
+
+fn phase_name(part: Part) -> String {
+    format!("part {}", part)
+}
+
+/// Saved timings to compare later runs against, keyed by day, as in `2024/07`.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Baseline {
+    days: BTreeMap<String, DayTimings>,
+}
+impl Baseline {
+    /// Kept out of the repository, timings only mean something on the machine they came from.
+    pub fn default_path() -> PathBuf {
+        workspace_dir().join(".cache").join("bench-baseline.json")
+    }
+    pub fn load(path: &Path) -> Result<Baseline, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|error| format!("could not read {}: {}", path.display(), error))?;
+        serde_json::from_str(&contents).map_err(|error| format!("{}: {}", path.display(), error))
+    }
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let write = || -> std::io::Result<()> {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, serde_json::to_string_pretty(self)?)
+        };
+        write().map_err(|error| format!("could not write {}: {}", path.display(), error))
+    }
+    pub fn get(&self, day: DayId) -> Option<&DayTimings> {
+        self.days.get(&day.to_string())
+    }
+    pub fn insert(&mut self, day: DayId, timings: DayTimings) {
+        self.days.insert(day.to_string(), timings);
+    }
+}
+
+/// One row of the `aoc bench` table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: DayId,
+    pub phase: String,
+    pub stats: Stats,
+    pub baseline: Option<Stats>,
+    pub threshold: f64,
+}
+impl Comparison {
+    /// Relative change of the median against the baseline, `0.25` being 25% slower.
+    pub fn change(&self) -> Option<f64> {
+        let baseline = self.baseline?.median.as_secs_f64();
+        (baseline > 0.0).then(|| self.stats.median.as_secs_f64() / baseline - 1.0)
+    }
+    pub fn regressed(&self) -> bool {
+        self.change().is_some_and(|change| change > self.threshold)
+    }
+    pub fn header() -> String {
+        format!(
+            "{:<7}  {:<8}  {:>12}  {:>12}  {:>12}  {}",
+            "Day", "Phase", "Median", "p95", "Baseline", "Change"
+        )
+    }
+}
+impl Display for Comparison {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let baseline = self
+            .baseline
+            .map(|baseline| format!("{:?}", baseline.median))
+            .unwrap_or_else(|| "-".to_string());
+        write!(
+            f,
+            "{:<7}  {:<8}  {:>12}  {:>12}  {:>12}",
+            self.day.to_string(),
+            self.phase,
+            format!("{:?}", self.stats.median),
+            format!("{:?}", self.stats.p95),
+            baseline
+        )?;
+        if let Some(change) = self.change() {
+            write!(f, "  {:+.1}%", change * 100.0)?;
+            if self.regressed() {
+                write!(f, " SLOWER")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn compare(
+    day: DayId,
+    timings: &DayTimings,
+    baseline: Option<&DayTimings>,
+    threshold: f64,
+) -> Vec<Comparison> {
+    timings
+        .iter()
+        .map(|(phase, stats)| Comparison {
+            day,
+            phase: phase.clone(),
+            stats: *stats,
+            baseline: baseline.and_then(|baseline| baseline.get(phase)).copied(),
+            threshold,
+        })
+        .collect()
+}
+
+/// Durations are stored as whole nanoseconds so the JSON stays readable.
+mod nanos {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos().try_into().unwrap_or(u64::MAX))
+    }
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use elves::{Result, Solution, Unsolved};
+
+    struct Counting;
+    impl Solution for Counting {
+        const YEAR: u16 = 2024;
+        const DAY: u8 = 4;
+        type Input = usize;
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.len())
+        }
+        fn part_one(input: &Self::Input) -> Result<impl Display> {
+            Ok(input * 2)
+        }
+        fn part_two(_input: &Self::Input) -> Result<impl Display> {
+            Ok(Unsolved)
+        }
+    }
+
+    fn millis(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn median_and_p95() {
+        let stats = Stats::from_samples(millis(&[5, 1, 4, 2, 3])).unwrap();
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(5));
+        assert_eq!(stats.samples, 5);
+
+        let stats = Stats::from_samples(millis(&(1..=100).collect::<Vec<_>>())).unwrap();
+        assert_eq!(stats.median, Duration::from_millis(50));
+        assert_eq!(stats.p95, Duration::from_millis(95));
+        assert_eq!(Stats::from_samples(vec![]), None);
+    }
+
+    #[test]
+    fn benches_every_phase() {
+        let timings = bench(&Counting, "XMAS", 1, 3).unwrap();
+        let phases: Vec<&str> = timings.keys().map(String::as_str).collect();
+        assert_eq!(phases, ["parse", "part one", "part two"]);
+        assert!(timings.values().all(|stats| stats.samples == 3));
+    }
+
+    #[test]
+    fn flags_regressions_against_baseline() {
+        let stats = |median| Stats {
+            median: Duration::from_millis(median),
+            p95: Duration::from_millis(median),
+            samples: 1,
+        };
+        let mut baseline = Baseline::default();
+        baseline.insert(
+            DayId::new(2024, 7),
+            DayTimings::from([("part one".to_string(), stats(100))]),
+        );
+        let baseline: Baseline =
+            serde_json::from_str(&serde_json::to_string(&baseline).unwrap()).unwrap();
+
+        let timings = DayTimings::from([
+            ("parse".to_string(), stats(1)),
+            ("part one".to_string(), stats(150)),
+        ]);
+        let day = DayId::new(2024, 7);
+        let rows = compare(
+            day,
+            &timings,
+            baseline.get(day),
+            DEFAULT_THRESHOLD_PERCENT / 100.0,
+        );
+        assert_eq!(rows[0].change(), None);
+        assert!(!rows[0].regressed());
+        assert!((rows[1].change().unwrap() - 0.5).abs() < 1e-9);
+        assert!(rows[1].regressed());
+        assert!(rows[1].to_string().ends_with("+50.0% SLOWER"));
+    }
+}
@@ -0,0 +1,182 @@
+use std::{
+    fmt::{Display, Formatter},
+    path::PathBuf,
+};
+
+use elves::{DayId, InputCache, InputError};
+
+/// Environment variable holding the adventofcode.com `session` cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Overrides the site inputs are downloaded from, e.g. to point at a local stub server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Gets a puzzle input over the network. Tests swap in their own instead of the real site.
+pub trait Transport {
+    fn get_input(&self, day: DayId, session: &str) -> Result<String, FetchError>;
+}
+
+/// Downloads inputs over HTTP from `base_url`.
+pub struct HttpTransport {
+    agent: ureq::Agent,
+    base_url: String,
+}
+impl HttpTransport {
+    pub fn new(base_url: impl Into<String>) -> HttpTransport {
+        HttpTransport {
+            agent: ureq::Agent::new_with_defaults(),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+    /// Talks to `$AOC_BASE_URL`, or adventofcode.com itself.
+    pub fn from_env() -> HttpTransport {
+        HttpTransport::new(
+            std::env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string()),
+        )
+    }
+}
+impl Transport for HttpTransport {
+    fn get_input(&self, day: DayId, session: &str) -> Result<String, FetchError> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, day.year, day.day);
+        self.agent
+            .get(&url)
+            .header("Cookie", &format!("session={}", session))
+            .call()
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|error| match error {
+                ureq::Error::StatusCode(status) => FetchError::Status { url, status },
+                error => FetchError::Http {
+                    url,
+                    error: error.to_string(),
+                },
+            })
+    }
+}
+
+/// Returns where the day's input is cached, downloading it first if it isn't there yet.
+pub fn fetch(
+    day: DayId,
+    session: Option<&str>,
+    transport: &dyn Transport,
+    cache: &InputCache,
+) -> Result<PathBuf, FetchError> {
+    if cache.get(day).is_some() {
+        return Ok(cache.path(day));
+    }
+    let session = session.ok_or(FetchError::MissingSession)?;
+    let input = transport.get_input(day, session)?;
+    Ok(cache.store(day, &input)?)
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// Neither `--session` nor `$AOC_SESSION` was given.
+    MissingSession,
+    /// The server answered, but not with the input.
+    Status {
+        url: String,
+        status: u16,
+    },
+    Http {
+        url: String,
+        error: String,
+    },
+    Cache(InputError),
+}
+impl From<InputError> for FetchError {
+    fn from(error: InputError) -> Self {
+        FetchError::Cache(error)
+    }
+}
+impl Display for FetchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::MissingSession => {
+                write!(f, "no session token, pass --session or set {}", SESSION_VAR)
+            }
+            FetchError::Status { url, status } => write!(f, "{} answered {}", url, status),
+            FetchError::Http { url, error } => write!(f, "could not fetch {}: {}", url, error),
+            FetchError::Cache(error) => write!(f, "{}", error),
+        }
+    }
+}
+impl std::error::Error for FetchError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        cell::Cell,
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+    };
+
+    struct FakeTransport {
+        calls: Cell<usize>,
+    }
+    impl Transport for FakeTransport {
+        fn get_input(&self, day: DayId, session: &str) -> Result<String, FetchError> {
+            self.calls.set(self.calls.get() + 1);
+            Ok(format!("{} {}", day, session))
+        }
+    }
+
+    fn temp_cache(name: &str) -> (PathBuf, InputCache) {
+        let dir = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        (dir.clone(), InputCache::new(dir))
+    }
+
+    #[test]
+    fn fetches_each_input_once() {
+        let (dir, cache) = temp_cache("fetch");
+        let transport = FakeTransport {
+            calls: Cell::new(0),
+        };
+        let day = DayId::new(2024, 5);
+        let path = fetch(day, Some("cookie"), &transport, &cache).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "2024/05 cookie");
+        // Cached now, so no session or network is needed
+        assert_eq!(fetch(day, None, &transport, &cache).unwrap(), path);
+        assert_eq!(transport.calls.get(), 1);
+        assert!(matches!(
+            fetch(DayId::new(2024, 6), None, &transport, &cache),
+            Err(FetchError::MissingSession)
+        ));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn downloads_from_stub_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            for line in BufReader::new(&stream).lines() {
+                let line = line.unwrap();
+                if line.is_empty() {
+                    break;
+                }
+                request.push(line);
+            }
+            let body = "3   4\n4   3\n";
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .unwrap();
+            request
+        });
+
+        let transport = HttpTransport::new(format!("http://{}/", address));
+        let input = transport.get_input(DayId::new(2024, 1), "cookie").unwrap();
+        assert_eq!(input, "3   4\n4   3\n");
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "GET /2024/day/1/input HTTP/1.1");
+        assert!(request
+            .iter()
+            .any(|line| line.eq_ignore_ascii_case("cookie: session=cookie")));
+    }
+}
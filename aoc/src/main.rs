@@ -0,0 +1,698 @@
+mod answers;
+mod bench;
+mod fetch;
+mod output;
+mod registry;
+mod scaffold;
+
+use answers::{Answers, Check, Status};
+use bench::{Baseline, Comparison};
+use clap::{ArgAction, Parser, Subcommand};
+use elves::{
+    config::{self, Config},
+    input::workspace_dir,
+    profile, Animation, DayId, InputCache, InputSource, Part, Render,
+};
+use output::{Format, Record};
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+/// Counts allocations for `aoc profile`.
+#[global_allocator]
+static ALLOCATOR: profile::CountingAllocator = profile::CountingAllocator;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Threads for the days that split their work up, one per core by default
+    #[arg(long, global = true)]
+    jobs: Option<usize>,
+    /// Log what the days are doing to stderr, `-vv` for every step
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+    /// Puzzle parameters for each day, aoc.toml at the root of the workspace by default
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+    /// Override one day's parameter, like `--set 2024/14.width=11` or `--set 14.width=11`
+    #[arg(long = "set", global = true, value_name = "DAY.KEY=VALUE", value_parser = parse_setting)]
+    settings: Vec<Setting>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one or more days and print their answers
+    Run {
+        /// Days to run: `2024/17`, a range like `2024/1-5`, a whole year like `2024`, or
+        /// `all`. Days without a year, like `17` or `1-5`, are from the latest year
+        #[arg(required = true, value_parser = parse_days)]
+        days: Vec<Vec<DayId>>,
+        /// Only run this part
+        #[arg(long, value_parser = parse_part)]
+        part: Option<Part>,
+        /// Read the input from this file instead, `-` for stdin. Needs a single day
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Print a table, or one record per day and part for scripts
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Check every day's answers against the ones recorded in answers.toml
+    Verify {
+        /// Days to check, all of them by default
+        #[arg(value_parser = parse_days)]
+        days: Vec<Vec<DayId>>,
+        /// Recorded answers to compare against
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Look for malformed lines in a day's input without solving it, reporting every one
+    Check {
+        #[arg(value_parser = parse_day)]
+        day: DayId,
+        /// The input to check, `-` for stdin. Defaults to the day's own input
+        file: Option<PathBuf>,
+    },
+    /// Run a day once and show where its time and memory went
+    Profile {
+        #[arg(value_parser = parse_day)]
+        day: DayId,
+        /// Only run this part
+        #[arg(long, value_parser = parse_part)]
+        part: Option<Part>,
+        /// Read the input from this file instead, `-` for stdin
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Print a made-up input for a day, the same one every time for the same seed and size
+    Gen {
+        #[arg(value_parser = parse_day)]
+        day: DayId,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Roughly how big: the number of lines, or the side of a grid
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+    },
+    /// Time each day's parse and parts over repeated runs and compare against a baseline
+    Bench {
+        /// Days to time, all of them by default
+        #[arg(value_parser = parse_days)]
+        days: Vec<Vec<DayId>>,
+        /// Untimed runs before sampling starts
+        #[arg(long, default_value_t = 2)]
+        warmup: usize,
+        /// Timed runs per day
+        #[arg(long, default_value_t = 10)]
+        samples: usize,
+        /// Baseline JSON to compare against, defaults to .cache/bench-baseline.json
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Save these timings as the new baseline
+        #[arg(long)]
+        save: bool,
+        /// Percent slower than the baseline median that counts as a regression
+        #[arg(long, default_value_t = bench::DEFAULT_THRESHOLD_PERCENT)]
+        threshold: f64,
+    },
+    /// Watch a day solve its puzzle step by step, for the days that can show it
+    Animate {
+        #[arg(value_parser = parse_day)]
+        day: DayId,
+        /// Frames per second
+        #[arg(long, default_value_t = 30.0)]
+        fps: f64,
+        /// Print every Nth frame one after another instead of playing them in place. With
+        /// --render, only keep every Nth frame of the GIF
+        #[arg(long)]
+        every: Option<usize>,
+        /// Write a .gif of every frame, or a .png or .ppm of the last one, instead
+        #[arg(long, value_parser = parse_render)]
+        render: Option<Render>,
+        /// Pixels per tile when rendering
+        #[arg(long, default_value_t = 4)]
+        scale: usize,
+        /// Read the input from this file instead, `-` for stdin
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Start a new day: create its crate from a template and add it to the workspace
+    New {
+        #[arg(value_parser = parse_day)]
+        day: DayId,
+    },
+    /// Download a day's input into the local cache, unless it's already there
+    Fetch {
+        #[arg(value_parser = parse_day)]
+        day: DayId,
+        /// adventofcode.com session cookie, defaults to `$AOC_SESSION`
+        #[arg(long)]
+        session: Option<String>,
+    },
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    let part: u8 = value
+        .parse()
+        .map_err(|_| format!("`{value}` is not a part"))?;
+    Part::try_from(part).map_err(String::from)
+}
+
+/// One `--set`: a value for a key in a day's table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Setting {
+    day: DayId,
+    key: String,
+    value: String,
+}
+
+/// Reads `2024/14.width=11`, or `14.width=11` for a day of the latest year.
+fn parse_setting(value: &str) -> Result<Setting, String> {
+    let (path, setting) = value
+        .split_once('=')
+        .ok_or_else(|| format!("`{value}` is not a DAY.KEY=VALUE setting"))?;
+    let (day, key) = path
+        .split_once('.')
+        .ok_or_else(|| format!("`{path}` is not a day and key like 2024/14.width"))?;
+    Ok(Setting {
+        day: parse_day(day)?,
+        key: key.trim().to_string(),
+        value: setting.trim().to_string(),
+    })
+}
+
+/// The parameters from `--config` or aoc.toml, with every `--set` over them.
+fn load_config(path: Option<PathBuf>, settings: &[Setting]) -> Result<Config, String> {
+    let mut config = Config::load(&path.unwrap_or_else(Config::default_path))?;
+    for setting in settings {
+        config.set(setting.day, &setting.key, &setting.value);
+    }
+    Ok(config)
+}
+
+fn parse_render(value: &str) -> Result<Render, String> {
+    Render::to_file(value)
+}
+
+fn day_number(value: &str) -> Result<u8, String> {
+    match value.trim().parse() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("`{value}` is not a day between 1 and 25")),
+    }
+}
+
+/// Reads `2024/17`, or just `17` for that day of the latest year.
+fn parse_day(value: &str) -> Result<DayId, String> {
+    match value.contains('/') {
+        true => value.parse(),
+        false => Ok(DayId::new(registry::latest_year(), day_number(value)?)),
+    }
+}
+
+/// Expands one `days` argument into the days it names.
+fn parse_days(value: &str) -> Result<Vec<DayId>, String> {
+    if value == "all" {
+        return Ok(registry::SOLUTIONS.iter().map(|s| s.id()).collect());
+    }
+    if let Ok(year @ 1000..) = value.parse::<u16>() {
+        let days: Vec<DayId> = registry::SOLUTIONS
+            .iter()
+            .map(|s| s.id())
+            .filter(|id| id.year == year)
+            .collect();
+        if days.is_empty() {
+            return Err(format!("there are no days for {year} yet"));
+        }
+        return Ok(days);
+    }
+    let (year, days) = match value.split_once('/') {
+        Some((year, days)) => (
+            year.parse()
+                .map_err(|_| format!("`{year}` is not a year"))?,
+            days,
+        ),
+        None => (registry::latest_year(), value),
+    };
+    let (first, last) = match days.split_once('-') {
+        Some((first, last)) => (day_number(first)?, day_number(last)?),
+        None => (day_number(days)?, day_number(days)?),
+    };
+    if first > last {
+        return Err(format!("`{days}` runs backwards, try `{last}-{first}`"));
+    }
+    Ok((first..=last).map(|day| DayId::new(year, day)).collect())
+}
+
+fn run(days: &[DayId], part: Option<Part>, input: Option<&Path>, format: Format) -> ExitCode {
+    if input.is_some() && days.len() > 1 {
+        eprintln!("--input can only be used when running a single day");
+        return ExitCode::FAILURE;
+    }
+    if format != Format::Text {
+        return run_records(days, part, input, format);
+    }
+    let mut status = ExitCode::SUCCESS;
+    for &day in days {
+        let Some(solution) = registry::find(day) else {
+            eprintln!("{day} has no solution yet");
+            status = ExitCode::FAILURE;
+            continue;
+        };
+        let report = InputSource::from_arg(input, day)
+            .load()
+            .map_err(|error| error.to_string())
+            .and_then(|input| {
+                solution
+                    .run(&input, part)
+                    .map_err(|error| error.to_string())
+            });
+        match report {
+            Ok(report) => print!("{}", report),
+            Err(error) => {
+                eprintln!("{day}: {error}");
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    status
+}
+
+/// [`run`] for `--format json` and `csv`, printing a record per day and part.
+fn run_records(
+    days: &[DayId],
+    part: Option<Part>,
+    input: Option<&Path>,
+    format: Format,
+) -> ExitCode {
+    if format == Format::Csv {
+        println!("{}", output::CSV_HEADER);
+    }
+    let mut failed = false;
+    for &day in days {
+        let records = match (
+            registry::find(day),
+            InputSource::from_arg(input, day).load(),
+        ) {
+            (None, _) => vec![Record::failed(day, "no solution yet".to_string())],
+            (_, Err(error)) => vec![Record::failed(day, error.to_string())],
+            (Some(solution), Ok(input)) => output::records(solution, &input, part),
+        };
+        for record in records {
+            failed |= !record.is_ok();
+            match format {
+                Format::Csv => println!("{}", record.to_csv()),
+                _ => println!("{}", record.to_json()),
+            }
+        }
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn verify(days: &[DayId], answers: &Answers) -> ExitCode {
+    let mut failed = false;
+    println!("{}", Check::header());
+    for &day in days {
+        let checks = match (registry::find(day), InputSource::Day(day).load()) {
+            (None, _) => vec![day_check(
+                day,
+                Status::Skipped("no solution yet".to_string()),
+            )],
+            (_, Err(error)) => vec![day_check(day, Status::Skipped(error.to_string()))],
+            (Some(solution), Ok(input)) => {
+                match panic::catch_unwind(AssertUnwindSafe(|| solution.run(&input, None))) {
+                    Ok(Ok(report)) => answers.check(&report),
+                    Ok(Err(error)) => vec![day_check(day, Status::Error(error.to_string()))],
+                    Err(panic) => vec![day_check(day, Status::Panicked(panic_message(&panic)))],
+                }
+            }
+        };
+        for check in checks {
+            failed |= matches!(
+                check.status,
+                Status::Fail { .. } | Status::Error(_) | Status::Panicked(_)
+            );
+            println!("{}", check);
+        }
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Prints every problem the day's validator finds in its input, failing if there are any.
+fn check(day: DayId, file: Option<&Path>) -> ExitCode {
+    let Some(solution) = registry::find(day) else {
+        eprintln!("{day} has no solution yet");
+        return ExitCode::FAILURE;
+    };
+    let input = match InputSource::from_arg(file, day).load() {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{day}: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let name = file.map_or_else(|| day.to_string(), |file| file.display().to_string());
+    let errors = solution.validate(&input);
+    for error in &errors {
+        println!("{name}: {error}");
+    }
+    match errors.len() {
+        0 => {
+            println!("{name}: ok");
+            ExitCode::SUCCESS
+        }
+        1 => {
+            println!("{name}: 1 problem");
+            ExitCode::FAILURE
+        }
+        count => {
+            println!("{name}: {count} problems");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Runs a day with profiling on, then prints its answers and the spans and counters it
+/// recorded.
+fn profile(day: DayId, part: Option<Part>, input: Option<&Path>) -> ExitCode {
+    let Some(solution) = registry::find(day) else {
+        eprintln!("{day} has no solution yet");
+        return ExitCode::FAILURE;
+    };
+    let input = match InputSource::from_arg(input, day).load() {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{day}: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    profile::start();
+    let report = solution.run(&input, part);
+    let profile = profile::stop();
+    let status = match report {
+        Ok(report) => {
+            print!("{}", report);
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{day}: {error}");
+            ExitCode::FAILURE
+        }
+    };
+    println!();
+    println!("{}", profile);
+    status
+}
+
+/// How `aoc animate` shows a day.
+enum Playback {
+    Terminal(Animation),
+    File(Render),
+}
+
+fn animate(day: DayId, playback: &Playback, input: Option<&Path>) -> ExitCode {
+    let Some(solution) = registry::find(day) else {
+        eprintln!("{day} has no solution yet");
+        return ExitCode::FAILURE;
+    };
+    let animated = InputSource::from_arg(input, day)
+        .load()
+        .map_err(|error| error.to_string())
+        .and_then(|input| {
+            match playback {
+                Playback::Terminal(animation) => solution.animate(&input, animation),
+                Playback::File(render) => solution.render(&input, render),
+            }
+            .map_err(|error| error.to_string())
+        });
+    match animated {
+        Ok(true) => {
+            if let Playback::File(render) = playback {
+                println!("Rendered to {}", render.path().display());
+            }
+            ExitCode::SUCCESS
+        }
+        Ok(false) => {
+            eprintln!("{day} has no animation");
+            ExitCode::FAILURE
+        }
+        Err(error) => {
+            eprintln!("{day}: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+struct BenchOptions {
+    warmup: usize,
+    samples: usize,
+    baseline: PathBuf,
+    save: bool,
+    threshold: f64,
+}
+
+fn bench(days: &[DayId], options: BenchOptions) -> ExitCode {
+    let mut baseline = if options.baseline.is_file() {
+        match Baseline::load(&options.baseline) {
+            Ok(baseline) => baseline,
+            Err(error) => {
+                eprintln!("{error}");
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        Baseline::default()
+    };
+    let mut regressed = false;
+    println!("{}", Comparison::header());
+    for &day in days {
+        let Some(solution) = registry::find(day) else {
+            eprintln!("{day} has no solution yet");
+            continue;
+        };
+        let input = match InputSource::Day(day).load() {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("{day}: {error}");
+                continue;
+            }
+        };
+        let timings = match bench::bench(solution, &input, options.warmup, options.samples) {
+            Ok(timings) => timings,
+            Err(error) => {
+                eprintln!("{day}: {error}");
+                continue;
+            }
+        };
+        for row in bench::compare(day, &timings, baseline.get(day), options.threshold / 100.0) {
+            regressed |= row.regressed();
+            println!("{}", row);
+        }
+        baseline.insert(day, timings);
+    }
+    if options.save {
+        if let Err(error) = baseline.save(&options.baseline) {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+        println!("Saved baseline to {}", options.baseline.display());
+    }
+    if regressed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// A row for the whole day, when it didn't get as far as answering.
+fn day_check(day: DayId, status: Status) -> Check {
+    Check {
+        day,
+        part: None,
+        answer: String::new(),
+        status,
+    }
+}
+
+fn panic_message(panic: &Box<dyn Any + Send>) -> String {
+    match (panic.downcast_ref::<&str>(), panic.downcast_ref::<String>()) {
+        (Some(message), _) => message.to_string(),
+        (_, Some(message)) => message.clone(),
+        _ => "panicked".to_string(),
+    }
+}
+
+/// Flattens `days` arguments, defaulting to every registered day.
+fn selected_days(days: Vec<Vec<DayId>>) -> Vec<DayId> {
+    let mut days: Vec<DayId> = days.into_iter().flatten().collect();
+    if days.is_empty() {
+        days = registry::SOLUTIONS.iter().map(|s| s.id()).collect();
+    }
+    days.sort();
+    days.dedup();
+    days
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if let Some(jobs) = cli.jobs {
+        elves::parallel::set_jobs(jobs);
+    }
+    elves::log::set_verbosity(cli.verbose);
+    match load_config(cli.config, &cli.settings) {
+        Ok(loaded) => config::set(loaded),
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    }
+    match cli.command {
+        Command::Run {
+            days,
+            part,
+            input,
+            format,
+        } => run(&selected_days(days), part, input.as_deref(), format),
+        Command::Verify { days, answers } => {
+            let path = answers.unwrap_or_else(Answers::default_path);
+            match Answers::load(&path) {
+                Ok(answers) => verify(&selected_days(days), &answers),
+                Err(error) => {
+                    eprintln!("{error}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Check { day, file } => check(day, file.as_deref()),
+        Command::Profile { day, part, input } => profile(day, part, input.as_deref()),
+        Command::Gen { day, seed, size } => {
+            match registry::find(day).map(|solution| solution.generate(seed, size)) {
+                Some(Some(input)) => {
+                    print!("{input}");
+                    ExitCode::SUCCESS
+                }
+                Some(None) => {
+                    eprintln!("{day} has no generator");
+                    ExitCode::FAILURE
+                }
+                None => {
+                    eprintln!("{day} has no solution yet");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Bench {
+            days,
+            warmup,
+            samples,
+            baseline,
+            save,
+            threshold,
+        } => bench(
+            &selected_days(days),
+            BenchOptions {
+                warmup,
+                samples,
+                baseline: baseline.unwrap_or_else(Baseline::default_path),
+                save,
+                threshold,
+            },
+        ),
+        Command::Animate {
+            day,
+            fps,
+            every,
+            render,
+            scale,
+            input,
+        } => {
+            let playback = match (render, every) {
+                (Some(render), every) => {
+                    Playback::File(render.scale(scale).fps(fps).every(every.unwrap_or(1)))
+                }
+                (None, Some(every)) => Playback::Terminal(Animation::headless(every)),
+                (None, None) => Playback::Terminal(Animation::live(fps)),
+            };
+            animate(day, &playback, input.as_deref())
+        }
+        Command::New { day } => match scaffold::new_day(&workspace_dir(), day) {
+            Ok(dir) => {
+                println!("Created {}", dir.display());
+                ExitCode::SUCCESS
+            }
+            Err(error) => {
+                eprintln!("{error}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Fetch { day, session } => {
+            let session = session.or_else(|| std::env::var(fetch::SESSION_VAR).ok());
+            let transport = fetch::HttpTransport::from_env();
+            match fetch::fetch(day, session.as_deref(), &transport, &InputCache::from_env()) {
+                Ok(path) => {
+                    println!("{}", path.display());
+                    ExitCode::SUCCESS
+                }
+                Err(error) => {
+                    eprintln!("{error}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_selections() {
+        let day = |day| DayId::new(2024, day);
+        assert_eq!(registry::latest_year(), 2024);
+        assert_eq!(parse_days("17"), Ok(vec![day(17)]));
+        assert_eq!(parse_days("2024/17"), Ok(vec![day(17)]));
+        assert_eq!(parse_days("3-5"), Ok(vec![day(3), day(4), day(5)]));
+        assert_eq!(
+            parse_days("2023/1-2"),
+            Ok(vec![DayId::new(2023, 1), DayId::new(2023, 2)])
+        );
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("5-3").is_err());
+        assert!(parse_days("2024/26").is_err());
+        assert!(parse_days("all").unwrap().contains(&day(23)));
+        assert_eq!(parse_days("2024").unwrap().len(), registry::SOLUTIONS.len());
+        assert!(parse_days("2015").is_err());
+        assert_eq!(parse_part("2"), Ok(Part::Two));
+        assert!(parse_part("3").is_err());
+    }
+
+    #[test]
+    fn settings() {
+        assert_eq!(
+            parse_setting("14.width=11"),
+            Ok(Setting {
+                day: DayId::new(2024, 14),
+                key: "width".to_string(),
+                value: "11".to_string(),
+            })
+        );
+        assert_eq!(parse_setting("2023/1.name = a=b").unwrap().value, "a=b");
+        assert!(parse_setting("14.width").is_err());
+        assert!(parse_setting("width=11").is_err());
+        assert!(parse_setting("26.width=11").is_err());
+    }
+}
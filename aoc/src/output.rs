@@ -0,0 +1,189 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use clap::ValueEnum;
+use elves::{DayId, Part, Runnable};
+use serde::Serialize;
+
+/// How `aoc run` prints its results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// A table for people
+    Text,
+    /// One JSON object per line, per day and part
+    Json,
+    /// A header, then one row per day and part
+    Csv,
+}
+
+/// How running a day or part went.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    Error,
+    Panicked,
+}
+
+/// One day's part in `--format json` or `csv`. `part` is missing when the day didn't get as
+/// far as running one, e.g. because its input couldn't be loaded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: Option<u8>,
+    pub answer: Option<String>,
+    pub elapsed_ns: Option<u64>,
+    pub input_hash: Option<String>,
+    pub status: Status,
+    pub message: Option<String>,
+}
+impl Record {
+    /// A record for a day that failed before any part ran.
+    pub fn failed(id: DayId, message: String) -> Record {
+        Record {
+            year: id.year,
+            day: id.day,
+            part: None,
+            answer: None,
+            elapsed_ns: None,
+            input_hash: None,
+            status: Status::Error,
+            message: Some(message),
+        }
+    }
+    pub fn is_ok(&self) -> bool {
+        self.status == Status::Ok
+    }
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("records only hold strings and numbers")
+    }
+    pub fn to_csv(&self) -> String {
+        let status = match self.status {
+            Status::Ok => "ok",
+            Status::Error => "error",
+            Status::Panicked => "panicked",
+        };
+        [
+            self.year.to_string(),
+            self.day.to_string(),
+            self.part.map(|part| part.to_string()).unwrap_or_default(),
+            csv_field(self.answer.as_deref().unwrap_or_default()),
+            self.elapsed_ns
+                .map(|elapsed| elapsed.to_string())
+                .unwrap_or_default(),
+            self.input_hash.clone().unwrap_or_default(),
+            status.to_string(),
+            csv_field(self.message.as_deref().unwrap_or_default()),
+        ]
+        .join(",")
+    }
+}
+
+pub const CSV_HEADER: &str = "year,day,part,answer,elapsed_ns,input_hash,status,message";
+
+/// Runs each part on its own, so a part that fails or panics still gets a record and
+/// doesn't take the other one down with it.
+pub fn records(solution: &dyn Runnable, input: &str, part: Option<Part>) -> Vec<Record> {
+    let input_hash = input_hash(input);
+    Part::ALL
+        .into_iter()
+        .filter(|p| part.is_none_or(|wanted| wanted == *p))
+        .map(|part| {
+            let mut record = Record {
+                year: solution.year(),
+                day: solution.day(),
+                part: Some(part_number(part)),
+                answer: None,
+                elapsed_ns: None,
+                input_hash: Some(input_hash.clone()),
+                status: Status::Ok,
+                message: None,
+            };
+            match panic::catch_unwind(AssertUnwindSafe(|| solution.run(input, Some(part)))) {
+                Ok(Ok(report)) => {
+                    if let Some(part) = report.parts.first() {
+                        record.answer = Some(part.answer.clone());
+                        record.elapsed_ns = Some(part.elapsed.as_nanos() as u64);
+                    }
+                }
+                Ok(Err(error)) => {
+                    record.status = Status::Error;
+                    record.message = Some(error.to_string());
+                }
+                Err(panic) => {
+                    record.status = Status::Panicked;
+                    record.message = Some(crate::panic_message(&panic));
+                }
+            }
+            record
+        })
+        .collect()
+}
+
+fn part_number(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+/// A 64-bit FNV-1a hash of the input, in hex, to tell which input an answer came from.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// Quotes a field holding commas, quotes or line breaks, like the `4,6,3` answers of day 17.
+fn csv_field(value: &str) -> String {
+    match value.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", value.replace('"', "\"\"")),
+        false => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record() -> Record {
+        Record {
+            year: 2024,
+            day: 17,
+            part: Some(1),
+            answer: Some("4,6,3".to_string()),
+            elapsed_ns: Some(1500),
+            input_hash: Some(input_hash("")),
+            status: Status::Ok,
+            message: None,
+        }
+    }
+
+    #[test]
+    fn hashes_inputs() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn writes_json_and_csv() {
+        assert_eq!(
+            record().to_json(),
+            r#"{"year":2024,"day":17,"part":1,"answer":"4,6,3","elapsed_ns":1500,"input_hash":"cbf29ce484222325","status":"ok","message":null}"#
+        );
+        assert_eq!(
+            record().to_csv(),
+            r#"2024,17,1,"4,6,3",1500,cbf29ce484222325,ok,"#
+        );
+        let failed = Record::failed(
+            DayId::new(2024, 3),
+            "line 2: \"x\" isn't a number".to_string(),
+        );
+        assert_eq!(
+            failed.to_csv(),
+            r#"2024,3,,,,,error,"line 2: ""x"" isn't a number""#
+        );
+        assert!(failed.to_json().contains(r#""part":null"#));
+    }
+}
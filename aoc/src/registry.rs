@@ -0,0 +1,107 @@
+use elves::{DayId, Runnable};
+
+/// Every day of every year, in order. `aoc new` adds new days here.
+pub static SOLUTIONS: &[&dyn Runnable] = &[
+    &y2024_one::DayOne,
+    &y2024_two::DayTwo,
+    &y2024_three::DayThree,
+    &y2024_four::DayFour,
+    &y2024_five::DayFive,
+    &y2024_six::DaySix,
+    &y2024_seven::DaySeven,
+    &y2024_eight::DayEight,
+    &y2024_nine::DayNine,
+    &y2024_ten::DayTen,
+    &y2024_eleven::DayEleven,
+    &y2024_twelve::DayTwelve,
+    &y2024_thirteen::DayThirteen,
+    &y2024_fourteen::DayFourteen,
+    &y2024_fifteen::DayFifteen,
+    &y2024_sixteen::DaySixteen,
+    &y2024_seventeen::DaySeventeen,
+    &y2024_eighteen::DayEighteen,
+    &y2024_nineteen::DayNineteen,
+    &y2024_twenty::DayTwenty,
+    &y2024_twentyone::DayTwentyOne,
+    &y2024_twentytwo::DayTwentyTwo,
+    &y2024_twentythree::DayTwentyThree,
+];
+
+pub fn find(day: DayId) -> Option<&'static dyn Runnable> {
+    SOLUTIONS
+        .iter()
+        .copied()
+        .find(|solution| solution.id() == day)
+}
+
+/// The most recent event with any days, which days given without a year belong to.
+pub fn latest_year() -> u16 {
+    SOLUTIONS
+        .iter()
+        .map(|solution| solution.year())
+        .max()
+        .expect("at least one day is registered")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_are_registered_once_in_order() {
+        let days: Vec<DayId> = SOLUTIONS.iter().map(|solution| solution.id()).collect();
+        let mut sorted = days.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(days, sorted);
+        assert!(find(DayId::new(2024, 17)).is_some());
+        assert!(find(DayId::new(2024, 25)).is_none());
+        assert!(find(DayId::new(2023, 17)).is_none());
+    }
+
+    #[test]
+    fn generated_inputs_are_valid() {
+        for solution in SOLUTIONS {
+            for seed in 0..5 {
+                let Some(input) = solution.generate(seed, 12) else {
+                    continue;
+                };
+                assert_eq!(solution.generate(seed, 12).as_ref(), Some(&input));
+                let errors = solution.validate(&input);
+                assert!(
+                    errors.is_empty(),
+                    "{} seed {}: {:?}\n{}",
+                    solution.id(),
+                    seed,
+                    errors,
+                    input
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn generated_inputs_grow_with_size() {
+        for solution in SOLUTIONS {
+            let Some(small) = solution.generate(0, 4) else {
+                continue;
+            };
+            let big = solution.generate(0, 64).unwrap();
+            assert!(
+                big.len() > small.len(),
+                "{} made {} bytes at size 64",
+                solution.id(),
+                big.len()
+            );
+            for size in [0, 1, 2] {
+                let tiny = solution.generate(0, size).unwrap();
+                assert!(
+                    solution.validate(&tiny).is_empty(),
+                    "{} size {}",
+                    solution.id(),
+                    size
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,356 @@
+use std::path::{Path, PathBuf};
+
+use elves::{day_name, DayId};
+
+/// Creates `days/<year>/<name>` from the template and wires it into the workspace, the `aoc`
+/// registry and `answers.toml`. Returns the new crate's directory.
+pub fn new_day(workspace: &Path, day: DayId) -> Result<PathBuf, String> {
+    let crate_name = day
+        .crate_name()
+        .ok_or_else(|| format!("{} is not a day between 1 and 25", day.day))?;
+    let dir = workspace.join(day.dir().unwrap_or_default());
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    let read = |path: &Path| {
+        std::fs::read_to_string(path)
+            .map_err(|error| format!("could not read {}: {}", path.display(), error))
+    };
+    let manifest = workspace.join("Cargo.toml");
+    let runner_manifest = workspace.join("aoc").join("Cargo.toml");
+    let registry = workspace.join("aoc").join("src").join("registry.rs");
+    let answers = workspace.join("answers.toml");
+    // Work everything out before touching anything, so a failure leaves nothing half done.
+    let edits = [
+        (&manifest, add_member(&read(&manifest)?, day)?),
+        (
+            &runner_manifest,
+            add_dependency(&read(&runner_manifest)?, day)?,
+        ),
+        (&registry, register(&read(&registry)?, day)?),
+        (&answers, add_answers(&read(&answers)?, day)),
+    ];
+    let files = [
+        (dir.join("Cargo.toml"), crate_manifest(&crate_name)),
+        (dir.join(".gitignore"), "/target\n".to_string()),
+        (dir.join("build.rs"), BUILD_SCRIPT.to_string()),
+        (dir.join("examples").join("example.txt"), String::new()),
+        (
+            dir.join("examples").join("answers.toml"),
+            EXAMPLE_ANSWERS.to_string(),
+        ),
+        (dir.join("src").join("lib.rs"), lib_template(day)),
+        (dir.join("src").join("main.rs"), main_template(day)),
+    ];
+
+    let write = |path: &Path, contents: &str| {
+        std::fs::write(path, contents)
+            .map_err(|error| format!("could not write {}: {}", path.display(), error))
+    };
+    for sub_dir in ["src", "examples"] {
+        std::fs::create_dir_all(dir.join(sub_dir))
+            .map_err(|error| format!("could not create {}: {}", dir.display(), error))?;
+    }
+    for (path, contents) in &files {
+        write(path, contents)?;
+    }
+    for (path, contents) in &edits {
+        write(path, contents)?;
+    }
+    Ok(dir)
+}
+
+/// `DayTwentyFour` for day 24.
+fn struct_name(day: u8) -> String {
+    let name = day_name(day).unwrap_or_default();
+    let (tens, ones) = match name.strip_prefix("twenty") {
+        Some(ones) if !ones.is_empty() => ("twenty", ones),
+        _ => (name, ""),
+    };
+    format!("Day{}{}", capitalise(tens), capitalise(ones))
+}
+
+fn capitalise(word: &str) -> String {
+    let mut chars = word.chars();
+    chars
+        .next()
+        .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
+
+/// Adds `days/<year>/<name>` to the workspace members, before the first member that sorts
+/// after it.
+fn add_member(manifest: &str, day: DayId) -> Result<String, String> {
+    let path = day.dir().unwrap_or_default().display().to_string();
+    let member = format!("\"{}\"", path);
+    let start = manifest
+        .find("members = [")
+        .ok_or("the workspace Cargo.toml has no members list")?;
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or("the workspace members list isn't closed")?;
+    let list = &manifest[start..end];
+    if list.contains(&member) {
+        return Err(format!("{} is already a workspace member", path));
+    }
+    let mut members = list.match_indices('"').step_by(2).filter_map(|(open, _)| {
+        let close = list[open + 1..].find('"')? + open + 1;
+        Some((start + open, &list[open..=close]))
+    });
+    let insert_at = members
+        .find(|(_, existing)| *existing > member.as_str())
+        .map(|(at, _)| (at, format!("{}, ", member)))
+        .unwrap_or_else(|| {
+            let last = manifest[..end].trim_end().len();
+            (last, format!(" {},", member))
+        });
+    let mut manifest = manifest.to_string();
+    manifest.insert_str(insert_at.0, &insert_at.1);
+    Ok(manifest)
+}
+
+/// Adds the day's crate to the runner's dependencies, after the day before it.
+fn add_dependency(manifest: &str, day: DayId) -> Result<String, String> {
+    let name = day.crate_name().unwrap_or_default();
+    let line = format!(
+        "{} = {{ path = \"../{}\" }}\n",
+        name,
+        day.dir().unwrap_or_default().display()
+    );
+    if manifest.contains(&format!("\n{} = ", name)) {
+        return Err(format!("aoc already depends on {}", name));
+    }
+    let later = |line: &str| crate_day(line.split_once(" = ")?.0);
+    Ok(insert_before_later_day(manifest, &line, day, later)
+        .unwrap_or_else(|| format!("{}\n{}", manifest.trim_end(), line)))
+}
+
+/// Adds the day to `SOLUTIONS`, keeping it in order.
+fn register(registry: &str, day: DayId) -> Result<String, String> {
+    let name = day.crate_name().unwrap_or_default().replace('-', "_");
+    let entry = format!("    &{}::{},\n", name, struct_name(day.day));
+    if registry.contains(&format!("&{}::", name)) {
+        return Err(format!("{} is already registered", day));
+    }
+    let later = |line: &str| crate_day(line.trim().strip_prefix('&')?.split_once("::")?.0);
+    if let Some(registry) = insert_before_later_day(registry, &entry, day, later) {
+        return Ok(registry);
+    }
+    let start = registry
+        .find("SOLUTIONS")
+        .ok_or("the registry has no SOLUTIONS list")?;
+    let end = start
+        + registry[start..]
+            .find("\n];")
+            .ok_or("the SOLUTIONS list isn't closed")?
+        + 1;
+    let mut registry = registry.to_string();
+    registry.insert_str(end, &entry);
+    Ok(registry)
+}
+
+/// The day a crate named like `y2024-seven`, or `y2024_seven` in code, is for.
+fn crate_day(name: &str) -> Option<DayId> {
+    let (year, name) = name.trim().strip_prefix('y')?.split_once(['-', '_'])?;
+    let day = (1..=25).find(|&day| day_name(day) == Some(name))?;
+    Some(DayId::new(year.parse().ok()?, day))
+}
+
+/// Inserts `line` at the start of the first line that `day_of` reads a later day from, if
+/// there's one.
+fn insert_before_later_day(
+    text: &str,
+    line: &str,
+    day: DayId,
+    day_of: impl Fn(&str) -> Option<DayId>,
+) -> Option<String> {
+    let mut start = 0;
+    let at = text.split_inclusive('\n').find_map(|existing| {
+        let at = start;
+        start += existing.len();
+        day_of(existing)
+            .is_some_and(|later| later > day)
+            .then_some(at)
+    })?;
+    let mut text = text.to_string();
+    text.insert_str(at, line);
+    Some(text)
+}
+
+/// An empty table for the day, to be filled in once its answers are accepted.
+fn add_answers(answers: &str, day: DayId) -> String {
+    let table = format!("[{}.{}]", day.year, day.day);
+    if answers.lines().any(|line| line.trim() == table) {
+        return answers.to_string();
+    }
+    match answers.trim_end() {
+        "" => format!("{}\n", table),
+        answers => format!("{}\n{}\n", answers, table),
+    }
+}
+
+fn crate_manifest(name: &str) -> String {
+    format!(
+        r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+elves.workspace = true
+
+[build-dependencies]
+elves.workspace = true
+"#
+    )
+}
+
+/// Turns the files in `examples/` into tests, see `elves::examples`.
+const BUILD_SCRIPT: &str = r#"fn main() {
+    elves::examples::generate();
+}
+"#;
+
+const EXAMPLE_ANSWERS: &str = r#"# Answers the puzzle gives for each example in this folder, e.g.
+#
+# [example]
+# one = 0
+# two = 0
+"#;
+
+fn main_template(day: DayId) -> String {
+    format!(
+        r#"fn main() -> std::process::ExitCode {{
+    elves::solution::main({}::{})
+}}
+"#,
+        day.crate_name().unwrap_or_default().replace('-', "_"),
+        struct_name(day.day)
+    )
+}
+
+fn lib_template(id: DayId) -> String {
+    let name = struct_name(id.day);
+    let (year, day) = (id.year, id.day);
+    format!(
+        r#"//! Day {day}.
+#![warn(missing_docs)]
+
+use std::fmt::Display;
+
+use elves::{{Result, Solution, Unsolved}};
+
+/// The day's [`Solution`].
+pub struct {name};
+impl Solution for {name} {{
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day};
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {{
+        Ok(input.to_string())
+    }}
+    fn part_one(_input: &Self::Input) -> Result<impl Display> {{
+        Ok(Unsolved)
+    }}
+    fn part_two(_input: &Self::Input) -> Result<impl Display> {{
+        Ok(Unsolved)
+    }}
+}}
+elves::examples!({name});
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"[workspace]
+members = [ "aoc", "days/2023/one", "days/2024/eight", "days/2024/one",
+    "days/2024/twentyfive", "elves",
+]
+"#;
+    const RUNNER: &str = r#"[dependencies]
+elves.workspace = true
+y2023-one = { path = "../days/2023/one" }
+y2024-one = { path = "../days/2024/one" }
+y2024-twentyfive = { path = "../days/2024/twentyfive" }
+"#;
+    const REGISTRY: &str = r#"pub static SOLUTIONS: &[&dyn Runnable] = &[
+    &y2023_one::DayOne,
+    &y2024_one::DayOne,
+    &y2024_twentyfive::DayTwentyFive,
+];
+"#;
+
+    #[test]
+    fn names_days() {
+        assert_eq!(struct_name(1), "DayOne");
+        assert_eq!(struct_name(20), "DayTwenty");
+        assert_eq!(struct_name(24), "DayTwentyFour");
+    }
+
+    #[test]
+    fn inserts_in_order() {
+        let day = |day| DayId::new(2024, day);
+        let manifest = add_member(MANIFEST, day(9)).unwrap();
+        assert!(manifest.contains(r#""days/2024/eight", "days/2024/nine", "days/2024/one","#));
+        assert!(add_member(MANIFEST, day(1)).is_err());
+
+        let runner = add_dependency(RUNNER, day(2)).unwrap();
+        assert!(runner.contains("one\" }\ny2024-two = { path = \"../days/2024/two\" }\ny2024-tw"));
+        let runner = add_dependency(RUNNER, DayId::new(2023, 25)).unwrap();
+        assert!(runner
+            .contains("\ny2023-twentyfive = { path = \"../days/2023/twentyfive\" }\ny2024-one"));
+
+        let registry = register(REGISTRY, day(24)).unwrap();
+        assert!(registry.contains("    &y2024_twentyfour::DayTwentyFour,\n    &y2024_twentyfive::"));
+        let registry = register(&register(REGISTRY, day(3)).unwrap(), day(2)).unwrap();
+        assert!(registry.contains(
+            "&y2024_one::DayOne,\n    &y2024_two::DayTwo,\n    &y2024_three::DayThree,\n"
+        ));
+        let registry = register(REGISTRY, DayId::new(2025, 1)).unwrap();
+        assert!(registry.ends_with("    &y2025_one::DayOne,\n];\n"));
+        assert!(register(REGISTRY, day(1)).is_err());
+
+        let answers = add_answers("# answers\n[2024.1]\none = \"11\"\n", day(2));
+        assert_eq!(answers, "# answers\n[2024.1]\none = \"11\"\n[2024.2]\n");
+        assert_eq!(add_answers(&answers, day(2)), answers);
+    }
+
+    #[test]
+    fn scaffolds_a_day_once() {
+        let dir = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("aoc").join("src")).unwrap();
+        std::fs::write(dir.join("Cargo.toml"), MANIFEST).unwrap();
+        std::fs::write(dir.join("aoc").join("Cargo.toml"), RUNNER).unwrap();
+        std::fs::write(dir.join("aoc").join("src").join("registry.rs"), REGISTRY).unwrap();
+        std::fs::write(dir.join("answers.toml"), "").unwrap();
+
+        let id = DayId::new(2024, 24);
+        let day = new_day(&dir, id).unwrap();
+        assert_eq!(day, dir.join("days").join("2024").join("twentyfour"));
+        let lib = std::fs::read_to_string(day.join("src").join("lib.rs")).unwrap();
+        assert!(lib.contains(
+            "impl Solution for DayTwentyFour {\n    const YEAR: u16 = 2024;\n    const DAY: u8 = 24;"
+        ));
+        let main = std::fs::read_to_string(day.join("src").join("main.rs")).unwrap();
+        assert!(main.contains("elves::solution::main(y2024_twentyfour::DayTwentyFour)"));
+        let package = std::fs::read_to_string(day.join("Cargo.toml")).unwrap();
+        assert!(package.contains("name = \"y2024-twentyfour\""));
+        assert!(!package.contains("colored"));
+        assert!(lib.starts_with("//! Day 24.\n#![warn(missing_docs)]\n"));
+        assert!(lib.ends_with("elves::examples!(DayTwentyFour);\n"));
+        assert!(day.join("examples").join("example.txt").exists());
+        let manifest = std::fs::read_to_string(dir.join("Cargo.toml")).unwrap();
+        assert!(manifest.contains(r#""days/2024/twentyfive", "days/2024/twentyfour", "elves","#));
+        let answers = std::fs::read_to_string(dir.join("answers.toml")).unwrap();
+        assert_eq!(answers, "[2024.24]\n");
+
+        assert!(new_day(&dir, id).unwrap_err().ends_with("already exists"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
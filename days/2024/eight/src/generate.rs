@@ -0,0 +1,19 @@
+//! A `size` by `size` roof with a few antennas each on a handful of frequencies.
+
+use elves::{Grid, Rng};
+
+const FREQUENCIES: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// The roof map, drawn with `.` for empty tiles.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    let frequencies: Vec<char> = FREQUENCIES.chars().collect();
+    let mut roof = Grid::new(size, size, '.');
+    for _ in 0..rng.between(1, 2 + size as i64 / 4) {
+        let frequency = *rng.pick(&frequencies);
+        for _ in 0..rng.between(2, 4) {
+            roof.set((rng.below(size), rng.below(size)), frequency);
+        }
+    }
+    roof.to_string()
+}
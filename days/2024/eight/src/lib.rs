@@ -0,0 +1,268 @@
+//! Day 8: Resonant Collinearity. Antennas on a roof, where every pair on the same frequency
+//! makes antinodes in line with them, worked out pair by pair with [`AntennaMath`].
+#![warn(missing_docs)]
+
+use colored::*;
+use elves::{Grid, Point, Result, Rng, Solution};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+pub mod generate;
+
+/// The puzzle, with the [`AntennaMap`] as its input.
+pub struct DayEight;
+impl Solution for DayEight {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 8;
+    type Input = AntennaMap;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        AntennaMap::new(input)
+    }
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        Ok(input
+            .antinodes(|math| math.antinode_positions().to_vec())
+            .len())
+    }
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        Ok(input
+            .antinodes(|math| {
+                math.resonant_antinode_positions(input.map_width(), input.map_height())
+            })
+            .len())
+    }
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+elves::examples!(DayEight);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const INPUT: &str = include_str!("../examples/example.txt");
+    #[test]
+    fn antenna_map() {
+        let map = AntennaMap::new(INPUT).unwrap();
+        map.print_map();
+        println!();
+        println!();
+        assert_eq!(map.map_width(), 12);
+        assert_eq!(map.map_height(), 12);
+    }
+    #[test]
+    fn antenna_pairs() {
+        let map = AntennaMap::new(INPUT).unwrap();
+        map.print_map();
+        let mut antinodes: HashSet<Point> = HashSet::new();
+        map.grid.iter().for_each(|(terrain, set)| {
+            if let MapTerrain::Antenna(c) = terrain {
+                let values_vec: Vec<_> = set.iter().collect(); // Collect the values into a vector for easier indexing
+                for i in 0..values_vec.len() {
+                    for j in i + 1..values_vec.len() {
+                        let first_antenna = values_vec[i];
+                        let second_antenna = values_vec[j];
+                        let math = AntennaMath {
+                            first_antenna: *first_antenna,
+                            second_antenna: *second_antenna,
+                        };
+                        println!("{} antenna pair at: {}", c.to_string().blue(), math);
+                        let new_antinodes = math
+                            .antinode_positions()
+                            .into_iter()
+                            .filter(|pos| map.in_bounds(*pos));
+                        for antinode in new_antinodes {
+                            antinodes.insert(antinode);
+                        }
+                    }
+                }
+            };
+        });
+        println!();
+        map.print_map_with_antinodes(&antinodes);
+        assert_eq!(antinodes.len(), 14);
+    }
+    #[test]
+    fn resonant_antinodes() {
+        let map = AntennaMap::new(INPUT).unwrap();
+        map.print_map();
+        let mut antinodes: HashSet<Point> = HashSet::new();
+        map.grid.iter().for_each(|(terrain, set)| {
+            if let MapTerrain::Antenna(c) = terrain {
+                let values_vec: Vec<_> = set.iter().collect(); // Collect the values into a vector for easier indexing
+                for i in 0..values_vec.len() {
+                    for j in i + 1..values_vec.len() {
+                        let first_antenna = values_vec[i];
+                        let second_antenna = values_vec[j];
+                        let math = AntennaMath {
+                            first_antenna: *first_antenna,
+                            second_antenna: *second_antenna,
+                        };
+                        println!("{} antenna pair at: {}", c.to_string().blue(), math);
+                        let new_antinodes = math
+                            .resonant_antinode_positions(map.map_width(), map.map_height())
+                            .into_iter()
+                            .filter(|pos| map.in_bounds(*pos));
+                        for antinode in new_antinodes {
+                            antinodes.insert(antinode);
+                        }
+                    }
+                }
+            };
+        });
+        println!();
+        map.print_map_with_antinodes(&antinodes);
+        assert_eq!(antinodes.len(), 34);
+    }
+}
+/// A tile of the roof.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MapTerrain {
+    /// Nothing there.
+    Empty,
+    /// An antenna on the frequency written on it.
+    Antenna(char),
+}
+impl From<char> for MapTerrain {
+    fn from(c: char) -> Self {
+        if c.is_alphanumeric() {
+            MapTerrain::Antenna(c)
+        } else {
+            MapTerrain::Empty
+        }
+    }
+}
+impl Display for MapTerrain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MapTerrain::Empty => write!(f, "{}", ".".green()), // Add cli color to antennas
+            // Add cli color to antennas
+            MapTerrain::Antenna(c) => write!(f, "{}", c.to_string().blue()),
+        }
+    }
+}
+/// The roof, with the antennas grouped by frequency.
+pub struct AntennaMap {
+    grid: HashMap<MapTerrain, HashSet<Point>>,
+    tiles: Grid<MapTerrain>,
+}
+impl AntennaMap {
+    /// Reads a roof drawn with `.` and a letter or digit per antenna.
+    pub fn new(input: &str) -> Result<Self> {
+        let tiles = Grid::<MapTerrain>::parse(input)?;
+        let mut grid = HashMap::new();
+        tiles.iter().for_each(|(coord, terrain)| {
+            let position = Point::from(coord);
+            let set = grid.entry(*terrain).or_insert_with(HashSet::new);
+            set.insert(position);
+        });
+        Ok(AntennaMap { grid, tiles })
+    }
+    /// Collects every in-bounds antinode produced by pairing up antennas of the same frequency.
+    pub fn antinodes(&self, positions: impl Fn(&AntennaMath) -> Vec<Point>) -> HashSet<Point> {
+        let mut antinodes: HashSet<Point> = HashSet::new();
+        self.grid.iter().for_each(|(terrain, set)| {
+            if let MapTerrain::Antenna(_) = terrain {
+                let values_vec: Vec<_> = set.iter().collect(); // Collect the values into a vector for easier indexing
+                for i in 0..values_vec.len() {
+                    for j in i + 1..values_vec.len() {
+                        let math = AntennaMath::new(*values_vec[i], *values_vec[j]);
+                        let new_antinodes = positions(&math)
+                            .into_iter()
+                            .filter(|pos| self.in_bounds(*pos));
+                        antinodes.extend(new_antinodes);
+                    }
+                }
+            };
+        });
+        antinodes
+    }
+    fn in_bounds(&self, position: Point) -> bool {
+        self.tiles.at(position).is_some()
+    }
+    /// How many tiles across the roof is.
+    pub fn map_width(&self) -> i64 {
+        self.tiles.width() as i64
+    }
+    /// How many tiles down the roof is.
+    pub fn map_height(&self) -> i64 {
+        self.tiles.height() as i64
+    }
+
+    /// Prints the roof, for debugging.
+    pub fn print_map(&self) {
+        print!("{}", self.tiles);
+    }
+    /// Prints the roof with `antinodes` marked in red, for debugging.
+    pub fn print_map_with_antinodes(&self, antinodes: &HashSet<Point>) {
+        for (y, row) in self.tiles.rows().enumerate() {
+            for (x, terrain) in row.iter().enumerate() {
+                let position = Point::new(x as i64, y as i64);
+                if antinodes.contains(&position) {
+                    print!("{}", "#".red());
+                } else {
+                    print!("{}", terrain);
+                }
+            }
+            println!();
+        }
+    }
+}
+/// Two antennas on the same frequency.
+pub struct AntennaMath {
+    first_antenna: Point,
+    second_antenna: Point,
+}
+impl Display for AntennaMath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "({}, {})",
+            self.first_antenna.to_string().green(),
+            self.second_antenna.to_string().bright_green()
+        )
+    }
+}
+impl AntennaMath {
+    /// The pair of antennas at `first_antenna` and `second_antenna`.
+    pub fn new(first_antenna: Point, second_antenna: Point) -> Self {
+        AntennaMath {
+            first_antenna,
+            second_antenna,
+        }
+    }
+    fn distances_between(&self) -> Point {
+        self.first_antenna - self.second_antenna
+    }
+    /// The two antinodes either side of the pair, each as far from the nearer antenna as
+    /// the antennas are from each other. They may be off the roof.
+    pub fn antinode_positions(&self) -> [Point; 2] {
+        let distance = self.distances_between();
+        [
+            self.first_antenna + distance,
+            self.second_antenna - distance,
+        ]
+    }
+    /// Every antinode in line with the pair at any multiple of their distance, the antennas
+    /// included, within an `x_bound` by `y_bound` roof.
+    pub fn resonant_antinode_positions(&self, x_bound: i64, y_bound: i64) -> Vec<Point> {
+        let distance = self.distances_between();
+        let in_bounds = |antinode: Point| {
+            antinode.x >= 0 && antinode.y >= 0 && antinode.x < x_bound && antinode.y < y_bound
+        };
+        let mut antinodes = Vec::new();
+        let mut first_antinode = self.first_antenna;
+        while in_bounds(first_antinode) {
+            antinodes.push(first_antinode);
+            first_antinode += distance;
+        }
+        let mut second_antinode = self.second_antenna;
+        while in_bounds(second_antinode) {
+            antinodes.push(second_antinode);
+            second_antinode -= distance;
+        }
+        antinodes
+    }
+}
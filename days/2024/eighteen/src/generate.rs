@@ -0,0 +1,21 @@
+//! `size` different bytes falling anywhere in the memory space but the two corners.
+
+use elves::Rng;
+
+use crate::Config;
+
+/// One `x,y` position per line, in the order the bytes fall.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let memory_size = Config::default().size;
+    let side = memory_size + 1;
+    let mut positions: Vec<(i64, i64)> = (0..side * side)
+        .map(|index| (index % side, index / side))
+        .filter(|&position| position != (0, 0) && position != (memory_size, memory_size))
+        .collect();
+    rng.shuffle(&mut positions);
+    positions
+        .iter()
+        .take(size)
+        .map(|(x, y)| format!("{},{}\n", x, y))
+        .collect()
+}
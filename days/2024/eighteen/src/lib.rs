@@ -0,0 +1,315 @@
+//! Day 18: RAM Run. Bytes falling into a square memory space, searched for the shortest way
+//! across and for the first byte that cuts it off.
+#![warn(missing_docs)]
+
+use std::fmt::Display;
+
+use colored::Colorize;
+use elves::{
+    check_lines, config::DayConfig, parse_lines, parse_number, search, Animation, Error, Grid,
+    Point, Render, RenderImage, Result, Rgb, Rng, Solution,
+};
+use serde::Deserialize;
+
+pub mod generate;
+
+/// The largest memory space [`Config::size`] allows, a grid of about 16 million cells.
+pub const MAX_SIZE: i64 = 4095;
+
+/// The memory space's size and how many bytes fall, from `[2024.18]` in `aoc.toml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// The memory space spans coordinates `0..=size` on both axes, up to [`MAX_SIZE`].
+    pub size: i64,
+    /// How many bytes have fallen by the time part one looks for a path.
+    pub fallen: isize,
+    /// Where part two starts looking for the blocking byte, which has to be before it.
+    /// `fallen` always is, this just skips ahead.
+    pub search_from: isize,
+}
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            size: 70,
+            fallen: 1024,
+            search_from: 2870,
+        }
+    }
+}
+impl DayConfig for Config {
+    fn validate(&self) -> Result<()> {
+        if self.size < 0 {
+            return Err(Error::config("the memory space's size can't be negative"));
+        }
+        if self.size > MAX_SIZE {
+            return Err(Error::config(format!(
+                "the memory space's size can be at most {}",
+                MAX_SIZE
+            )));
+        }
+        if self.fallen < 0 || self.search_from < 0 {
+            return Err(Error::config(
+                "bytes can't have fallen a negative number of times",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// The day's [`Solution`]: the input is one falling byte's `x,y` position per line.
+pub struct DayEighteen;
+impl Solution for DayEighteen {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 18;
+    type Input = CorruptedMemory;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        CorruptedMemory::try_from(input)
+    }
+    fn validate(input: &str) -> Vec<Error> {
+        check_lines(input, parse_position)
+    }
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        let config: Config = Self::config()?;
+        let mut memory_space = MemorySpace::new(config.size);
+        memory_space.corrupt_memory(config.fallen, input);
+        let shortest = memory_space
+            .bfs()
+            .ok_or_else(|| Error::no_answer("no path to the exit"))?;
+        Ok(shortest.len() - 1)
+    }
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        let config: Config = Self::config()?;
+        MemorySpace::new(config.size)
+            .first_blocking_byte(input, config.search_from)
+            .ok_or_else(|| Error::no_answer("the path was never blocked"))
+    }
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+    /// Lets part one's bytes fall one at a time, then walks the shortest path out.
+    fn animate(input: &Self::Input, animation: &Animation) -> Result<bool> {
+        let config: Config = Self::config()?;
+        animation.run(
+            &mut MemorySpace::new(config.size),
+            MemorySpace::falling_bytes(input, config.fallen),
+        )?;
+        Ok(true)
+    }
+    fn render(input: &Self::Input, render: &Render) -> Result<bool> {
+        let config: Config = Self::config()?;
+        render.run(
+            &mut MemorySpace::new(config.size),
+            MemorySpace::falling_bytes(input, config.fallen),
+        )?;
+        Ok(true)
+    }
+}
+elves::examples!(DayEighteen);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const CORRUPTED_MEMORY_TEST: &str = include_str!("../examples/example.txt");
+    #[test]
+    fn byte_map() -> Result<()> {
+        let mut map = MemorySpace::new(6);
+        elves::assert_snapshot!("empty", map);
+        let corrupted_memory = CorruptedMemory::try_from(CORRUPTED_MEMORY_TEST)?;
+        map.corrupt_memory(12, &corrupted_memory);
+        elves::assert_snapshot!("twelve-bytes", map);
+        let shortest = map.bfs().ok_or(Error::no_answer("no path"))?;
+        map.safe_memory(&shortest);
+        elves::assert_snapshot!("shortest-path", map);
+        assert_eq!(shortest.len() - 1, 22);
+
+        let mut falling = MemorySpace::new(6);
+        let frames = Animation::headless(4).run(
+            &mut falling,
+            MemorySpace::falling_bytes(&corrupted_memory, 12),
+        )?;
+        assert_eq!(frames, 13);
+        assert_eq!(falling.to_string(), map.to_string());
+        let blocking = MemorySpace::new(6)
+            .first_blocking_byte(&corrupted_memory, 12)
+            .ok_or(Error::no_answer("never blocked"))?;
+        assert_eq!((blocking.x, blocking.y), (6, 1));
+        Ok(())
+    }
+    #[test]
+    fn rejects_memory_spaces_too_big_to_hold() {
+        let config = |size| Config {
+            size,
+            ..Config::default()
+        };
+        assert!(config(MAX_SIZE).validate().is_ok());
+        assert!(matches!(config(-1).validate(), Err(Error::Config(_))));
+        assert!(matches!(
+            config(100_000_000_000).validate(),
+            Err(Error::Config(_))
+        ));
+    }
+}
+/// The memory space, from the start at the top left to the exit at the bottom right.
+#[derive(Debug, Clone)]
+pub struct MemorySpace {
+    size: i64,
+    memory: Grid<MemoryValue>,
+}
+impl MemorySpace {
+    /// A space with nothing corrupted yet, spanning `0..=size` on both axes.
+    pub fn new(size: i64) -> MemorySpace {
+        let side = (size + 1) as usize;
+        let memory = Grid::new(side, side, MemoryValue::Safe);
+        MemorySpace { size, memory }
+    }
+    fn is_safe(&self, position: Point) -> bool {
+        self.memory.at(position) == Some(&MemoryValue::Safe)
+    }
+    /// The shortest path from the start to the exit around the corrupted bytes, both ends
+    /// included.
+    pub fn bfs(&self) -> Option<Vec<Point>> {
+        let end_position = self.end_position();
+        search::bfs(
+            self.start_position(),
+            |current_pos| current_pos.neighbours4().filter(|next| self.is_safe(*next)),
+            |position| *position == end_position,
+        )
+    }
+    /// Marks a path as walked.
+    pub fn safe_memory(&mut self, safe_memory: &[Point]) {
+        safe_memory
+            .iter()
+            .filter_map(|position| position.coord())
+            .for_each(|coord| {
+                self.memory.set(coord, MemoryValue::Walked);
+            });
+    }
+    /// Corrupts the first `size` bytes to fall.
+    pub fn corrupt_memory(&mut self, size: isize, corrupted_memory: &CorruptedMemory) {
+        corrupted_memory
+            .take_bytes(size)
+            .iter()
+            .filter_map(|position| position.coord())
+            .for_each(|coord| {
+                self.memory.set(coord, MemoryValue::Corrupted);
+            });
+    }
+    /// The byte whose fall first cuts the start off from the exit, letting bytes fall
+    /// one at a time from `fallen` onwards.
+    pub fn first_blocking_byte(
+        &self,
+        corrupted_memory: &CorruptedMemory,
+        fallen: isize,
+    ) -> Option<Point> {
+        let total = corrupted_memory.corrupted_positions.len() as isize;
+        (fallen..=total).find_map(|nanoseconds| {
+            let mut new_space = self.clone();
+            new_space.corrupt_memory(nanoseconds, corrupted_memory);
+            match new_space.bfs() {
+                Some(_) => None,
+                None => corrupted_memory
+                    .corrupted_positions
+                    .get((nanoseconds - 1) as usize)
+                    .copied(),
+            }
+        })
+    }
+    /// Steps for an [`Animation`]: one byte falls per step until `fallen` have, then
+    /// the shortest path out is walked, if there still is one.
+    pub fn falling_bytes(
+        corrupted_memory: &CorruptedMemory,
+        fallen: isize,
+    ) -> impl FnMut(&mut MemorySpace) -> bool + '_ {
+        let mut bytes = corrupted_memory.take_bytes(fallen).iter();
+        let mut walked = false;
+        move |space| {
+            if let Some(byte) = bytes.next() {
+                if let Some(coord) = byte.coord() {
+                    space.memory.set(coord, MemoryValue::Corrupted);
+                }
+                return true;
+            }
+            if walked {
+                return false;
+            }
+            walked = true;
+            if let Some(path) = space.bfs() {
+                space.safe_memory(&path);
+            }
+            true
+        }
+    }
+    fn start_position(&self) -> Point {
+        Point::ORIGIN
+    }
+    fn end_position(&self) -> Point {
+        Point::new(self.size, self.size)
+    }
+}
+impl Display for MemorySpace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.memory)?;
+        writeln!(f)
+    }
+}
+/// The positions bytes fall on, in the order they fall.
+pub struct CorruptedMemory {
+    corrupted_positions: Vec<Point>,
+}
+impl CorruptedMemory {
+    /// The first `size` bytes to fall, or all of them if fewer fall.
+    pub fn take_bytes(&self, size: isize) -> &[Point] {
+        &self.corrupted_positions[..(size as usize).min(self.corrupted_positions.len())]
+    }
+}
+impl TryFrom<&str> for CorruptedMemory {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let corrupted_positions = parse_lines(value, parse_position)?;
+        Ok(CorruptedMemory {
+            corrupted_positions,
+        })
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum MemoryValue {
+    Safe,
+    Corrupted,
+    Walked,
+}
+impl RenderImage for MemorySpace {
+    fn render_image(&self) -> Grid<Rgb> {
+        self.memory.map(MemoryValue::colour)
+    }
+}
+impl MemoryValue {
+    fn colour(&self) -> Rgb {
+        match self {
+            MemoryValue::Safe => [20, 60, 30],
+            MemoryValue::Corrupted => [230, 60, 60],
+            MemoryValue::Walked => [90, 160, 255],
+        }
+    }
+}
+impl Display for MemoryValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MemoryValue::Safe => write!(f, "{}", ".".bright_green()),
+            MemoryValue::Corrupted => write!(f, "{}", "#".bright_red()),
+            MemoryValue::Walked => write!(f, "{}", "O".bright_blue()),
+        }
+    }
+}
+/// Reads an `x,y` line from the puzzle input.
+fn parse_position(value: &str) -> Result<Point> {
+    let (x_str, y_str) = value
+        .trim()
+        .split_once(',')
+        .ok_or_else(|| Error::parse(value.trim(), "expected a position as x,y"))?;
+    Ok(Point::new(
+        parse_number(x_str, "x position")?,
+        parse_number(y_str, "y position")?,
+    ))
+}
@@ -0,0 +1,14 @@
+//! `size` stones engraved with numbers of one to six digits.
+
+use elves::Rng;
+
+/// The stones on one line, separated by spaces.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let stones: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let digits = rng.between(1, 6) as u32;
+            rng.between(0, 10_i64.pow(digits) - 1).to_string()
+        })
+        .collect();
+    stones.join(" ") + "\n"
+}
@@ -0,0 +1,298 @@
+//! Day 11: Plutonian Pebbles. Stones that split or change their engraving every blink, blinked
+//! one at a time with [`StoneArrangement::blink_once`] or only counted with
+//! [`StoneArrangement::blink_recursive`].
+#![warn(missing_docs)]
+
+use std::fmt::Display;
+
+use elves::{
+    config::DayConfig,
+    parse_lines,
+    parser::{int, map, pair, parse_all, separated, spaces, tag},
+    Error, Memo, Result, Rng, Solution,
+};
+use serde::Deserialize;
+
+pub mod generate;
+
+/// How long each part watches the stones, from `[2024.11]` in `aoc.toml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Blinks before part one counts the stones.
+    pub part_one_blinks: u64,
+    /// Blinks before part two counts the stones.
+    pub part_two_blinks: u64,
+}
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            part_one_blinks: 25,
+            part_two_blinks: 75,
+        }
+    }
+}
+impl DayConfig for Config {
+    /// Any number of blinks is accepted, none at all included. Part one keeps every stone,
+    /// so it runs out of memory long before part two, which only counts them, runs out of
+    /// `u64`: a count that doesn't fit is reported as no answer.
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// The day's [`Solution`]: the input is the stones' engravings, separated by spaces.
+pub struct DayEleven;
+impl Solution for DayEleven {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 11;
+    type Input = StoneArrangement;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        StoneArrangement::try_from(input)
+    }
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        let config: Config = Self::config()?;
+        let mut stones = input.clone();
+        for _ in 0..config.part_one_blinks {
+            stones.blink_once()?;
+        }
+        Ok(stones.stones.len())
+    }
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        let config: Config = Self::config()?;
+        input.blink_recursive(config.part_two_blinks)
+    }
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+elves::examples!(DayEleven);
+
+#[cfg(test)]
+mod tests {
+    use crate::{DayEleven, StoneArrangement, TimeStone};
+    use elves::{Error, Solution};
+
+    const TEST_STRING: &str = include_str!("../examples/blink-rules.txt");
+    const RESULT_STRING: &str = "1 2024 1 0 9 9 2021976";
+    #[test]
+    fn blink_once() {
+        let stones = StoneArrangement::try_from(TEST_STRING).unwrap();
+        println!("{}", stones);
+        let blinked_stone = stones
+            .stones
+            .iter()
+            .map(|stone| stone.blink().unwrap())
+            .fold(StoneArrangement { stones: vec![] }, |mut acc, x| {
+                acc.stones.extend(x);
+                acc
+            });
+        println!("{}", blinked_stone,);
+        assert!(RESULT_STRING == blinked_stone.to_string());
+    }
+
+    const TEST_STRING_2: &str = include_str!("../examples/example.txt");
+    const FIRST_BLINK: &str = "253000 1 7";
+    const SECOND_BLINK: &str = "253 0 2024 14168";
+    const THIRD_BLINK: &str = "512072 1 20 24 28676032";
+    const FOURTH_BLINK: &str = "512 72 2024 2 0 2 4 2867 6032";
+    const FIFTH_BLINK: &str = "1036288 7 2 20 24 4048 1 4048 8096 28 67 60 32";
+    const SIXTH_BLINK: &str =
+        "2097446912 14168 4048 2 0 2 4 40 48 2024 40 48 80 96 2 8 6 7 6 0 3 2";
+    #[test]
+    fn blink_six_times() {
+        let mut stones = StoneArrangement::try_from(TEST_STRING_2).unwrap();
+        println!("{}", stones);
+        for i in 0..6 {
+            stones.blink_once().unwrap();
+            println!("{}", stones);
+            match i {
+                0 => assert!(FIRST_BLINK == stones.to_string()),
+                1 => assert!(SECOND_BLINK == stones.to_string()),
+                2 => assert!(THIRD_BLINK == stones.to_string()),
+                3 => assert!(FOURTH_BLINK == stones.to_string()),
+                4 => assert!(FIFTH_BLINK == stones.to_string()),
+                5 => assert!(SIXTH_BLINK == stones.to_string()),
+                _ => panic!("Unexpected iteration"),
+            }
+        }
+    }
+    #[test]
+    fn blink_twenty_five_times() {
+        let mut stones = StoneArrangement::try_from(TEST_STRING_2).unwrap();
+        println!("{}", stones);
+        for _ in 0..25 {
+            stones.blink_once().unwrap();
+        }
+        assert_eq!(stones.stones.len(), 55312);
+    }
+    #[test]
+    fn blink_recursive() {
+        let stones = StoneArrangement::try_from(TEST_STRING_2).unwrap();
+        assert_eq!(stones.blink_recursive(25).unwrap(), 55312);
+    }
+    #[test]
+    fn rejects_stones_too_big_to_blink() {
+        let error = DayEleven::parse("125 17\n1 123456789012345678901234567").unwrap_err();
+        assert_eq!(
+            error.location(),
+            Some(elves::Location { line: 2, column: 3 })
+        );
+        let stones = DayEleven::parse("0012 1844674407370955161").unwrap();
+        assert_eq!(stones.to_string(), "12 1844674407370955161");
+        assert!(matches!(stones.blink_recursive(1), Err(Error::NoAnswer(_))));
+    }
+    #[test]
+    fn reports_counts_too_big_for_a_u64() {
+        let stones = StoneArrangement::try_from("125 17").unwrap();
+        assert!(matches!(
+            stones.blink_recursive(200),
+            Err(Error::NoAnswer(_))
+        ));
+    }
+    #[test]
+    fn blink_recursive_counts_like_blink_once() {
+        let arrangement = |stones: &[u64]| StoneArrangement {
+            stones: stones.iter().map(|stone| TimeStone::from(*stone)).collect(),
+        };
+        elves::differential::assert_agree(
+            |rng, size| {
+                let stones = (0..size).map(|_| rng.below(1_000_000) as u64).collect();
+                (stones, rng.below(size.min(15) + 1) as u64)
+            },
+            |(stones, blinks): &(Vec<u64>, u64)| {
+                let mut stones = arrangement(stones);
+                for _ in 0..*blinks {
+                    stones.blink_once().unwrap();
+                }
+                stones.stones.len() as u64
+            },
+            |(stones, blinks)| arrangement(stones).blink_recursive(*blinks).unwrap(),
+        );
+    }
+}
+/// The stones in a line, in order.
+#[derive(Clone, Debug)]
+pub struct StoneArrangement {
+    stones: Vec<TimeStone>,
+}
+impl StoneArrangement {
+    /// Blinks every stone once, keeping the new stones in order.
+    pub fn blink_once(&mut self) -> Result<()> {
+        let mut stones = vec![];
+        for stone in &self.stones {
+            stones.extend(stone.blink()?);
+        }
+        self.stones = stones;
+        Ok(())
+    }
+    /// Counts the stones after `blinks` blinks without keeping them in order, since the
+    /// order never changes how a stone blinks.
+    pub fn blink_recursive(&self, blinks: u64) -> Result<u64> {
+        let mut memo = Memo::new();
+        let mut count: u64 = 0;
+        for stone in &self.stones {
+            count = add_stones(count, stone.count_after(blinks, &mut memo)?)?;
+        }
+        Ok(count)
+    }
+}
+impl TryFrom<&str> for StoneArrangement {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let lines = parse_lines(value, |line| {
+            parse_all(
+                line,
+                separated(map(int::<u64>(), TimeStone::from), pair(tag(" "), spaces())),
+            )
+        })?;
+        Ok(StoneArrangement {
+            stones: lines.into_iter().flatten().collect(),
+        })
+    }
+}
+impl Display for TimeStone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.engraving)
+    }
+}
+impl Display for StoneArrangement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.stones
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<String>>()
+                .join(" ")
+        )
+    }
+}
+/// One stone and the number engraved on it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TimeStone {
+    engraving: u64,
+}
+/// `count` more stones, or no answer once there are more than a `u64` can count.
+fn add_stones(count: u64, more: u64) -> Result<u64> {
+    count
+        .checked_add(more)
+        .ok_or_else(|| Error::no_answer("more stones than fit in a u64"))
+}
+impl TimeStone {
+    /// How many stones this one has split into after `blinks` blinks.
+    fn count_after(&self, blinks: u64, memo: &mut Memo<(TimeStone, u64), u64>) -> Result<u64> {
+        if blinks == 0 {
+            return Ok(1);
+        }
+        if let Some(count) = memo.get(&(*self, blinks)) {
+            return Ok(count);
+        }
+        let mut count = 0;
+        for stone in self.blink()? {
+            count = add_stones(count, stone.count_after(blinks - 1, memo)?)?;
+        }
+        memo.insert((*self, blinks), count);
+        Ok(count)
+    }
+    /// The stones this one becomes after one blink: 0 becomes 1, an even number of digits
+    /// splits in half, and anything else is multiplied by 2024, which fails once the
+    /// engraving no longer fits in a `u64`.
+    pub fn blink(&self) -> Result<Vec<TimeStone>> {
+        let digits = self.engraving.checked_ilog10().map_or(1, |log| log + 1);
+        if self.engraving == 0 {
+            return Ok(vec![TimeStone::from(1)]);
+        }
+        if digits.is_multiple_of(2) {
+            let half = 10_u64.pow(digits / 2);
+            return Ok(vec![
+                TimeStone::from(self.engraving / half),
+                TimeStone::from(self.engraving % half),
+            ]);
+        }
+        match self.engraving.checked_mul(2024) {
+            Some(engraving) => Ok(vec![TimeStone::from(engraving)]),
+            None => Err(Error::no_answer(format!(
+                "the stone engraved {} grows too big to engrave",
+                self.engraving
+            ))),
+        }
+    }
+}
+impl From<&TimeStone> for u64 {
+    fn from(stone: &TimeStone) -> u64 {
+        stone.engraving
+    }
+}
+impl From<TimeStone> for u64 {
+    fn from(stone: TimeStone) -> u64 {
+        stone.engraving
+    }
+}
+impl From<u64> for TimeStone {
+    fn from(item: u64) -> Self {
+        TimeStone { engraving: item }
+    }
+}
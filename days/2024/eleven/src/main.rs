@@ -0,0 +1,3 @@
+fn main() -> std::process::ExitCode {
+    elves::solution::main(y2024_eleven::DayEleven)
+}
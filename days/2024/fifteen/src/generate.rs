@@ -0,0 +1,27 @@
+//! A `size` by `size` walled warehouse with boxes, a few walls inside and the robot, then
+//! `size` squared moves for it.
+
+use elves::{Grid, Rng};
+
+/// The warehouse map, a blank line, then the moves in lines of up to seventy.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(3);
+    let mut warehouse = Grid::new(size, size, '#');
+    for (x, y) in warehouse.coords() {
+        if x == 0 || y == 0 || x == size - 1 || y == size - 1 {
+            continue;
+        }
+        let tile = match rng.below(10) {
+            0 => '#',
+            1..=3 => 'O',
+            _ => '.',
+        };
+        warehouse.set((x, y), tile);
+    }
+    warehouse.set((1 + rng.below(size - 2), 1 + rng.below(size - 2)), '@');
+    let moves: Vec<char> = (0..size * size)
+        .map(|_| *rng.pick(&['<', '>', '^', 'v']))
+        .collect();
+    let moves: Vec<String> = moves.chunks(70).map(String::from_iter).collect();
+    format!("{}\n{}\n", warehouse, moves.join("\n"))
+}
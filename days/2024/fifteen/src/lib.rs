@@ -0,0 +1,339 @@
+//! Day 15: Warehouse Woes. A robot pushing rows of boxes around a warehouse, scored by the
+//! boxes' GPS coordinates once it's done. Only the narrow warehouse of part one is solved.
+#![warn(missing_docs)]
+
+use std::fmt::Display;
+
+use colored::Colorize;
+use elves::{
+    Animation, Coord, Dir4, Error, Grid, Render, RenderImage, Result, Rgb, Rng, Solution, Unsolved,
+};
+
+pub mod generate;
+
+/// The day's [`Solution`]: the input is the warehouse map, a blank line and the robot's moves.
+pub struct DayFifteen;
+impl Solution for DayFifteen {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 15;
+    type Input = (Warehouse, RobotInstructionSet);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut lines = input.trim().lines();
+        let warehouse = Warehouse::try_from(
+            lines
+                .by_ref()
+                .take_while(|l| !l.trim().is_empty())
+                .collect::<Vec<&str>>()
+                .join("\n")
+                .as_str(),
+        )?;
+        let instruction_set =
+            RobotInstructionSet::try_from(lines.collect::<Vec<&str>>().join("\n").as_str())?;
+        Ok((warehouse, instruction_set))
+    }
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        let (warehouse, instruction_set) = input;
+        let mut warehouse = warehouse.clone();
+        instruction_set
+            .instructions
+            .iter()
+            .for_each(|instruction| warehouse.move_robot(*instruction));
+        Ok(warehouse.gps_sum())
+    }
+    fn part_two(_input: &Self::Input) -> Result<impl Display> {
+        Ok(Unsolved)
+    }
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+    fn animate(input: &Self::Input, animation: &Animation) -> Result<bool> {
+        let (warehouse, instruction_set) = input;
+        animation.run(&mut warehouse.clone(), instruction_set.robot_moves())?;
+        Ok(true)
+    }
+    fn render(input: &Self::Input, render: &Render) -> Result<bool> {
+        let (warehouse, instruction_set) = input;
+        render.run(&mut warehouse.clone(), instruction_set.robot_moves())?;
+        Ok(true)
+    }
+}
+elves::examples!(DayFifteen);
+
+#[cfg(test)]
+mod tests {
+
+    const LARGER: &str = include_str!("../examples/larger.txt");
+    /// The larger example's warehouse map and robot moves.
+    fn larger() -> (&'static str, &'static str) {
+        LARGER.split_once("\n\n").unwrap()
+    }
+    #[test]
+    fn warehouse_map() {
+        let warehouse = super::Warehouse::try_from(larger().0).unwrap();
+        elves::assert_snapshot!("larger-warehouse", warehouse);
+        assert_eq!(warehouse.warehouse_width(), 10);
+        assert_eq!(warehouse.warehouse_height(), 10);
+    }
+    #[test]
+    fn robot_instruction_set() {
+        let instruction_set = super::RobotInstructionSet::try_from(larger().1).unwrap();
+        println!();
+        println!("{}", instruction_set);
+        assert_eq!(instruction_set.instructions.len(), 700);
+    }
+    const TEST_INPUT: &str = include_str!("../examples/small.txt");
+    const TEST_RESULT: &str = r#"
+    ########
+#....OO#
+##.....#
+#.....O#
+#.#O@..#
+#...O..#
+#...O..#
+########
+"#;
+    #[test]
+    fn move_warehouse_robot_small() {
+        // split lines at the empty one
+        //
+        let mut lines = TEST_INPUT.trim().lines();
+        let mut warehouse = super::Warehouse::try_from(
+            lines
+                .by_ref()
+                .take_while(|l| !l.trim().is_empty())
+                .collect::<Vec<&str>>()
+                .join("\n")
+                .as_str(),
+        )
+        .unwrap();
+        let instruction_set =
+            super::RobotInstructionSet::try_from(lines.collect::<Vec<&str>>().join("\n").as_str())
+                .unwrap();
+        println!();
+        println!("{}", warehouse);
+        println!();
+        println!("{}", instruction_set);
+        println!();
+        let frames = elves::snapshot::frames(&mut warehouse, 1, instruction_set.robot_moves());
+        elves::assert_snapshot!("small-moves", frames);
+        let test_result = super::Warehouse::try_from(TEST_RESULT).unwrap();
+        println!();
+        println!("EXPECTED Result");
+        println!("{}", test_result);
+        assert_eq!(warehouse.map, test_result.map);
+        assert_eq!(warehouse.gps_sum(), 2028);
+    }
+    const LARGE_TEST_RESULT: &str = r#"
+    ##########
+#.O.O.OOO#
+#........#
+#OO......#
+#OO@.....#
+#O#.....O#
+#O.....OO#
+#O.....OO#
+#OO....OO#
+##########
+"#;
+    #[test]
+    fn move_warehouse_robot_large() {
+        let mut warehouse = super::Warehouse::try_from(larger().0).unwrap();
+        let instruction_set = super::RobotInstructionSet::try_from(larger().1).unwrap();
+        println!();
+        println!("{}", warehouse);
+        println!();
+        println!("{}", instruction_set);
+        println!();
+        let frames = elves::snapshot::frames(&mut warehouse, 100, instruction_set.robot_moves());
+        elves::assert_snapshot!("larger-every-100-moves", frames);
+        let test_result = super::Warehouse::try_from(LARGE_TEST_RESULT).unwrap();
+        println!();
+        println!("EXPECTED Result");
+        println!("{}", test_result);
+        assert_eq!(warehouse.map, test_result.map);
+        assert_eq!(warehouse.gps_sum(), 10092);
+    }
+}
+/// The moves the robot tries, in order.
+pub struct RobotInstructionSet {
+    instructions: Vec<Dir4>,
+}
+impl RobotInstructionSet {
+    /// Every move, in order.
+    pub fn instructions(&self) -> &[Dir4] {
+        &self.instructions
+    }
+    /// Moves the robot once per step of an [`Animation`], until the instructions run out.
+    pub fn robot_moves(&self) -> impl FnMut(&mut Warehouse) -> bool + '_ {
+        let mut instructions = self.instructions.iter();
+        move |warehouse| {
+            instructions
+                .next()
+                .map(|instruction| warehouse.move_robot(*instruction))
+                .is_some()
+        }
+    }
+}
+/// The warehouse floor, with its walls, boxes and the robot.
+#[derive(Debug, Clone)]
+pub struct Warehouse {
+    map: Grid<WarehouseTile>,
+}
+impl Warehouse {
+    /// Tiles across, walls included.
+    pub fn warehouse_width(&self) -> usize {
+        self.map.width()
+    }
+    /// Tiles down, walls included.
+    pub fn warehouse_height(&self) -> usize {
+        self.map.height()
+    }
+    /// Every box's GPS coordinate, 100 times its row plus its column, added up.
+    pub fn gps_sum(&self) -> u64 {
+        self.map
+            .find_all(|tile| *tile == WarehouseTile::GoodsBox)
+            .map(gps_value)
+            .sum()
+    }
+    /// Moves the robot one tile, pushing any boxes in the way, unless a wall stops it.
+    pub fn move_robot(&mut self, instruction: Dir4) {
+        let robot_position = self.map.position(&WarehouseTile::Robot).unwrap();
+        let Some(new_robot_position) = self.map.step(robot_position, instruction) else {
+            return;
+        };
+        match self.map[new_robot_position] {
+            WarehouseTile::Empty => {
+                self.map.set(new_robot_position, WarehouseTile::Robot);
+                self.map.set(robot_position, WarehouseTile::Empty);
+            }
+            WarehouseTile::GoodsBox => {
+                // Start checking the space after the box
+                let mut current_position = new_robot_position;
+                // Calculate the next position, until we walk off the map
+                while let Some(next_position) = self.map.step(current_position, instruction) {
+                    // Check the tile at the next position
+                    match self.map[next_position] {
+                        WarehouseTile::Empty => {
+                            // We found an empty space, move the box and the robot
+                            self.map.set(next_position, WarehouseTile::GoodsBox);
+
+                            // Move the robot to the new position
+                            self.map.set(new_robot_position, WarehouseTile::Robot);
+                            self.map.set(robot_position, WarehouseTile::Empty);
+                            break; // Exit the loop as we've moved the box
+                        }
+                        WarehouseTile::GoodsBox => {
+                            // If the next space is another box, continue checking the next position
+                            current_position = next_position;
+                        }
+                        _ => break,
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+/// Goods positioning system coordinate of a box.
+fn gps_value((x, y): Coord) -> u64 {
+    (y as u64 * 100) + x as u64
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// What's on one tile of the warehouse.
+pub enum WarehouseTile {
+    /// A box the robot can push, `O`.
+    GoodsBox,
+    /// Open floor, `.`.
+    Empty,
+    /// A wall nothing moves through, `#`.
+    Wall,
+    /// The robot, `@`.
+    Robot,
+}
+
+/////////////////////
+// PARSING METHODS //
+// //////////////////
+impl TryFrom<&str> for Warehouse {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let map = Grid::parse(value)?;
+        if map.position(&WarehouseTile::Robot).is_none() {
+            return Err(Error::parse("", "the warehouse has no robot in it"));
+        }
+        Ok(Warehouse { map })
+    }
+}
+impl TryFrom<&str> for RobotInstructionSet {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let instructions = value
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| {
+                Dir4::try_from(c)
+                    .map_err(|_| Error::parse(c.to_string(), "invalid robot instruction"))
+            })
+            .collect::<Result<Vec<Dir4>>>()?;
+        Ok(RobotInstructionSet { instructions })
+    }
+}
+impl TryFrom<char> for WarehouseTile {
+    type Error = &'static str;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(WarehouseTile::Wall),
+            '.' => Ok(WarehouseTile::Empty),
+            'O' => Ok(WarehouseTile::GoodsBox),
+            '@' => Ok(WarehouseTile::Robot),
+            _ => Err("Invalid character"),
+        }
+    }
+}
+impl Display for Warehouse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.map)
+    }
+}
+impl RenderImage for Warehouse {
+    fn render_image(&self) -> Grid<Rgb> {
+        self.map.map(WarehouseTile::colour)
+    }
+}
+impl WarehouseTile {
+    fn colour(&self) -> Rgb {
+        match self {
+            WarehouseTile::Wall => [178, 34, 34],
+            WarehouseTile::Empty => [24, 24, 24],
+            WarehouseTile::GoodsBox => [230, 190, 40],
+            WarehouseTile::Robot => [80, 150, 255],
+        }
+    }
+}
+impl Display for WarehouseTile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            WarehouseTile::Wall => "#".red(),
+            WarehouseTile::Empty => ".".white(),
+            WarehouseTile::GoodsBox => "O".yellow(),
+            WarehouseTile::Robot => "@".bright_blue(),
+        };
+        write!(f, "{}", c)
+    }
+}
+impl Display for RobotInstructionSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for instruction in &self.instructions {
+            let arrow = instruction.to_string();
+            let c = match instruction {
+                Dir4::Up => arrow.bright_green(),
+                Dir4::Left => arrow.bright_blue(),
+                Dir4::Right => arrow.bright_magenta(),
+                Dir4::Down => arrow.bright_cyan(),
+            };
+            write!(f, "{}", c)?;
+        }
+        Ok(())
+    }
+}
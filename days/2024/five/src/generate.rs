@@ -0,0 +1,30 @@
+//! Page ordering rules for every pair out of a set of pages, then `size` updates of an odd
+//! number of those pages, about half of them already in order.
+
+use elves::Rng;
+
+/// The rules, a blank line, then the updates.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut order: Vec<u32> = (10..100).collect();
+    rng.shuffle(&mut order);
+    order.truncate(size.clamp(5, 40));
+    let mut rules = Vec::new();
+    for (i, before) in order.iter().enumerate() {
+        for after in &order[i + 1..] {
+            rules.push(format!("{}|{}\n", before, after));
+        }
+    }
+    rng.shuffle(&mut rules);
+    let updates = (0..size).map(|_| {
+        let length = 2 * rng.between(2, (order.len() as i64 - 1) / 2 + 1) as usize - 1;
+        let mut positions: Vec<usize> = (0..order.len()).collect();
+        rng.shuffle(&mut positions);
+        positions.truncate(length);
+        if rng.chance(50) {
+            positions.sort();
+        }
+        let pages: Vec<String> = positions.iter().map(|&i| order[i].to_string()).collect();
+        pages.join(",") + "\n"
+    });
+    rules.concat() + "\n" + &updates.collect::<String>()
+}
@@ -0,0 +1,251 @@
+//! Day 5: Print Queue. Page ordering rules like `47|53`, and updates that either follow every
+//! rule that applies to them or need putting in order with a [`SafetyGraph`].
+#![warn(missing_docs)]
+
+use std::{collections::HashMap, fmt::Display};
+
+use elves::{
+    check_lines, parse_lines,
+    parser::{int, map, parse_all, separated, separated_pair, tag},
+    Error, Location, Result, Rng, Solution,
+};
+
+pub mod generate;
+
+/// The puzzle, with the rules and the updates as its input.
+pub struct DayFive;
+impl Solution for DayFive {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 5;
+    type Input = (Vec<SafetyRule>, Vec<SafetyUpdate>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (rules, updates_start, updates) = sections(input);
+        let rule_list = parse_lines(rules, |line| SafetyRule::try_from(line))?;
+        let update_list = parse_lines(updates, |line| SafetyUpdate::try_from(line))
+            .map_err(|error| error.offset(updates_start))?;
+        Ok((rule_list, update_list))
+    }
+    fn validate(input: &str) -> Vec<Error> {
+        let (rules, updates_start, updates) = sections(input);
+        let mut errors = check_lines(rules, |line| SafetyRule::try_from(line));
+        errors.extend(
+            check_lines(updates, |line| SafetyUpdate::try_from(line))
+                .into_iter()
+                .map(|error| error.offset(updates_start)),
+        );
+        errors
+    }
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        let (rule_list, update_list) = input;
+        Ok(update_list
+            .iter()
+            .filter(|update| update.check_order(&SafetyGraph::new(rule_list, update)))
+            .map(|update| update.middle())
+            .sum::<u32>())
+    }
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        let (rule_list, update_list) = input;
+        Ok(update_list
+            .iter()
+            .filter_map(|update| {
+                let update_graph = SafetyGraph::new(rule_list, update);
+                if update.check_order(&update_graph) {
+                    return None;
+                }
+                let mut update = update.clone();
+                update.sort_by_graph(&update_graph);
+                Some(update.middle())
+            })
+            .sum::<u32>())
+    }
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+elves::examples!(DayFive);
+
+/// Splits the input at the blank line after the rules, into the rules, where the updates
+/// start and the updates. Without a blank line every line has to be a rule.
+pub fn sections(input: &str) -> (&str, Location, &str) {
+    let mut offset = 0;
+    for (index, line) in input.split_inclusive('\n').enumerate() {
+        if line.trim().is_empty() && !input[..offset].trim().is_empty() {
+            let start = Location {
+                line: index + 2,
+                column: 1,
+            };
+            return (&input[..offset], start, &input[offset + line.len()..]);
+        }
+        offset += line.len();
+    }
+    (input, Location { line: 1, column: 1 }, "")
+}
+
+/// `x|y`: page `x` has to be printed before page `y`.
+#[derive(Debug)]
+pub struct SafetyRule {
+    x: u32,
+    y: u32,
+}
+impl SafetyRule {
+    /// Whether the update has both pages, so the rule applies to it.
+    pub fn contains_x_and_y(&self, list: &SafetyUpdate) -> bool {
+        list.list.contains(&self.x) && list.list.contains(&self.y)
+    }
+}
+impl TryFrom<&str> for SafetyRule {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let rule = separated_pair(int(), tag("|"), int());
+        parse_all(value.trim(), map(rule, |(x, y)| SafetyRule { x, y }))
+    }
+}
+
+/// The rules that apply to one update, as how many of its pages must come before each page.
+#[derive(Debug)]
+pub struct SafetyGraph {
+    in_degree: HashMap<u32, usize>, // keeps track of how many numbers a given number depends on
+}
+impl SafetyGraph {
+    /// The graph of the rules in `rule_set` that apply to `update`.
+    pub fn new(rule_set: &[SafetyRule], update: &SafetyUpdate) -> Self {
+        let mut new_graph = SafetyGraph {
+            in_degree: HashMap::new(),
+        };
+        rule_set.iter().for_each(|rule| {
+            if rule.contains_x_and_y(update) {
+                *new_graph.in_degree.entry(rule.y).or_insert(0) += 1;
+                new_graph.in_degree.entry(rule.x).or_insert(0);
+            }
+        });
+        new_graph
+    }
+}
+
+/// The pages of one update, in the order they're printed.
+#[derive(Debug, Clone)]
+pub struct SafetyUpdate {
+    list: Vec<u32>,
+}
+impl SafetyUpdate {
+    /// The page in the middle of the update.
+    pub fn middle(&self) -> u32 {
+        let middle_point = self.list.len() / 2;
+        self.list[middle_point]
+    }
+    /// Whether the pages already follow the rules in `graph`.
+    pub fn check_order(&self, graph: &SafetyGraph) -> bool {
+        let mut lastlevel_of_nodes = None;
+        let mut in_order = true;
+        self.list.iter().for_each(|node| {
+            if let Some(node_level) = graph.in_degree.get(node) {
+                match lastlevel_of_nodes {
+                    Some(last_level) => {
+                        if node_level > last_level {
+                            lastlevel_of_nodes = Some(node_level);
+                        } else {
+                            in_order = false;
+                        }
+                    }
+                    None => {
+                        lastlevel_of_nodes = Some(node_level);
+                    }
+                }
+            }
+        });
+        in_order
+    }
+    /// Puts the pages in an order that follows the rules in `graph`.
+    pub fn sort_by_graph(&mut self, graph: &SafetyGraph) {
+        self.list.sort_by(|a, b| {
+            let a_level = graph.in_degree.get(a).unwrap();
+            let b_level = graph.in_degree.get(b).unwrap();
+            a_level.cmp(b_level)
+        });
+    }
+}
+impl TryFrom<&str> for SafetyUpdate {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let list = parse_all(value.trim(), separated(int(), tag(",")))?;
+        Ok(SafetyUpdate { list })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    const RULE_SET_TEST_STR: &str = "1|2";
+    const RULE_SET_TEST_STR_INVALID: &str = "1|2|3";
+    use super::*;
+    #[test]
+    fn test_rule_set() {
+        let rule_set = SafetyRule::try_from(RULE_SET_TEST_STR).unwrap();
+        assert_eq!(rule_set.x, 1);
+        assert_eq!(rule_set.y, 2);
+        let error = SafetyRule::try_from(RULE_SET_TEST_STR_INVALID).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 4: expected the end of the input, found \"|3\""
+        );
+    }
+    #[test]
+    fn reports_every_bad_line() {
+        let errors = DayFive::validate("47|53\n47 53\n\n75,47\n75,x\n75|29\n");
+        let errors: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            errors,
+            [
+                "line 2, column 3: expected \"|\", found \" 53\"",
+                "line 5, column 4: expected an integer, found \"x\"",
+                "line 6, column 3: expected the end of the input, found \"|29\"",
+            ]
+        );
+        assert!(DayFive::validate(EXAMPLE).is_empty());
+    }
+    /// Rules and updates, each test picks out the lines it can parse.
+    const EXAMPLE: &str = include_str!("../examples/example.txt");
+    #[test]
+    fn test_rule_set_list() {
+        let list = EXAMPLE
+            .split('\n')
+            .filter_map(|x| SafetyRule::try_from(x).ok())
+            .collect::<Vec<SafetyRule>>();
+        println!("List: {:?}", list);
+        let graph = SafetyGraph::new(&list, &SafetyUpdate { list: vec![47, 53] });
+        println!();
+        println!("In-degree");
+        for (k, v) in &graph.in_degree {
+            println!("Key: {}, Value: {:?}", k, v);
+        }
+    }
+    #[test]
+    fn test_update_list() {
+        let rule_list = EXAMPLE
+            .split('\n')
+            .filter_map(|x| SafetyRule::try_from(x).ok())
+            .collect::<Vec<SafetyRule>>();
+        let list = EXAMPLE.split('\n').collect::<Vec<&str>>();
+        let mut total = 0;
+        let mut unordered_total = 0;
+        for update in list {
+            let Ok(mut update) = SafetyUpdate::try_from(update) else {
+                continue;
+            };
+            let graph = SafetyGraph::new(&rule_list, &update);
+            let in_order = update.check_order(&graph);
+            println!();
+            println!("Update: {:?} is ordered: {}", update.list, in_order);
+            if in_order {
+                total += update.middle();
+            } else {
+                update.sort_by_graph(&graph);
+                println!("Sorted update: {:?}", update.list);
+                unordered_total += update.middle();
+            }
+            println!("Total: {}", total);
+        }
+        assert_eq!(total, 143);
+        assert_eq!(unordered_total, 123);
+    }
+}
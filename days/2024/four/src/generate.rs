@@ -0,0 +1,42 @@
+//! A `size` by `size` word search of the letters in XMAS, with some XMASes and X-MASes
+//! planted in it.
+
+use elves::{Dir8, Grid, Rng};
+
+/// The word search, a row per line.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(4);
+    let mut grid = Grid::new(size, size, 'X');
+    for coord in grid.coords() {
+        grid.set(coord, *rng.pick(&['X', 'M', 'A', 'S']));
+    }
+    for _ in 0..size {
+        let start = (rng.below(size), rng.below(size));
+        let direction = *rng.pick(&Dir8::ALL);
+        let mut coord = Some(start);
+        let mut word = Vec::new();
+        for letter in "XMAS".chars() {
+            let Some(at) = coord else { break };
+            word.push((at, letter));
+            coord = grid.step(at, direction);
+        }
+        if word.len() == 4 {
+            for (at, letter) in word {
+                grid.set(at, letter);
+            }
+        }
+    }
+    for _ in 0..size / 2 {
+        let (x, y) = (1 + rng.below(size - 2), 1 + rng.below(size - 2));
+        let (top, bottom) = match rng.chance(50) {
+            true => ('M', 'S'),
+            false => ('S', 'M'),
+        };
+        grid.set((x, y), 'A');
+        grid.set((x - 1, y - 1), top);
+        grid.set((x + 1, y - 1), top);
+        grid.set((x - 1, y + 1), bottom);
+        grid.set((x + 1, y + 1), bottom);
+    }
+    grid.to_string()
+}
@@ -0,0 +1,376 @@
+//! Day 4: Ceres Search. A word search, counting every XMAS in it in any of the eight
+//! directions with [`Grid`], then every X made of two MASes with [`ScannerGrid`].
+#![warn(missing_docs)]
+
+use std::fmt::Display;
+
+use elves::{Error, Result, Rng, Solution};
+
+pub mod generate;
+
+/// The puzzle, with the word search as a grid of letters.
+pub struct DayFour;
+impl Solution for DayFour {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+    type Input = elves::Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let letters = elves::Grid::parse(input)?;
+        if letters.is_empty() {
+            return Err(Error::parse("", "expected a word search"));
+        }
+        Ok(letters)
+    }
+    fn validate(input: &str) -> Vec<Error> {
+        let letters = input.trim();
+        if letters.is_empty() {
+            return vec![Error::parse("", "expected a word search")];
+        }
+        // Count lines from the top of the untrimmed input, like `Grid::parse`
+        let skipped = input[..input.len() - input.trim_start().len()]
+            .matches('\n')
+            .count();
+        let mut rows = letters.lines().map(|row| row.trim().chars().count());
+        let width = rows.next().unwrap_or(0);
+        rows.zip(skipped + 2..)
+            .filter(|&(found, _)| found != width)
+            .map(|(found, line)| {
+                Error::at(
+                    line,
+                    width.min(found) + 1,
+                    "",
+                    format!("line has {} letters, expected {}", found, width),
+                )
+            })
+            .collect()
+    }
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        let mut grid = Grid::from_rows(letter_rows(input));
+        grid.find_row_xmas();
+        grid.find_col_xmas();
+        grid.find_diagonal_xmas();
+        Ok(grid.xmas_counter)
+    }
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        let scanner_grid = ScannerGrid {
+            xmas_counter: 0,
+            rows: letter_rows(input),
+        };
+        Ok(scanner_grid.scan_sections().len())
+    }
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+elves::examples!(DayFour);
+
+fn letter_rows(letters: &elves::Grid<char>) -> Vec<Vec<char>> {
+    letters.rows().map(<[char]>::to_vec).collect()
+}
+
+/// One arm of an X-MAS.
+pub const XMASCROSS: [char; 3] = ['M', 'A', 'S'];
+/// One arm of an X-MAS, read backwards.
+pub const REV_XMASCROSS: [char; 3] = ['S', 'A', 'M'];
+
+/// A three by three square of the word search.
+#[derive(Debug)]
+pub struct GridSections {
+    /// The letters, row by row.
+    pub section: [[char; 3]; 3],
+}
+impl GridSections {
+    /// Whether both diagonals spell MAS, either way round.
+    pub fn validate_section(&self) -> bool {
+        let diag_one = [self.section[0][0], self.section[1][1], self.section[2][2]];
+
+        let diag_two = [self.section[0][2], self.section[1][1], self.section[2][0]];
+        if diag_one == XMASCROSS || diag_one == REV_XMASCROSS {
+            diag_two == XMASCROSS || diag_two == REV_XMASCROSS
+        } else {
+            false
+        }
+    }
+}
+
+/// The word search, scanned three by three for X-MASes.
+#[derive(Debug)]
+pub struct ScannerGrid {
+    /// Unused by the scan, which returns what it finds instead.
+    pub xmas_counter: u32,
+    /// The letters, row by row.
+    pub rows: Vec<Vec<char>>,
+}
+impl ScannerGrid {
+    /// Every three by three square that holds an X-MAS.
+    pub fn scan_sections(&self) -> Vec<GridSections> {
+        let mut sections = vec![];
+        let width = self.rows.first().map_or(0, Vec::len);
+        for i in 0..self.rows.len().saturating_sub(2) {
+            for j in 0..width.saturating_sub(2) {
+                let mut section = [[0 as char; 3]; 3];
+                for (k, section_row) in section.iter_mut().enumerate() {
+                    for (l, cell) in section_row.iter_mut().enumerate() {
+                        *cell = self.rows[i + k][j + l];
+                    }
+                }
+                let new_section = GridSections { section };
+                if new_section.validate_section() {
+                    sections.push(new_section);
+                }
+            }
+        }
+        sections
+    }
+    #[cfg(test)]
+    fn new_test() -> Self {
+        Self::from(include_str!("../examples/x-mas.txt"))
+    }
+}
+impl From<&str> for ScannerGrid {
+    fn from(grid_text: &str) -> Self {
+        let rows = grid_text
+            .split("\n")
+            .filter_map(|x| {
+                let row = x.chars().collect::<Vec<char>>();
+                if !row.is_empty() {
+                    Some(row)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        Self {
+            xmas_counter: 0,
+            rows,
+        }
+    }
+}
+
+type XmasType = [char; 4];
+const XMAS: XmasType = ['X', 'M', 'A', 'S'];
+const REVERSE_XMAS: XmasType = ['S', 'A', 'M', 'X'];
+
+/// The word search sliced every way XMAS can be written, so each slice is searched like a row.
+#[derive(Debug)]
+pub struct Grid {
+    /// The XMASes found so far.
+    pub xmas_counter: u32,
+    /// The letters, row by row.
+    pub rows: Vec<Vec<char>>,
+    /// The letters, column by column.
+    pub cols: Vec<Vec<char>>,
+    /// The letters along every diagonal, in both directions.
+    pub diagonals: Vec<Vec<char>>,
+}
+impl Default for Grid {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Grid {
+    /// An empty grid.
+    pub fn new() -> Self {
+        Self {
+            xmas_counter: 0,
+            rows: vec![],
+            cols: vec![],
+            diagonals: vec![],
+        }
+    }
+    /// Builds a grid along with its columns and both diagonal directions.
+    pub fn from_rows(rows: Vec<Vec<char>>) -> Self {
+        let mut grid = Self {
+            rows,
+            ..Self::new()
+        };
+        grid.build_columns_from_rows();
+        grid.build_diagonal_slices();
+        grid.build_opposite_diagonal_slices();
+        grid
+    }
+    /// The number of letters in each row.
+    pub fn width(&self) -> usize {
+        self.rows.first().map_or(0, Vec::len)
+    }
+    /// Fills in the columns from the rows.
+    pub fn build_columns_from_rows(&mut self) {
+        let mut cols = vec![];
+        for i in 0..self.width() {
+            let mut col = vec![];
+            for j in 0..self.rows.len() {
+                col.push(self.rows[j][i]);
+            }
+            cols.push(col);
+        }
+        self.cols = cols;
+    }
+    /// Adds the diagonals running down and to the right.
+    pub fn build_diagonal_slices(&mut self) {
+        let (width, height) = (self.width(), self.rows.len());
+        let mut slices = vec![];
+        for i in 0..height {
+            let mut slice = vec![];
+            for j in 0..width {
+                if i + j < height {
+                    slice.push(self.rows[i + j][j]);
+                }
+            }
+            slices.push(slice);
+        }
+        for i in 1..width {
+            let mut slice = vec![];
+            for j in 0..height {
+                if i + j < width {
+                    slice.push(self.rows[j][i + j]);
+                }
+            }
+            slices.push(slice);
+        }
+        self.diagonals.extend(slices);
+    }
+    /// Adds the diagonals running down and to the left.
+    pub fn build_opposite_diagonal_slices(&mut self) {
+        let (width, height) = (self.width(), self.rows.len());
+        let mut slices = vec![];
+        for i in 0..height {
+            let mut slice = vec![];
+            for j in 0..width {
+                if i + j < height {
+                    slice.push(self.rows[i + j][width - j - 1]);
+                }
+            }
+            slices.push(slice);
+        }
+        for i in 1..width {
+            let mut slice = vec![];
+            for j in 0..height {
+                if i + j < width {
+                    slice.push(self.rows[j][width - i - j - 1]);
+                }
+            }
+            slices.push(slice);
+        }
+        self.diagonals.extend(slices);
+    }
+    /// Counts the XMASes along the rows, forwards and backwards.
+    pub fn find_row_xmas(&mut self) {
+        self.rows.iter().for_each(|row| {
+            row.windows(4).for_each(|window| {
+                if window == XMAS || window == REVERSE_XMAS {
+                    self.xmas_counter += 1;
+                }
+            });
+        });
+    }
+    /// Counts the XMASes down the columns, forwards and backwards.
+    pub fn find_col_xmas(&mut self) {
+        self.cols.iter().for_each(|col| {
+            // println!("COL: {:?}", col);
+            col.windows(4).for_each(|x| {
+                // `println!("Window: {:?}", x);
+                if x == XMAS || x == REVERSE_XMAS {
+                    self.xmas_counter += 1;
+                }
+            });
+        });
+    }
+    /// Counts the XMASes along the diagonals, forwards and backwards.
+    pub fn find_diagonal_xmas(&mut self) {
+        self.diagonals.iter().for_each(|col| {
+            // println!("COL: {:?}", col);
+            col.windows(4).for_each(|x| {
+                // `println!("Window: {:?}", x);
+                if x == XMAS || x == REVERSE_XMAS {
+                    self.xmas_counter += 1;
+                }
+            });
+        });
+    }
+    /// Prints the rows with their numbers, for debugging.
+    pub fn print_rows(&self) {
+        let col_nums: Vec<char> = (0..self.width() as u32)
+            .map(|x| std::char::from_digit(x, 10).unwrap())
+            .collect();
+        println!("C {:?}", col_nums);
+        self.rows.iter().enumerate().for_each(|(i, row)| {
+            println!("{} {:?}", i, row);
+        });
+    }
+    /// Prints the columns, for debugging.
+    pub fn print_cols(&self) {
+        let col_nums: Vec<u32> = (0..self.cols.len() as u32).collect();
+        println!("{:?}", col_nums);
+        for col in &self.cols {
+            println!("{:?}", col);
+        }
+    }
+    /// Prints the diagonals, for debugging.
+    pub fn print_diagonals(&self) {
+        let col_nums: Vec<u32> = (0..self.diagonals.len() as u32).collect();
+        println!("{:?}", col_nums);
+        for col in &self.diagonals {
+            println!("{:?}", col);
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn text_to_grid() {
+        let grid_text = include_str!("../examples/larger.txt");
+        let grid_text = grid_text.trim().split("\n").collect::<Vec<&str>>();
+        let mut empty_grid = super::Grid::new();
+        grid_text.iter().for_each(|x| {
+            let new_char = x.chars().collect::<Vec<char>>();
+            empty_grid.rows.push(new_char.clone());
+        });
+        empty_grid.build_columns_from_rows();
+        empty_grid.build_diagonal_slices();
+        empty_grid.build_opposite_diagonal_slices();
+        println!();
+        println!("Rows");
+        empty_grid.print_rows();
+        println!();
+        println!("Cols");
+        empty_grid.print_cols();
+        println!("Diagonals");
+        empty_grid.print_diagonals();
+
+        empty_grid.find_row_xmas();
+        empty_grid.find_col_xmas();
+        empty_grid.find_diagonal_xmas();
+
+        assert_eq!(empty_grid.xmas_counter, 18);
+    }
+    #[test]
+    fn x_mas_sections() {
+        let scanner_grid = super::ScannerGrid::new_test();
+        assert_eq!(scanner_grid.scan_sections().len(), 9);
+        let scanner_grid = super::ScannerGrid::from(include_str!("../examples/larger.txt"));
+        assert_eq!(scanner_grid.scan_sections().len(), 9);
+    }
+    #[test]
+    fn searches_grids_of_any_shape() {
+        use elves::Solution;
+        assert!(super::DayFour::parse("").is_err());
+        let ragged = super::DayFour::parse("XMAS\nXMA\n").unwrap_err();
+        assert_eq!(ragged.location().map(|location| location.line), Some(2));
+        assert_eq!(super::DayFour::validate("\n").len(), 1);
+        let lines: Vec<_> = super::DayFour::validate("\nXMAS\nXMA\nXMAS\nXMASX")
+            .iter()
+            .filter_map(|error| error.location())
+            .map(|location| (location.line, location.column))
+            .collect();
+        assert_eq!(lines, [(3, 4), (5, 5)]);
+
+        let tall = super::DayFour::parse("X..\nM.M\nAA.\nS.S").unwrap();
+        assert_eq!(super::DayFour::part_one(&tall).unwrap().to_string(), "1");
+        assert_eq!(super::DayFour::part_two(&tall).unwrap().to_string(), "1");
+        let wide = super::DayFour::parse("XMASAMX").unwrap();
+        assert_eq!(super::DayFour::part_one(&wide).unwrap().to_string(), "2");
+        assert_eq!(super::DayFour::part_two(&wide).unwrap().to_string(), "0");
+    }
+}
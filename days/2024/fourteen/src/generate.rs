@@ -0,0 +1,22 @@
+//! `size` robots anywhere on the 101 by 103 bathroom floor, moving up to 99 tiles a second
+//! each way.
+
+use elves::Rng;
+
+use crate::Config;
+
+/// One `p=x,y v=dx,dy` line per robot.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let Config { width, height, .. } = Config::default();
+    (0..size)
+        .map(|_| {
+            format!(
+                "p={},{} v={},{}\n",
+                rng.between(0, width as i64 - 1),
+                rng.between(0, height as i64 - 1),
+                rng.between(-99, 99),
+                rng.between(-99, 99)
+            )
+        })
+        .collect()
+}
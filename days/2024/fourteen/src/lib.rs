@@ -0,0 +1,413 @@
+//! Day 14: Restroom Redoubt. Robots wrapping around a bathroom floor, scored by how many end
+//! up in each quadrant, until they line up into a Christmas tree.
+#![warn(missing_docs)]
+
+use std::{collections::HashSet, fmt::Display};
+
+use colored::Colorize;
+use elves::{
+    config::DayConfig,
+    parse_lines,
+    parser::{int, key_value, parse_all, separated_pair, tag},
+    Animation, Error, Grid, Render, RenderImage, Result, Rgb, Rng, Solution,
+};
+use serde::Deserialize;
+
+pub mod generate;
+
+/// The floor's size and how long part one waits, from `[2024.14]` in `aoc.toml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Tiles across the floor.
+    pub width: i32,
+    /// Tiles down the floor.
+    pub height: i32,
+    /// Seconds the robots move for before part one checks the quadrants.
+    pub seconds: i32,
+}
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            width: 101,
+            height: 103,
+            seconds: 100,
+        }
+    }
+}
+impl DayConfig for Config {
+    fn validate(&self) -> Result<()> {
+        if self.width <= 0 || self.height <= 0 {
+            return Err(Error::config(
+                "the floor needs to be at least one tile wide and high",
+            ));
+        }
+        if self.width.checked_mul(self.height).is_none() {
+            return Err(Error::config("the floor has too many tiles to search"));
+        }
+        if self.seconds < 0 {
+            return Err(Error::config("the robots can't move for negative seconds"));
+        }
+        Ok(())
+    }
+}
+impl Config {
+    /// A floor this size with these robots on it.
+    pub fn floor(&self, robots: Vec<GuardRobot>) -> BathroomFloor {
+        BathroomFloor::new(self.width, self.height, robots)
+    }
+}
+
+/// The day's [`Solution`]: the input is one robot's position and velocity per line.
+pub struct DayFourteen;
+impl Solution for DayFourteen {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 14;
+    type Input = Vec<GuardRobot>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut index = 0;
+        parse_lines(input, |line| {
+            index += 1;
+            GuardRobot::try_from((index - 1, line))
+        })
+    }
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        let config: Config = Self::config()?;
+        let mut floor = config.floor(input.clone());
+        floor.move_robots(config.seconds);
+        Ok(floor.safety_factor())
+    }
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        let mut floor = Self::config::<Config>()?.floor(input.clone());
+        Ok(floor.find_easter_egg())
+    }
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+    /// Plays the robots second by second, stopping on the easter egg.
+    fn animate(input: &Self::Input, animation: &Animation) -> Result<bool> {
+        let mut floor = Self::config::<Config>()?.floor(input.clone());
+        let step = floor.until_easter_egg();
+        animation.run(&mut floor, step)?;
+        Ok(true)
+    }
+    /// The easter egg as a still, or every second leading up to it as a GIF.
+    fn render(input: &Self::Input, render: &Render) -> Result<bool> {
+        let mut floor = Self::config::<Config>()?.floor(input.clone());
+        let step = floor.until_easter_egg();
+        render.run(&mut floor, step)?;
+        Ok(true)
+    }
+}
+elves::examples!(DayFourteen);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const ROBOT_INSTRUCTIONS: &str = include_str!("../examples/example.txt");
+    #[test]
+    fn robot_paths() {
+        println!();
+        let robots: Vec<GuardRobot> = ROBOT_INSTRUCTIONS
+            .lines()
+            .enumerate()
+            .map(|line| GuardRobot::try_from(line).unwrap())
+            .collect();
+        let mut floor = BathroomFloor {
+            height: 7,
+            width: 11,
+            robots,
+            quadrants: vec![],
+        };
+        floor.get_quadrants();
+        println!("{}", floor);
+        assert_eq!(floor.robots.len(), 12);
+        floor.move_robots(100);
+        println!("{}", floor);
+        floor.fill_quadrants();
+        let safety_factor = floor
+            .quadrants
+            .iter()
+            .enumerate()
+            .fold(1, |acc, (i, quadrant)| {
+                println!("Quadrant: {}", i);
+                println!("Robots: {}", quadrant.robots.len());
+                println!();
+                acc * quadrant.robots.len()
+            });
+        println!();
+        println!("Safety Factor: {}", safety_factor);
+        println!();
+        assert_eq!(safety_factor, 12);
+    }
+    #[test]
+    fn safety_factor() {
+        let robots = DayFourteen::parse(ROBOT_INSTRUCTIONS).unwrap();
+        let mut floor = BathroomFloor::new(11, 7, robots);
+        floor.move_robots(100);
+        assert_eq!(floor.safety_factor(), 12);
+    }
+    #[test]
+    fn rejects_empty_floors() {
+        let config = |width, height| Config {
+            width,
+            height,
+            ..Config::default()
+        };
+        assert!(config(11, 7).validate().is_ok());
+        assert!(matches!(config(0, 7).validate(), Err(Error::Config(_))));
+        assert!(matches!(config(11, -1).validate(), Err(Error::Config(_))));
+    }
+}
+
+/// One corner of the floor, not counting the middle row and column, with the robots in it.
+#[derive(Debug)]
+pub struct BathroomQuadrant {
+    start: RobotPosition,
+    end: RobotPosition,
+    robots: Vec<GuardRobot>,
+}
+/// The floor and every robot on it.
+pub struct BathroomFloor {
+    width: i32,
+    height: i32,
+    robots: Vec<GuardRobot>,
+    quadrants: Vec<BathroomQuadrant>,
+}
+impl BathroomFloor {
+    /// A `width` by `height` floor with these robots on it.
+    pub fn new(width: i32, height: i32, robots: Vec<GuardRobot>) -> Self {
+        BathroomFloor {
+            width,
+            height,
+            robots,
+            quadrants: vec![],
+        }
+    }
+    /// Multiplies together how many robots ended up in each quadrant.
+    pub fn safety_factor(&mut self) -> usize {
+        self.get_quadrants();
+        self.fill_quadrants();
+        self.quadrants
+            .iter()
+            .fold(1, |acc, quadrant| acc * quadrant.robots.len())
+    }
+    /// Robots loop back to their start after `width * height` seconds. The tree
+    /// bunches most of them into one quadrant, which gives the lowest safety factor.
+    pub fn find_easter_egg(&mut self) -> i32 {
+        let mut min_safety_factor = usize::MAX;
+        let mut easter_egg_second = 0;
+        for second in 1..=self.width * self.height {
+            self.move_robots(1);
+            let safety_factor = self.safety_factor();
+            if safety_factor < min_safety_factor {
+                min_safety_factor = safety_factor;
+                easter_egg_second = second;
+            }
+        }
+        easter_egg_second
+    }
+    /// Moves the robots a second per step, until they show the easter egg.
+    pub fn until_easter_egg(&self) -> impl FnMut(&mut BathroomFloor) -> bool {
+        let easter_egg =
+            BathroomFloor::new(self.width, self.height, self.robots.clone()).find_easter_egg();
+        let mut second = 0;
+        move |floor| {
+            if second == easter_egg {
+                return false;
+            }
+            floor.move_robots(1);
+            second += 1;
+            true
+        }
+    }
+    /// Moves every robot `times` seconds, wrapping around the edges.
+    pub fn move_robots(&mut self, times: i32) {
+        for robot in self.robots.iter_mut() {
+            robot.move_x_times(times, (self.width, self.height));
+        }
+    }
+    fn fill_quadrants(&mut self) {
+        for robot in self.robots.iter() {
+            for quadrant in self.quadrants.iter_mut() {
+                let robot_x = robot.position.x;
+                let robot_y = robot.position.y;
+                if robot_x >= quadrant.start.x
+                    && robot_x <= quadrant.end.x
+                    && robot_y >= quadrant.start.y
+                    && robot_y <= quadrant.end.y
+                {
+                    quadrant.robots.push(*robot);
+                }
+            }
+        }
+    }
+    /// Forgets which robots were counted in each quadrant.
+    pub fn empty_quadrants(&mut self) {
+        for quadrant in self.quadrants.iter_mut() {
+            quadrant.robots.clear();
+        }
+    }
+    fn get_quadrants(&mut self) {
+        let mut quadrants: Vec<BathroomQuadrant> = vec![];
+        let top_left = BathroomQuadrant {
+            start: RobotPosition { x: 0, y: 0 },
+            end: RobotPosition {
+                x: self.width / 2 - 1,
+                y: self.height / 2 - 1,
+            },
+            robots: Vec::new(),
+        };
+        let top_right = BathroomQuadrant {
+            start: RobotPosition {
+                x: self.width / 2 + 1,
+                y: 0,
+            },
+            end: RobotPosition {
+                x: self.width,
+                y: self.height / 2 - 1,
+            },
+            robots: Vec::new(),
+        };
+        let bottom_left = BathroomQuadrant {
+            start: RobotPosition {
+                x: 0,
+                y: self.height / 2 + 1,
+            },
+            end: RobotPosition {
+                x: self.width / 2 - 1,
+                y: self.height,
+            },
+            robots: Vec::new(),
+        };
+        let bottom_right = BathroomQuadrant {
+            start: RobotPosition {
+                x: self.width / 2 + 1,
+                y: self.height / 2 + 1,
+            },
+            end: RobotPosition {
+                x: self.width,
+                y: self.height,
+            },
+            robots: Vec::new(),
+        };
+        quadrants.push(top_left);
+        quadrants.push(top_right);
+        quadrants.push(bottom_left);
+        quadrants.push(bottom_right);
+        self.quadrants = quadrants;
+    }
+}
+impl RenderImage for BathroomFloor {
+    fn render_image(&self) -> Grid<Rgb> {
+        let mut image = Grid::new(self.width as usize, self.height as usize, [0, 0, 0]);
+        for robot in &self.robots {
+            image.set(
+                (robot.position.x as usize, robot.position.y as usize),
+                [60, 220, 90],
+            );
+        }
+        image
+    }
+}
+impl Display for BathroomFloor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let robots: HashSet<(i32, i32)> = self
+            .robots
+            .iter()
+            .map(|robot| (robot.position.x, robot.position.y))
+            .collect();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                match robots.contains(&(x, y)) {
+                    true => write!(f, "{}", "O".bright_green())?,
+                    false => write!(f, "{}", " ".black())?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+/// A robot and the line of the input it came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GuardRobot {
+    index: i32,
+    position: RobotPosition,
+    velocity: RobotVelocity,
+}
+impl GuardRobot {
+    /// Where the robot is now.
+    pub fn position(&self) -> RobotPosition {
+        self.position
+    }
+    fn move_x_times(&mut self, times: i32, bounds: (i32, i32)) {
+        // Widened, so a long wait can't overflow before wrapping around the floor
+        let moved = |position: i32, velocity: i32, bound: i32| {
+            (position as i64 + velocity as i64 * times as i64).rem_euclid(bound as i64) as i32
+        };
+        self.position.x = moved(self.position.x, self.velocity.x, bounds.0);
+        self.position.y = moved(self.position.y, self.velocity.y, bounds.1);
+    }
+}
+impl TryFrom<(usize, &str)> for GuardRobot {
+    type Error = Error;
+    fn try_from((index, line): (usize, &str)) -> Result<Self, Self::Error> {
+        let xy = || separated_pair(int(), tag(","), int());
+        let robot = separated_pair(
+            key_value("p", "=", xy()),
+            tag(" "),
+            key_value("v", "=", xy()),
+        );
+        let ((x, y), (dx, dy)) = parse_all(line.trim(), robot)?;
+        Ok(Self {
+            index: index as i32,
+            position: RobotPosition { x, y },
+            velocity: RobotVelocity { x: dx, y: dy },
+        })
+    }
+}
+impl Display for GuardRobot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Robot: {}", self.index)?;
+        writeln!(f, "{}", self.position)?;
+        writeln!(f, "{}", self.velocity)
+    }
+}
+/// Tiles from the top left corner of the floor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RobotPosition {
+    /// Tiles from the left.
+    pub x: i32,
+    /// Tiles from the top.
+    pub y: i32,
+}
+impl Display for RobotPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "At     X: {}, Y: {}",
+            self.x.to_string().blue(),
+            self.y.to_string().blue()
+        )
+    }
+}
+/// Tiles a robot moves each second.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RobotVelocity {
+    /// Towards the right.
+    pub x: i32,
+    /// Downwards.
+    pub y: i32,
+}
+impl Display for RobotVelocity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Moving X: {}, Y: {} u/s",
+            self.x.to_string().green(),
+            self.y.to_string().green()
+        )
+    }
+}
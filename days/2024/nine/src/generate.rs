@@ -0,0 +1,15 @@
+//! A disk map of `size` files, each one to nine blocks with up to nine free blocks after it.
+
+use elves::Rng;
+
+/// The disk map on one line.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut disk_map = String::new();
+    for file in 0..size.max(1) {
+        if file > 0 {
+            disk_map.push_str(&rng.between(0, 9).to_string());
+        }
+        disk_map.push_str(&rng.between(1, 9).to_string());
+    }
+    disk_map + "\n"
+}
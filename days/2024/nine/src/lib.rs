@@ -0,0 +1,416 @@
+//! Day 9: Disk Fragmenter. A dense disk map unpacked into blocks, compacted block by block
+//! with [`RamMemory`] and file by file with [`second_part_try_two`].
+#![warn(missing_docs)]
+
+use colored::Colorize;
+use elves::{Error, Result, Rng, Solution};
+use std::{collections::HashSet, fmt::Display};
+
+pub mod generate;
+mod second_try;
+
+pub use second_try::second_part_try_two;
+
+/// The day's [`Solution`]: the input is the disk map, digits only.
+pub struct DayNine;
+impl Solution for DayNine {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 9;
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        match Self::validate(input).into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(input.trim().to_string()),
+        }
+    }
+    fn validate(input: &str) -> Vec<Error> {
+        let mut lines = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty());
+        let Some((index, disk_map)) = lines.next() else {
+            return vec![Error::parse("", "expected a disk map")];
+        };
+        let indent = disk_map.chars().take_while(|c| c.is_whitespace()).count();
+        let mut errors: Vec<Error> = disk_map
+            .trim()
+            .chars()
+            .enumerate()
+            .filter(|(_, c)| !c.is_ascii_digit())
+            .map(|(column, c)| {
+                Error::at(
+                    index + 1,
+                    indent + column + 1,
+                    c,
+                    "expected a block size digit",
+                )
+            })
+            .collect();
+        errors.extend(lines.map(|(index, line)| {
+            Error::parse(line.trim(), "expected the disk map on one line").on_line(index + 1, line)
+        }));
+        errors
+    }
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        let mut memory_block = RamMemory::new(input);
+        memory_block.swap_memory();
+        Ok(memory_block.compute_checksum())
+    }
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        Ok(second_part_try_two(input))
+    }
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+elves::examples!(DayNine);
+
+#[cfg(test)]
+mod tests {
+    use crate::{MemoryLayout, MemoryManager};
+
+    const TEST_DISK_MAP: &str = include_str!("../examples/example.txt");
+    const TEST_RESULT: &str = "00...111...2...333.44.5555.6666.777.888899";
+    #[test]
+    fn read_disk_map() {
+        let memory_manager = MemoryManager::from(TEST_DISK_MAP);
+        let memory_blocks = memory_manager.memory_blocks();
+
+        println!();
+        println!("Compact Memory");
+        println!();
+        println!("{}", TEST_DISK_MAP);
+        println!();
+        println!();
+        println!("Memory Blocks");
+        println!();
+        println!("{}", memory_blocks);
+        println!();
+        let mem_string: String = memory_blocks.into();
+        assert_eq!(&mem_string, TEST_RESULT);
+    }
+    const SECOND_TEST_RESULT: &str = "0099811188827773336446555566..............";
+    #[test]
+    fn swap_memory() {
+        let memory_manager = MemoryManager::from(TEST_DISK_MAP);
+        let mut memory_blocks = memory_manager.memory_blocks();
+        println!("{}", memory_blocks);
+        memory_blocks.swap_memory();
+        println!();
+        let mem_string: String = memory_blocks.into();
+        println!("{}", mem_string);
+        assert_eq!(&mem_string, SECOND_TEST_RESULT);
+    }
+    #[test]
+    fn compute_checksum() {
+        let memory_manager = MemoryManager::from(TEST_DISK_MAP);
+        let mut memory_blocks = memory_manager.memory_blocks();
+        println!("{}", memory_blocks);
+        memory_blocks.swap_memory();
+        println!();
+        let checksum = memory_blocks.compute_checksum();
+        println!("Checksum: {}", checksum);
+        assert_eq!(checksum, 1928);
+    }
+    #[test]
+    fn moves_whole_files_like_the_naive_mover() {
+        let mut memory = MemoryManager::from(TEST_DISK_MAP).memory_blocks();
+        memory.move_whole_files();
+        assert_eq!(memory.checksum_with_gaps(), 2858);
+        elves::differential::assert_agree(
+            |rng, size| {
+                let disk_map = crate::generate::input(rng, size);
+                disk_map
+                    .trim()
+                    .bytes()
+                    .map(|digit| digit - b'0')
+                    .collect::<Vec<u8>>()
+            },
+            |digits| {
+                let disk_map: String = digits.iter().map(u8::to_string).collect();
+                let mut memory = MemoryManager::from(disk_map.as_str()).memory_blocks();
+                memory.move_whole_files();
+                memory.checksum_with_gaps()
+            },
+            |digits| {
+                let disk_map: String = digits.iter().map(u8::to_string).collect();
+                crate::second_part_try_two(&disk_map)
+            },
+        );
+    }
+    #[test]
+    fn handles_empty_disks_and_points_at_bad_digits() {
+        use elves::Solution;
+        for disk_map in ["0", "00"] {
+            let disk_map = crate::DayNine::parse(disk_map).unwrap();
+            assert_eq!(
+                crate::DayNine::part_one(&disk_map).unwrap().to_string(),
+                "0"
+            );
+            assert_eq!(
+                crate::DayNine::part_two(&disk_map).unwrap().to_string(),
+                "0"
+            );
+        }
+        let error = crate::DayNine::parse("\n  12x4").unwrap_err();
+        assert_eq!(
+            error.location(),
+            Some(elves::Location { line: 2, column: 5 })
+        );
+        assert!(crate::DayNine::parse("\n").is_err());
+        let columns: Vec<_> = crate::DayNine::validate("1x2 4\n56")
+            .iter()
+            .filter_map(|error| error.location())
+            .map(|location| (location.line, location.column))
+            .collect();
+        assert_eq!(columns, [(1, 2), (1, 4), (2, 1)]);
+    }
+    #[test]
+    fn swap_files() {
+        let memory_manager = MemoryManager::from(TEST_DISK_MAP);
+        println!();
+        println!("Memory Layout");
+        println!();
+        println!("{}", memory_manager.memory_blocks());
+        let mut swap_files = memory_manager.memory_layout.iter().collect::<Vec<_>>();
+        swap_files.sort_by_key(|a| a.id());
+        swap_files.chunks_mut(2).for_each(|chunk| {
+            chunk.sort_by_key(|a| a.order());
+        });
+        // Each file or free run as its file id and size, so whole files can be moved
+        let mut runs: Vec<(Option<u64>, u64)> = swap_files
+            .iter()
+            .map(|block| match block {
+                MemoryLayout::FileBlock(block) => (Some(block.memory_id), block.block_size),
+                MemoryLayout::EmptyBlock(block) => (None, block.block_size),
+            })
+            .collect();
+        let last_id = runs.iter().filter_map(|(id, _)| *id).max().unwrap();
+
+        for id in (0..=last_id).rev() {
+            let right_index = runs.iter().position(|(file, _)| *file == Some(id)).unwrap();
+            let size = runs[right_index].1;
+            let next_empty_block_that_fits = runs[..right_index]
+                .iter()
+                .position(|(file, free)| file.is_none() && *free >= size);
+            let Some(left_index) = next_empty_block_that_fits else {
+                continue;
+            };
+            runs[right_index].0 = None;
+            runs[left_index].1 -= size;
+            runs.insert(left_index, (Some(id), size));
+        }
+        let layout: String = runs
+            .iter()
+            .map(|(file, size)| {
+                let block = file.map_or(".".to_string(), |id| id.to_string());
+                block.repeat(*size as usize)
+            })
+            .collect();
+        assert_eq!(layout, "00992111777.44.333....5555.6666.....8888..");
+    }
+}
+/// A disk unpacked one block at a time, each block free or part of a file.
+#[derive(Clone)]
+pub struct RamMemory {
+    memory: Vec<MemChar>,
+}
+impl RamMemory {
+    /// Unpacks a disk map, whose digits alternate between file and free space sizes.
+    pub fn new(disk_map: &str) -> RamMemory {
+        MemoryManager::from(disk_map).memory_blocks()
+    }
+    /// Moves file blocks one at a time from the end of the disk into the leftmost free block.
+    pub fn swap_memory(&mut self) {
+        let mut left_index = 0;
+        let mut right_index = self.memory.len().saturating_sub(1);
+        while left_index < right_index {
+            if let MemChar::Empty = self.memory[left_index] {
+                while left_index < right_index && self.memory[right_index] == MemChar::Empty {
+                    right_index -= 1;
+                }
+                if left_index < right_index {
+                    self.memory.swap(left_index, right_index);
+                }
+            }
+            left_index += 1;
+        }
+    }
+
+    /// Moves whole files one block at a time, highest id first, into the leftmost run of free
+    /// blocks before them that fits: slow, but too simple to get wrong.
+    #[cfg(test)]
+    fn move_whole_files(&mut self) {
+        let last_file = self.memory.iter().filter_map(MemChar::file).max();
+        for id in (0..=last_file.unwrap_or(0)).rev() {
+            let Some(start) = self.memory.iter().position(|c| c.file() == Some(id)) else {
+                continue;
+            };
+            let size = self.memory.iter().filter(|c| c.file() == Some(id)).count();
+            let gap = (0..start).find(|&gap| {
+                gap + size <= start
+                    && self.memory[gap..gap + size]
+                        .iter()
+                        .all(|c| c.file().is_none())
+            });
+            if let Some(gap) = gap {
+                self.memory[gap..gap + size].fill(MemChar::File(id));
+                self.memory[start..start + size].fill(MemChar::Empty);
+            }
+        }
+    }
+    /// Like [`RamMemory::compute_checksum`], but counting the positions of free blocks too, for
+    /// disks that still have gaps between the files.
+    #[cfg(test)]
+    fn checksum_with_gaps(&self) -> u64 {
+        self.memory
+            .iter()
+            .enumerate()
+            .filter_map(|(position, c)| Some(position as u64 * c.file()?))
+            .sum()
+    }
+
+    /// Adds up each file block's position times its file id, once the disk is compacted.
+    pub fn compute_checksum(&self) -> u64 {
+        self.memory
+            .iter()
+            .filter_map(|mem_char| match mem_char {
+                MemChar::File(mem_id) => Some(mem_id),
+                _ => None,
+            })
+            .enumerate()
+            .fold(0_u64, |mut acc, (position, mem_char)| {
+                acc += position as u64 * mem_char;
+                acc
+            })
+    }
+}
+impl From<RamMemory> for String {
+    fn from(val: RamMemory) -> Self {
+        val.memory.iter().fold(String::new(), |mut acc, mem_char| {
+            acc.push_str(&mem_char.to_string());
+            acc
+        })
+    }
+}
+impl Display for RamMemory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.memory.iter().for_each(|mem_char| {
+            let _ = write!(f, "{}", mem_char);
+        });
+        writeln!(f)
+    }
+}
+#[derive(Eq, PartialEq, Hash, Clone)]
+enum MemChar {
+    Empty,
+    File(u64),
+}
+impl MemChar {
+    #[cfg(test)]
+    fn file(&self) -> Option<u64> {
+        match self {
+            MemChar::File(id) => Some(*id),
+            MemChar::Empty => None,
+        }
+    }
+}
+impl Display for MemChar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MemChar::Empty => write!(f, "{}", ".".yellow()),
+            MemChar::File(file_char) => write!(f, "{}", file_char.to_string().green()),
+        }
+    }
+}
+#[derive(Eq, PartialEq, Hash, Debug)]
+struct MemoryBlock {
+    memory_id: u64,
+    block_size: u64,
+}
+#[derive(Eq, PartialEq, Hash, Debug)]
+enum MemoryLayout {
+    FileBlock(MemoryBlock),
+    EmptyBlock(MemoryBlock),
+}
+impl MemoryLayout {
+    fn block(&self) -> Vec<MemChar> {
+        match self {
+            MemoryLayout::FileBlock(block) => {
+                vec![MemChar::File(block.memory_id); block.block_size.try_into().unwrap()]
+            }
+            MemoryLayout::EmptyBlock(block) => {
+                vec![MemChar::Empty; block.block_size.try_into().unwrap()]
+            }
+        }
+    }
+    fn id(&self) -> u64 {
+        match self {
+            MemoryLayout::FileBlock(block) => block.memory_id,
+            MemoryLayout::EmptyBlock(block) => block.memory_id,
+        }
+    }
+    #[cfg(test)]
+    fn order(&self) -> u64 {
+        match self {
+            MemoryLayout::FileBlock(_) => 0,
+            MemoryLayout::EmptyBlock(_) => 1,
+        }
+    }
+}
+struct MemoryManager {
+    memory_layout: HashSet<MemoryLayout>,
+}
+impl MemoryManager {
+    fn memory_blocks(&self) -> RamMemory {
+        let mut file_blocks = self
+            .memory_layout
+            .iter()
+            .filter(|block| matches!(block, MemoryLayout::FileBlock(_)))
+            .collect::<Vec<_>>();
+        let mut raw_mem = RamMemory { memory: vec![] };
+        file_blocks.sort_by_key(|a| a.id());
+        file_blocks.iter().for_each(|file_block| {
+            raw_mem.memory.extend(file_block.block());
+            if let Some(empty_block) = self
+                .memory_layout
+                .iter()
+                .find(|block| block.id() == file_block.id() && block != file_block)
+            {
+                raw_mem.memory.extend(empty_block.block());
+            }
+        });
+        raw_mem
+    }
+}
+impl From<&str> for MemoryManager {
+    fn from(s: &str) -> Self {
+        let chars = s.chars().collect::<Vec<char>>();
+        let mut memory_id = 0;
+        let memory_layout = chars
+            .iter()
+            .enumerate()
+            .fold(HashSet::new(), |mut acc, (i, c)| {
+                if *c == '\n' {
+                    return acc;
+                }
+                if let Ok(block_size) = c.to_string().parse::<u64>() {
+                    if i % 2 == 0 {
+                        acc.insert(MemoryLayout::FileBlock(MemoryBlock {
+                            memory_id,
+                            block_size,
+                        }));
+                    } else {
+                        acc.insert(MemoryLayout::EmptyBlock(MemoryBlock {
+                            memory_id,
+                            block_size,
+                        }));
+                        memory_id += 1;
+                    }
+                }
+                acc
+            });
+        MemoryManager { memory_layout }
+    }
+}
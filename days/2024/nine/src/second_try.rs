@@ -1,11 +1,13 @@
 use std::fmt::Display;
 
 use colored::Colorize;
-pub fn second_part_try_two(input: &str) {
-    let chars = input.chars().collect::<Vec<char>>();
+/// The checksum after moving each whole file, highest id first, into the leftmost gap before it
+/// that fits.
+pub fn second_part_try_two(input: &str) -> u64 {
+    let chars = input.trim().chars().collect::<Vec<char>>();
     let mut disk_blocks = Vec::new();
     chars.chunks(2).enumerate().for_each(|(position, pair)| {
-        if let Some(first) = pair.get(0) {
+        if let Some(first) = pair.first() {
             let size = first.to_digit(10).expect("Invalid size") as u64;
             let position = position as u64;
             let disk_block = DiskBlock {
@@ -26,66 +28,36 @@ pub fn second_part_try_two(input: &str) {
             disk_blocks.push(disk_block);
         }
     });
-    disk_blocks.sort_by(|a, b| a.position.cmp(&b.position));
-    disk_blocks.chunks_mut(2).for_each(|pair| {
-        pair.sort_by(|a, b| b.file.cmp(&a.file));
-    });
-
-    let mut right_index = disk_blocks.len() as u64;
-    let mut left_index = 0;
 
-    while right_index > left_index {
-        let (last_file_pos, last_file_block) = disk_blocks
+    // Try every file once, highest id first, against the leftmost gap that fits it.
+    // A moved file leaves a gap behind that only files further right could use,
+    // and those have all been tried already, so gaps never need merging.
+    let last_file = disk_blocks.len().div_ceil(2) as u64;
+    for file_id in (0..last_file).rev() {
+        let file_pos = disk_blocks
             .iter()
-            .enumerate()
-            .rev()
-            .skip(disk_blocks.len() - right_index as usize)
-            .find(|(_, block)| block.file)
+            .position(|block| block.file && block.position == file_id)
             .expect("No file found");
-        let last_block_size = last_file_block.size;
-        if let Some((empty_pos, empty_block)) = disk_blocks
+        let file_size = disk_blocks[file_pos].size;
+        let Some(empty_pos) = disk_blocks
             .iter()
-            .enumerate()
-            .take(last_file_pos)
-            .find(|(_, block)| !block.file && block.size >= last_file_block.size)
-        {
-            let empty_block_size = empty_block.size;
-            let empty_block_position = empty_block.position;
-            if empty_block_size == last_block_size {
-                disk_blocks.swap(empty_pos, last_file_pos);
-            }
-            if empty_block_size > last_block_size {
-                // Create a new empty block with the remaining size
-                let new_empty_block = DiskBlock {
-                    position: empty_block_position, // Adjust the position of the remainder
-                    size: empty_block_size - last_block_size,
-                    file: false,
-                };
-                // Update the original empty block to reflect the size of the file block
-                let updated_empty_block = DiskBlock {
-                    position: last_file_pos as u64,
-                    size: last_block_size, // Size of the file block
-                    file: false,
-                };
-                // Swap the file block with the original empty block
-                disk_blocks.swap(empty_pos, last_file_pos);
-                // Update the swapped empty block's size
-                disk_blocks[last_file_pos] = updated_empty_block;
-                // Insert the new empty block after the swapped file block
-                disk_blocks.insert(empty_pos + 1, new_empty_block);
-            }
-            if left_index < empty_block_position {
-                left_index = empty_block_position;
-            }
-            if left_index == 0 {
-                left_index = empty_block_position;
-            }
-        } else {
-            right_index -= last_block_size;
+            .take(file_pos)
+            .position(|block| !block.file && block.size >= file_size)
+        else {
             continue;
-        }
-        right_index -= last_block_size;
+        };
+        let file_block = std::mem::replace(
+            &mut disk_blocks[file_pos],
+            DiskBlock {
+                position: file_id,
+                size: file_size,
+                file: false,
+            },
+        );
+        disk_blocks[empty_pos].size -= file_size;
+        disk_blocks.insert(empty_pos, file_block);
     }
+
     let mut raw_mem = Vec::new();
     disk_blocks.iter().for_each(|block| {
         for _ in 0..block.size {
@@ -96,49 +68,26 @@ pub fn second_part_try_two(input: &str) {
             }
         }
     });
-    let total = raw_mem
+    raw_mem
         .iter()
         .enumerate()
         .fold(0, |mut acc, (index, mem_char)| {
-            print_different_color_for_digits(*mem_char);
             acc += mem_char * index as u64;
             acc
-        });
-    println!("Total: {}", total);
-}
-
-fn print_different_color_for_digits(digit: u64) {
-    let mod_2 = digit % 2;
-    let mod_3 = digit % 3;
-    let mod_5 = digit % 5;
-    let mod_7 = digit % 7;
-    if digit == 0 {
-        print!("{}", ".".to_string().white());
-    } else if mod_2 == 0 {
-        print!("{}", digit.to_string().green());
-    } else if mod_3 == 0 {
-        print!("{}", digit.to_string().blue());
-    } else if mod_5 == 0 {
-        print!("{}", digit.to_string().yellow());
-    } else if mod_7 == 0 {
-        print!("{}", digit.to_string().red());
-    } else {
-        print!("{}", digit.to_string().purple());
-    }
+        })
 }
 
 #[cfg(test)]
 mod tests {
     use super::DiskBlock;
 
-    const TEST_DISK_MAP: &str = "2333133121414131402";
-    const TEST_RESULT: &str = "00...111...2...333.44.5555.6666.777.888899";
+    const TEST_DISK_MAP: &str = include_str!("../examples/example.txt");
     #[test]
     fn second_disk_read() {
-        let chars = TEST_DISK_MAP.chars().collect::<Vec<char>>();
+        let chars = TEST_DISK_MAP.trim().chars().collect::<Vec<char>>();
         let mut disk_blocks = Vec::new();
         chars.chunks(2).enumerate().for_each(|(position, pair)| {
-            if let Some(first) = pair.get(0) {
+            if let Some(first) = pair.first() {
                 let size = first.to_digit(10).expect("Invalid size") as u64;
                 let position = position.to_string().parse().expect("Invalid position");
                 let disk_block = DiskBlock {
@@ -159,9 +108,9 @@ mod tests {
                 disk_blocks.push(disk_block);
             }
         });
-        disk_blocks.sort_by(|a, b| a.position.cmp(&b.position));
+        disk_blocks.sort_by_key(|a| a.position);
         disk_blocks.chunks_mut(2).for_each(|pair| {
-            pair.sort_by(|a, b| b.file.cmp(&a.file));
+            pair.sort_by_key(|b| std::cmp::Reverse(b.file));
         });
 
         let mut right_index = disk_blocks.len() as u64;
@@ -239,6 +188,13 @@ mod tests {
         println!("Total: {}", total);
         assert_eq!(2858, total);
     }
+    #[test]
+    fn move_whole_files() {
+        assert_eq!(super::second_part_try_two(TEST_DISK_MAP), 2858);
+        // Neither file fits into the gaps to its left
+        assert_eq!(super::second_part_try_two("12345"), 132);
+        assert_eq!(super::second_part_try_two("1313165"), 169);
+    }
 }
 #[derive(Debug)]
 pub struct DiskBlock {
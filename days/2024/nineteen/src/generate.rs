@@ -0,0 +1,30 @@
+//! A few towel patterns, then `size` designs. Most designs are made of the patterns, the
+//! rest of any stripes at all.
+
+use elves::Rng;
+
+const STRIPES: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+
+/// The patterns on one line, a blank line, then a design per line.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut patterns: Vec<String> = (0..size.clamp(3, 400))
+        .map(|_| stripes(rng, 1, 4))
+        .collect();
+    patterns.sort();
+    patterns.dedup();
+    let designs: Vec<String> = (0..size)
+        .map(|_| match rng.chance(70) {
+            true => (0..rng.between(2, 8))
+                .map(|_| rng.pick(&patterns).as_str())
+                .collect(),
+            false => stripes(rng, 5, 30),
+        })
+        .collect();
+    format!("{}\n\n{}\n", patterns.join(", "), designs.join("\n"))
+}
+
+fn stripes(rng: &mut Rng, shortest: i64, longest: i64) -> String {
+    (0..rng.between(shortest, longest))
+        .map(|_| *rng.pick(&STRIPES))
+        .collect()
+}
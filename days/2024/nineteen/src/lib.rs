@@ -0,0 +1,239 @@
+//! Day 19: Linen Layout. Towel designs made by laying striped patterns end to end, checked for
+//! whether they can be made and counted for every way they can.
+#![warn(missing_docs)]
+
+use std::fmt::Display;
+
+use colored::Colorize;
+use elves::{parse_lines, Error, Memo, Result, Rng, Solution};
+
+pub mod generate;
+
+/// The day's [`Solution`]: the input is the patterns on one line, a blank line and a design per
+/// line.
+pub struct DayNineteen;
+impl Solution for DayNineteen {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 19;
+    type Input = TowelDesigns;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        TowelDesigns::try_from(input)
+    }
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        Ok(input
+            .designs
+            .iter()
+            .filter_map(|design| OnsenTowel::build_towel_return(design, &input.patterns))
+            .count())
+    }
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        Ok(input
+            .designs
+            .iter()
+            .map(|design| OnsenTowel::count_ways_to_build_towel(design, &input.patterns))
+            .sum::<usize>())
+    }
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+elves::examples!(DayNineteen);
+
+/// The available towel patterns, followed by the designs the onsen wants made from them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TowelDesigns {
+    /// Patterns there are as many towels of as needed.
+    pub patterns: Vec<TowelPattern>,
+    /// Designs, as their stripe letters.
+    pub designs: Vec<String>,
+}
+impl TryFrom<&str> for TowelDesigns {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut patterns = Vec::new();
+        let mut designs = Vec::new();
+        parse_lines(value, |line| {
+            if patterns.is_empty() {
+                patterns = line
+                    .split(',')
+                    .map(|p| TowelPattern::try_from(p.trim()))
+                    .collect::<Result<_>>()?;
+            } else {
+                TowelPattern::try_from(line)?;
+                designs.push(line.to_string());
+            }
+            Ok(())
+        })?;
+        Ok(TowelDesigns { patterns, designs })
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const ONSEN_TOWELS: &str = include_str!("../examples/example.txt");
+    #[test]
+    fn onsen_patterns() -> Result<(), &'static str> {
+        let input_str = ONSEN_TOWELS.trim().lines();
+        let pattern_line = input_str.clone().next().ok_or("No pattern line")?;
+        let patterns = pattern_line
+            .split(",")
+            .filter_map(|p| TowelPattern::try_from(p.trim()).ok())
+            .collect::<Vec<_>>();
+        patterns.iter().for_each(|p| println!("{}", p));
+        let targets = input_str
+            .skip(1)
+            .filter_map(|s| {
+                let s = s.trim();
+                if s.is_empty() {
+                    None
+                } else {
+                    let variations = OnsenTowel::count_ways_to_build_towel(s, &patterns);
+                    Some(variations)
+                }
+            })
+            .sum::<usize>();
+        assert_eq!(targets, 16);
+        let designs = TowelDesigns::try_from(ONSEN_TOWELS).unwrap();
+        assert_eq!(DayNineteen::part_one(&designs).unwrap().to_string(), "6");
+        Ok(())
+    }
+}
+/// A design made out of patterns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OnsenTowel {
+    /// The patterns laid end to end, in order.
+    pub pattern: Vec<TowelPattern>,
+}
+impl OnsenTowel {
+    /// Every distinct order of patterns that makes `target`.
+    pub fn count_ways_to_build_towel(target: &str, patterns: &[TowelPattern]) -> usize {
+        Self::count_ways_from(0, target, patterns, &mut Memo::new())
+    }
+    /// Ways to build the rest of `target`, from `start` on.
+    fn count_ways_from(
+        start: usize,
+        target: &str,
+        patterns: &[TowelPattern],
+        memo: &mut Memo<usize, usize>,
+    ) -> usize {
+        if start == target.len() {
+            return 1; // There's one way to build an empty string (no patterns)
+        }
+        memo.get_or_insert_with(start, |memo| {
+            patterns
+                .iter()
+                .filter(|pattern| {
+                    let end = start + pattern.stripes.len();
+                    target
+                        .get(start..end)
+                        .and_then(|stripes| TowelPattern::try_from(stripes).ok())
+                        .is_some_and(|towel_pattern| towel_pattern == **pattern)
+                })
+                .map(|pattern| {
+                    Self::count_ways_from(start + pattern.stripes.len(), target, patterns, memo)
+                })
+                .sum()
+        })
+    }
+    /// One way to make `target` out of `patterns`, if there is any.
+    pub fn build_towel_return(target: &str, patterns: &[TowelPattern]) -> Option<Self> {
+        let target_len = target.len();
+        let mut dp = vec![None; target_len + 1];
+        dp[0] = Some(vec![]); // Initialize with an empty pattern to start the process
+
+        // Iterate through each position in the target string
+        for i in 1..=target_len {
+            // Check each smaller pattern
+            for pattern in patterns {
+                let pattern_len = pattern.stripes.len();
+                if i >= pattern_len && dp[i - pattern_len].is_some() {
+                    let pattern_str = &target[i - pattern_len..i];
+                    if let Ok(towel_pattern) = TowelPattern::try_from(pattern_str) {
+                        if towel_pattern == *pattern && dp[i - pattern_len].is_some() {
+                            // If dp[i - pattern_len] is valid, append this pattern to it
+                            let mut new_pattern_sequence =
+                                dp[i - pattern_len].as_ref().unwrap().clone();
+                            new_pattern_sequence.push(towel_pattern.clone());
+                            dp[i] = Some(new_pattern_sequence);
+                            break; // No need to check further for this i
+                        }
+                    }
+                }
+            }
+        }
+
+        // If dp[target_len] is Some, return the sequence of patterns
+        dp[target_len].clone().map(|pattern_sequence| Self {
+            pattern: pattern_sequence,
+        })
+    }
+}
+
+/// Stripes on one towel, in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TowelPattern {
+    stripes: Vec<TowelStripe>,
+}
+impl TryFrom<&str> for TowelPattern {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            return Err(Error::parse("", "empty towel pattern"));
+        }
+        Ok(Self {
+            stripes: value
+                .chars()
+                .map(|c| {
+                    TowelStripe::try_from(c).map_err(|error| Error::parse(c.to_string(), error))
+                })
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+impl Display for TowelPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.stripes
+            .iter()
+            .try_for_each(|stripe| write!(f, "{}", stripe))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The colour of one stripe.
+pub enum TowelStripe {
+    /// `w`.
+    White,
+    /// `b`.
+    Black,
+    /// `u`.
+    Blue,
+    /// `r`.
+    Red,
+    /// `g`.
+    Green,
+}
+impl TryFrom<char> for TowelStripe {
+    type Error = &'static str;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'w' => Ok(TowelStripe::White),
+            'b' => Ok(TowelStripe::Black),
+            'u' => Ok(TowelStripe::Blue),
+            'r' => Ok(TowelStripe::Red),
+            'g' => Ok(TowelStripe::Green),
+            _ => Err("invalid stripe colour"),
+        }
+    }
+}
+impl Display for TowelStripe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TowelStripe::White => write!(f, "{}", "w".white()),
+            TowelStripe::Black => write!(f, "{}", "b".cyan()),
+            TowelStripe::Blue => write!(f, "{}", "u".blue()),
+            TowelStripe::Red => write!(f, "{}", "r".red()),
+            TowelStripe::Green => write!(f, "{}", "g".green()),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+//! Two lists of location ids, `size` lines long, with plenty of ids on both lists.
+
+use elves::Rng;
+
+/// A line per pair of ids, three spaces apart like the real input.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let ids: Vec<i64> = (0..size.max(1))
+        .map(|_| rng.between(10000, 99999))
+        .collect();
+    (0..size)
+        .map(|_| {
+            let left = *rng.pick(&ids);
+            let right = match rng.chance(60) {
+                true => *rng.pick(&ids),
+                false => rng.between(10000, 99999),
+            };
+            format!("{}   {}\n", left, right)
+        })
+        .collect()
+}
@@ -0,0 +1,70 @@
+//! Day 1: Historian Hysteria. Two lists of location ids, compared by the distance between
+//! their sorted ids and by how often each id on the left turns up on the right.
+#![warn(missing_docs)]
+
+use std::{collections::HashMap, fmt::Display};
+
+use elves::{check_lines, parse_lines, parse_number, Error, Result, Rng, Solution};
+
+pub mod generate;
+
+/// The puzzle, with both lists as its input, parsed by [`parse_pair`] line by line.
+pub struct DayOne;
+impl Solution for DayOne {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+    type Input = (Vec<i32>, Vec<i32>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let pairs = parse_lines(input, parse_pair)?;
+        Ok(pairs.into_iter().unzip())
+    }
+    fn validate(input: &str) -> Vec<Error> {
+        check_lines(input, parse_pair)
+    }
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        let (mut id_list_a, mut id_list_b) = input.clone();
+        id_list_a.sort();
+        id_list_b.sort();
+        Ok(id_list_a
+            .iter()
+            .zip(id_list_b.iter())
+            .map(|(id_number_a, id_number_b)| (id_number_a - id_number_b).abs())
+            .sum::<i32>())
+    }
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        let (id_list_a, id_list_b) = input;
+        let mut hash_map_a = HashMap::new();
+        let mut hash_map_b = HashMap::new();
+        id_list_a
+            .iter()
+            .for_each(|id| *hash_map_a.entry(id).or_insert(0) += 1);
+        id_list_b
+            .iter()
+            .for_each(|id| *hash_map_b.entry(id).or_insert(0) += 1);
+        Ok(hash_map_b
+            .iter()
+            .filter_map(|(item_key, item_times_seen)| {
+                let value = hash_map_a.get(item_key)?;
+                let similarity = item_times_seen * value * *item_key;
+                Some(similarity)
+            })
+            .sum::<i32>())
+    }
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+elves::examples!(DayOne);
+
+/// One line of the two lists, a location id from each.
+pub fn parse_pair(line: &str) -> Result<(i32, i32)> {
+    let split_line: Vec<&str> = line.split_whitespace().collect();
+    let [a, b] = split_line[..] else {
+        return Err(Error::parse(line, "expected two location ids"));
+    };
+    Ok((
+        parse_number::<i32>(a, "location id")?,
+        parse_number::<i32>(b, "location id")?,
+    ))
+}
@@ -0,0 +1,28 @@
+//! `size` calibration equations of two to six operands, most of which some mix of `+`, `*`
+//! and `||` makes true.
+
+use elves::Rng;
+
+/// One `result: operands` line per equation.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let operands: Vec<u64> = (0..rng.between(2, 6))
+                .map(|_| rng.between(1, 99) as u64)
+                .collect();
+            let result =
+                match rng.chance(70) {
+                    true => operands[1..].iter().fold(operands[0], |total, &operand| {
+                        match rng.below(3) {
+                            0 => total + operand,
+                            1 => total * operand,
+                            _ => format!("{}{}", total, operand).parse().unwrap_or(total),
+                        }
+                    }),
+                    false => rng.between(1, 1_000_000) as u64,
+                };
+            let operands: Vec<String> = operands.iter().map(u64::to_string).collect();
+            format!("{}: {}\n", result, operands.join(" "))
+        })
+        .collect()
+}
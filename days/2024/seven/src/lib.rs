@@ -0,0 +1,308 @@
+//! Day 7: Bridge Repair. Calibration equations missing their operators, true when some mix
+//! of `+`, `*` and, for part two, `||` evaluated left to right gives the test value.
+#![warn(missing_docs)]
+
+use std::{collections::HashSet, fmt::Display};
+
+use elves::{check_lines, parallel, parse_lines, parse_number, Error, Result, Rng, Solution};
+
+pub mod generate;
+
+/// The puzzle, with a [`BridgeEquation`] per line as its input.
+pub struct DaySeven;
+impl Solution for DaySeven {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 7;
+    type Input = Vec<BridgeEquation>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(input, |line| BridgeEquation::try_from(line))
+    }
+    fn validate(input: &str) -> Vec<Error> {
+        check_lines(input, |line| BridgeEquation::try_from(line))
+    }
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        part_one_parallel(input).ok_or_else(too_big)
+    }
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        part_two_parallel(input).ok_or_else(too_big)
+    }
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+elves::examples!(DaySeven);
+
+fn too_big() -> Error {
+    Error::no_answer("the test values add up to more than fit in a u64")
+}
+
+/// The sum of the test values `+` and `*` can make, one equation after another, or `None`
+/// if it doesn't fit in a `u64`.
+pub fn part_one_single_thread(equations: &[BridgeEquation]) -> Option<u64> {
+    let mut total: u64 = 0;
+    for eq in equations.iter().cloned() {
+        if let Some(result) = eq.check_operation() {
+            total = total.checked_add(result)?;
+        }
+    }
+    Some(total)
+}
+/// The sum of the test values `+`, `*` and `||` can make, one equation after another, or
+/// `None` if it doesn't fit in a `u64`.
+pub fn part_two_single_thread(equations: &[BridgeEquation]) -> Option<u64> {
+    let mut total: u64 = 0;
+    for eq in equations.iter().cloned() {
+        if let Some(result) = eq.check_complex_operation() {
+            total = total.checked_add(result)?;
+        }
+    }
+    Some(total)
+}
+/// [`part_one_single_thread`], with the equations spread over [`parallel`]'s threads.
+pub fn part_one_parallel(equations: &[BridgeEquation]) -> Option<u64> {
+    parallel::map_reduce(
+        equations,
+        |eq| Some(eq.clone().check_operation().unwrap_or(0)),
+        || Some(0),
+        |a, b| a?.checked_add(b?),
+    )
+}
+/// [`part_two_single_thread`], with the equations spread over [`parallel`]'s threads.
+pub fn part_two_parallel(equations: &[BridgeEquation]) -> Option<u64> {
+    parallel::map_reduce(
+        equations,
+        |eq| Some(eq.clone().check_complex_operation().unwrap_or(0)),
+        || Some(0),
+        |a, b| a?.checked_add(b?),
+    )
+}
+
+#[derive(Debug)]
+enum Operator {
+    Add((u64, u64)),
+    Multiply((u64, u64)),
+    Cat((u64, u64)),
+}
+impl Operator {
+    /// The result, or `None` if it overflows: it's then past any test value, so that
+    /// branch can never reach one.
+    fn operate(&self) -> Option<u64> {
+        match *self {
+            Self::Add((a, b)) => a.checked_add(b),
+            Self::Multiply((a, b)) => a.checked_mul(b),
+            Self::Cat((a, b)) => {
+                let digits = b.checked_ilog10().map_or(1, |log| log + 1);
+                a.checked_mul(10u64.checked_pow(digits)?)?.checked_add(b)
+            }
+        }
+    }
+}
+
+/// The numbers on the right of an equation.
+#[derive(Debug, Clone)]
+pub struct OperandList {
+    operands: Vec<u64>,
+}
+impl OperandList {
+    fn simple_operation(&self, target: u64) -> bool {
+        let mut results = HashSet::new();
+        self.operands.iter().for_each(|x| {
+            if results.is_empty() {
+                results.insert(*x);
+                return;
+            }
+            let mut new_results = HashSet::new();
+            results.iter().for_each(|y| {
+                new_results.extend(Operator::Add((*x, *y)).operate());
+                new_results.extend(Operator::Multiply((*x, *y)).operate());
+            });
+            results = new_results;
+            if results.contains(&target) || results.iter().max().unwrap() > &target {}
+        });
+        results.contains(&target)
+    }
+    fn complex_operation(&self, target: u64) -> bool {
+        let mut results = HashSet::new();
+        self.operands.iter().for_each(|x| {
+            if results.is_empty() {
+                results.insert(*x);
+                return;
+            }
+            let mut new_results = HashSet::new();
+            results.iter().for_each(|y| {
+                new_results.extend(Operator::Add((*x, *y)).operate());
+                new_results.extend(Operator::Multiply((*x, *y)).operate());
+                new_results.extend(Operator::Cat((*y, *x)).operate());
+            });
+            results = new_results;
+            if results.contains(&target) || results.iter().max().unwrap() > &target {}
+        });
+        results.contains(&target)
+    }
+}
+impl TryFrom<&str> for OperandList {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let operands = value
+            .split_whitespace()
+            .map(|x| parse_number(x, "operand"))
+            .collect::<Result<_>>()?;
+        Ok(Self { operands })
+    }
+}
+
+/// One `result: operands` line.
+#[derive(Debug, Clone)]
+pub struct BridgeEquation {
+    result: u64,
+    operands: OperandList,
+}
+impl Display for BridgeEquation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "\x1b[32m{}\x1b[0m: {}",
+            self.result,
+            self.operands
+                .operands
+                .iter()
+                .map(|x| format!("\x1b[34m{}\x1b[0m", x)) // Blue for each operand
+                .collect::<Vec<_>>()
+                .join(" ")
+        )
+    }
+}
+impl BridgeEquation {
+    /// The test value, if `+` and `*` can make it.
+    pub fn check_operation(self) -> Option<u64> {
+        if self.operands.simple_operation(self.result) {
+            Some(self.result)
+        } else {
+            None
+        }
+    }
+    /// The test value, if `+`, `*` and `||` can make it.
+    pub fn check_complex_operation(self) -> Option<u64> {
+        if self.operands.complex_operation(self.result) {
+            Some(self.result)
+        } else {
+            None
+        }
+    }
+}
+impl TryFrom<&str> for BridgeEquation {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (ruslt_str, oprands_str) = value
+            .split_once(": ")
+            .ok_or_else(|| Error::parse(value, "expected `result: operands`"))?;
+        let result = parse_number(ruslt_str, "test value")?;
+        let operands = OperandList::try_from(oprands_str)?;
+        Ok(Self { result, operands })
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_STR_PART_ONE: &str = include_str!("../examples/example.txt");
+    #[test]
+    fn read_equations() {
+        let equations = TEST_STR_PART_ONE
+            .lines()
+            .filter_map(|x| BridgeEquation::try_from(x).ok())
+            .collect::<Vec<_>>();
+        equations.iter().for_each(|x| println!("{:?}", x));
+        assert_eq!(equations.len(), 9);
+    }
+    #[test]
+    fn operate_equations() {
+        let equations = TEST_STR_PART_ONE
+            .lines()
+            .filter_map(|x| BridgeEquation::try_from(x).ok())
+            .collect::<Vec<_>>();
+        equations.iter().for_each(|x| println!("{:?}", x));
+        let mut total = 0;
+        for eq in equations {
+            if let Some(result) = eq.check_operation() {
+                total += result;
+            }
+        }
+        assert_eq!(total, 3749);
+    }
+    #[test]
+    fn operate_complex_equations() {
+        let equations = TEST_STR_PART_ONE
+            .lines()
+            .filter_map(|x| BridgeEquation::try_from(x).ok())
+            .collect::<Vec<_>>();
+        let mut total = 0;
+        for eq in equations {
+            if let Some(result) = eq.check_complex_operation() {
+                total += result;
+            }
+        }
+        assert_eq!(total, 11387);
+    }
+    #[test]
+    fn overflowing_operators_never_reach_the_test_value() {
+        let equations = DaySeven::parse(
+            "18446744073709551615: 99999999999 99999999999 9\n18446744073709551615: 1844674407370955161 5",
+        )
+        .unwrap();
+        assert_eq!(part_one_parallel(&equations), Some(0));
+        assert_eq!(part_two_parallel(&equations), Some(18446744073709551615));
+        let twice = DaySeven::parse("18446744073709551615: 1844674407370955161 5\n18446744073709551615: 1844674407370955161 5").unwrap();
+        assert!(matches!(
+            DaySeven::part_two(&twice),
+            Err(Error::NoAnswer(_))
+        ));
+    }
+    #[test]
+    fn parallel_matches_single_thread() {
+        let equations = DaySeven::parse(TEST_STR_PART_ONE).unwrap();
+        assert_eq!(
+            part_one_parallel(&equations),
+            part_one_single_thread(&equations)
+        );
+        assert_eq!(
+            part_two_parallel(&equations),
+            part_two_single_thread(&equations)
+        );
+    }
+    #[test]
+    fn parallel_matches_single_thread_on_generated_equations() {
+        let equations = |list: &Vec<(u64, Vec<u64>)>| {
+            list.iter()
+                .map(|(result, operands)| BridgeEquation {
+                    result: *result,
+                    operands: OperandList {
+                        operands: operands.clone(),
+                    },
+                })
+                .collect::<Vec<_>>()
+        };
+        parallel::set_jobs(4);
+        elves::differential::assert_agree(
+            |rng, size| {
+                DaySeven::parse(&generate::input(rng, size))
+                    .unwrap()
+                    .into_iter()
+                    .map(|equation| (equation.result, equation.operands.operands))
+                    .collect::<Vec<_>>()
+            },
+            |list| {
+                let equations = equations(list);
+                (
+                    part_one_single_thread(&equations),
+                    part_two_single_thread(&equations),
+                )
+            },
+            |list| {
+                let equations = equations(list);
+                (part_one_parallel(&equations), part_two_parallel(&equations))
+            },
+        );
+        parallel::set_jobs(0);
+    }
+}
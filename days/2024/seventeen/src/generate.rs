@@ -0,0 +1,21 @@
+//! A program shaped like the real ones: each time round the loop it mixes the low three bits
+//! of A with some constants, prints one number and shifts A right by three, until A is zero.
+//! A starts out `size` octal digits long, so that's how many numbers get printed.
+
+use elves::Rng;
+
+/// The registers, a blank line, then the program.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let digits = size.clamp(1, 16) as u32;
+    let a = rng.between(8_i64.pow(digits - 1), 8_i64.pow(digits) - 1);
+    let mut program = [2, 4, 1, 0, 7, 5, 1, 0, 4, 0, 0, 3, 5, 5, 3, 0];
+    for operand in [3, 7, 9] {
+        program[operand] = rng.between(0, 7);
+    }
+    let program: Vec<String> = program.iter().map(i64::to_string).collect();
+    format!(
+        "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+        a,
+        program.join(",")
+    )
+}
@@ -0,0 +1,462 @@
+//! Day 17: Chronospatial Computer. A three-bit computer with three registers, run to see what
+//! it prints and searched for the value of A that makes it print its own program.
+#![warn(missing_docs)]
+
+use elves::{parse_number, Error, Location, Result, Rng, Solution};
+use std::fmt::Display;
+
+pub mod generate;
+
+/// Instructions [`DebugProgram::run`] carries out before giving up on a program that never
+/// halts. Real programs halt within a few hundred.
+pub const MAX_STEPS: usize = 1_000_000;
+
+/// The day's [`Solution`]: the input is the three registers and the program.
+pub struct DaySeventeen;
+impl Solution for DaySeventeen {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 17;
+    type Input = DebugProgram;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        DebugProgram::try_from(input)
+    }
+    fn validate(input: &str) -> Vec<Error> {
+        let (program, mut errors, locations) = DebugProgram::read(input);
+        if errors.is_empty() {
+            errors.extend(program.reserved_operands(&locations));
+        }
+        errors
+    }
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        let mut debug_program = input.clone();
+        debug_program.run()?;
+        Ok(debug_program.printed_output())
+    }
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        input
+            .find_self_replicating_a()
+            .ok_or_else(|| Error::no_answer("no value of A replicates the program"))
+    }
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+elves::examples!(DaySeventeen);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const DEBUG_PROGRAM_TEST: &str = include_str!("../examples/example.txt");
+    const DEBUG_PROGRAM: &str = include_str!("../examples/full-program.txt");
+    const QUINE_PROGRAM_TEST: &str = include_str!("../examples/quine.txt");
+    #[test]
+    fn part_two() {
+        let debug_program = DebugProgram::try_from(QUINE_PROGRAM_TEST).unwrap();
+        assert_eq!(debug_program.find_self_replicating_a(), Some(117440));
+
+        let debug_program = DebugProgram::try_from(DEBUG_PROGRAM).unwrap();
+        let a = debug_program.find_self_replicating_a().unwrap();
+        let mut replicated = debug_program.with_register_a(a);
+        replicated.run().unwrap();
+        assert_eq!(replicated.output, replicated.instructions);
+    }
+    #[test]
+    fn test() {
+        let mut debug_program = DebugProgram::try_from(DEBUG_PROGRAM_TEST).unwrap();
+        debug_program.run().unwrap();
+        assert_eq!(debug_program.printed_output(), "4,6,3,5,6,3,5,2,1,0");
+
+        let mut debug_program = DebugProgram::try_from(DEBUG_PROGRAM).unwrap();
+        debug_program.run().unwrap();
+        assert_eq!(debug_program.output.len(), 9);
+    }
+    #[test]
+    fn rejects_bad_programs() {
+        let error =
+            DebugProgram::try_from("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,8")
+                .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 5, column 12: invalid three-bit number, found \"8\""
+        );
+        let errors = DaySeventeen::validate(
+            "Register A: x\nRegister B: 0\nRegister D: 0\n\nProgram: 0,8,1,12,8",
+        );
+        let errors: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            errors,
+            [
+                "line 1, column 13: invalid register value, found \"x\"",
+                "line 3, column 10: unknown register, found \"D:\"",
+                "line 5, column 12: invalid three-bit number, found \"8\"",
+                "line 5, column 16: invalid three-bit number, found \"12\"",
+                "line 5, column 19: invalid three-bit number, found \"8\"",
+            ]
+        );
+
+        let mut debug_program =
+            DebugProgram::try_from("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 2,7")
+                .unwrap();
+        assert!(debug_program.run().is_err());
+        let errors = DaySeventeen::validate(
+            "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 1,7,2, 7",
+        );
+        let errors: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            errors,
+            ["line 5, column 17: reserved combo operand, found \"7\""]
+        );
+    }
+    #[test]
+    fn survives_wide_shifts_and_endless_loops() {
+        let mut shifted = DebugProgram::try_from(
+            "Register A: 8\nRegister B: 100\nRegister C: 0\n\nProgram: 0,5,6,5,7,5",
+        )
+        .unwrap();
+        shifted.run().unwrap();
+        assert_eq!((shifted.register.a, shifted.register.b), (0, 0));
+
+        let endless =
+            DebugProgram::try_from("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,0")
+                .unwrap();
+        assert!(matches!(
+            DaySeventeen::part_one(&endless).map(|output| output.to_string()),
+            Err(Error::NoAnswer(_))
+        ));
+        assert!(DaySeventeen::part_two(&endless).is_err());
+    }
+}
+/// The computer: its registers, the program and what it has printed so far.
+#[derive(Debug, Clone)]
+pub struct DebugProgram {
+    instruction_pointer: usize,
+    register: Registrar,
+    instructions: Vec<ThreeBitNumber>,
+    output: Vec<ThreeBitNumber>,
+}
+impl TryFrom<&str> for DebugProgram {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (program, errors, _) = DebugProgram::read(value);
+        match errors.into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(program),
+        }
+    }
+}
+impl DebugProgram {
+    /// Reads the three registers and the program, carrying on past anything malformed so
+    /// every problem is reported, each at its line and column. Also returns where each
+    /// number of the program is.
+    fn read(value: &str) -> (DebugProgram, Vec<Error>, Vec<Location>) {
+        let mut register = Registrar { a: 0, b: 0, c: 0 };
+        let mut registers = 0;
+        let mut instructions = Vec::new();
+        let mut locations = Vec::new();
+        let mut has_program = false;
+        let mut errors = Vec::new();
+        for (index, line) in value.lines().enumerate() {
+            let number = index + 1;
+            let line = line.trim_end();
+            if line.trim().is_empty() {
+                continue;
+            }
+            if let Some(list) = line.trim_start().strip_prefix("Program:") {
+                has_program = true;
+                let mut start = line.len() - list.len();
+                for item in list.split(',') {
+                    let text = item.trim();
+                    let column = line[..start + item.len() - item.trim_start().len()]
+                        .chars()
+                        .count()
+                        + 1;
+                    match three_bit_number(text) {
+                        Ok(instruction) => {
+                            instructions.push(instruction);
+                            locations.push(Location {
+                                line: number,
+                                column,
+                            });
+                        }
+                        Err(error) => errors.push(Error::at(number, column, text, error)),
+                    }
+                    start += item.len() + 1;
+                }
+                continue;
+            }
+            if registers == 3 {
+                errors.push(
+                    Error::parse(line.trim(), "expected `Program: ...`").on_line(number, line),
+                );
+                continue;
+            }
+            registers += 1;
+            let mut words = line.split_whitespace();
+            let Some(name) = words.nth(1) else {
+                errors.push(
+                    Error::parse(line.trim(), "expected `Register X: value`").on_line(number, line),
+                );
+                continue;
+            };
+            let value = match parse_number(words.next().unwrap_or_default(), "register value") {
+                Ok(value) => value,
+                Err(error) => {
+                    errors.push(error.on_line(number, line));
+                    continue;
+                }
+            };
+            match name {
+                "A:" => register.a = value,
+                "B:" => register.b = value,
+                "C:" => register.c = value,
+                _ => errors.push(Error::parse(name, "unknown register").on_line(number, line)),
+            }
+        }
+        if registers < 3 {
+            errors.push(Error::parse("", "expected registers A, B and C"));
+        }
+        if !has_program {
+            errors.push(Error::parse("", "missing `Program:` line"));
+        }
+        let program = DebugProgram {
+            instruction_pointer: 0,
+            register,
+            instructions,
+            output: vec![],
+        };
+        (program, errors, locations)
+    }
+    /// The reserved combo operand 7 wherever an instruction that takes a combo operand is
+    /// given it, at the `locations` [`DebugProgram::read`] found the program's numbers.
+    fn reserved_operands(&self, locations: &[Location]) -> Vec<Error> {
+        self.instructions
+            .chunks_exact(2)
+            .zip(locations.chunks_exact(2))
+            .filter(|(instruction, _)| {
+                OpCode::try_from(instruction[0]).is_ok_and(OpCode::takes_combo)
+                    && ComboOperand::try_from(instruction[1]).is_err()
+            })
+            .map(|(instruction, locations)| {
+                let Location { line, column } = locations[1];
+                Error::at(
+                    line,
+                    column,
+                    instruction[1].num.to_string(),
+                    "reserved combo operand",
+                )
+            })
+            .collect()
+    }
+}
+/// One number of the program, which has to fit in three bits.
+fn three_bit_number(text: &str) -> std::result::Result<ThreeBitNumber, &'static str> {
+    let mut digits = text.chars();
+    match (digits.next(), digits.next()) {
+        (Some(digit), None) => {
+            ThreeBitNumber::try_from(digit).map_err(|_| "invalid three-bit number")
+        }
+        _ => Err("invalid three-bit number"),
+    }
+}
+impl DebugProgram {
+    /// A fresh copy of the program, ready to run with register A set to `a`.
+    pub fn with_register_a(&self, a: u64) -> Self {
+        DebugProgram {
+            instruction_pointer: 0,
+            register: Registrar { a, b: 0, c: 0 },
+            instructions: self.instructions.clone(),
+            output: vec![],
+        }
+    }
+    /// Runs until the instruction pointer walks off the end of the program, or fails if it
+    /// hasn't after [`MAX_STEPS`] instructions.
+    pub fn run(&mut self) -> Result<()> {
+        for _ in 0..MAX_STEPS {
+            if !self.debug()? {
+                return Ok(());
+            }
+        }
+        Err(Error::no_answer(format!(
+            "the program didn't halt within {} instructions",
+            MAX_STEPS
+        )))
+    }
+    /// What the program has printed, joined with commas.
+    pub fn printed_output(&self) -> String {
+        self.output
+            .iter()
+            .map(|x| x.num.to_string())
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+    /// Lowest value of register A that makes the program print itself.
+    ///
+    /// The program shifts A right by three bits every loop and prints one digit per
+    /// shift, so A is built three bits at a time, matching the output from the end.
+    pub fn find_self_replicating_a(&self) -> Option<u64> {
+        let mut candidates = vec![0u64];
+        for matched in 1..=self.instructions.len() {
+            let expected = &self.instructions[self.instructions.len() - matched..];
+            candidates = candidates
+                .iter()
+                .flat_map(|a| (0..8).map(move |bits| a * 8 + bits))
+                .filter(|a| {
+                    let mut debug_program = self.with_register_a(*a);
+                    debug_program.run().is_ok() && debug_program.output == expected
+                })
+                .collect();
+        }
+        candidates.into_iter().filter(|a| *a != 0).min()
+    }
+    fn combo_value(&self, operand: ThreeBitNumber) -> Result<u64> {
+        Ok(match ComboOperand::try_from(operand)? {
+            ComboOperand::Value(value) => value,
+            ComboOperand::RegisterA => self.register.a,
+            ComboOperand::RegisterB => self.register.b,
+            ComboOperand::RegisterC => self.register.c,
+        })
+    }
+    /// Runs one instruction, `false` once the program has halted.
+    fn debug(&mut self) -> Result<bool> {
+        let (Some(opcode), Some(operand)) = (
+            self.instructions.get(self.instruction_pointer),
+            self.instructions.get(self.instruction_pointer + 1).copied(),
+        ) else {
+            return Ok(false);
+        };
+        let opcode = OpCode::try_from(*opcode)?;
+        match opcode {
+            OpCode::Adv => {
+                self.register.a = shift_right(self.register.a, self.combo_value(operand)?);
+                self.instruction_pointer += 2;
+            }
+            OpCode::Bxl => {
+                self.register.b ^= u64::from(operand.num);
+                self.instruction_pointer += 2;
+            }
+            OpCode::BSt => {
+                self.register.b = self.combo_value(operand)? % 8;
+                self.instruction_pointer += 2;
+            }
+            OpCode::Jnz => {
+                if self.register.a != 0 {
+                    self.instruction_pointer = operand.num as usize;
+                } else {
+                    self.instruction_pointer += 2;
+                }
+            }
+            OpCode::Bxc => {
+                self.register.b ^= self.register.c;
+                self.instruction_pointer += 2;
+            }
+            OpCode::Out => {
+                let value = (self.combo_value(operand)? % 8) as u32;
+                self.output.push(ThreeBitNumber { num: value });
+                self.instruction_pointer += 2;
+            }
+            OpCode::Bdv => {
+                self.register.b = shift_right(self.register.a, self.combo_value(operand)?);
+                self.instruction_pointer += 2;
+            }
+            OpCode::Cdv => {
+                self.register.c = shift_right(self.register.a, self.combo_value(operand)?);
+                self.instruction_pointer += 2;
+            }
+        }
+        Ok(true)
+    }
+}
+/// `value` divided by 2 to the power of `by`, which is 0 once `by` shifts every bit out.
+fn shift_right(value: u64, by: u64) -> u64 {
+    u32::try_from(by)
+        .ok()
+        .and_then(|by| value.checked_shr(by))
+        .unwrap_or(0)
+}
+#[derive(Debug, Clone)]
+struct Registrar {
+    a: u64,
+    b: u64,
+    c: u64,
+}
+enum ComboOperand {
+    Value(u64),
+    RegisterA,
+    RegisterB,
+    RegisterC,
+}
+impl TryFrom<ThreeBitNumber> for ComboOperand {
+    type Error = Error;
+    fn try_from(value: ThreeBitNumber) -> Result<Self, Self::Error> {
+        match value.num {
+            0 => Ok(ComboOperand::Value(0)),
+            1 => Ok(ComboOperand::Value(1)),
+            2 => Ok(ComboOperand::Value(2)),
+            3 => Ok(ComboOperand::Value(3)),
+            4 => Ok(ComboOperand::RegisterA),
+            5 => Ok(ComboOperand::RegisterB),
+            6 => Ok(ComboOperand::RegisterC),
+            _ => Err(Error::parse(
+                value.num.to_string(),
+                "reserved combo operand",
+            )),
+        }
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ThreeBitNumber {
+    num: u32,
+}
+#[derive(Debug, Clone, Copy)]
+enum OpCode {
+    Adv,
+    Bxl,
+    BSt,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+impl OpCode {
+    /// Whether the operand is a combo operand rather than a literal one.
+    fn takes_combo(self) -> bool {
+        matches!(
+            self,
+            OpCode::Adv | OpCode::BSt | OpCode::Out | OpCode::Bdv | OpCode::Cdv
+        )
+    }
+}
+impl TryFrom<ThreeBitNumber> for OpCode {
+    type Error = Error;
+    fn try_from(value: ThreeBitNumber) -> Result<Self, Self::Error> {
+        match value.num {
+            0 => Ok(OpCode::Adv),
+            1 => Ok(OpCode::Bxl),
+            2 => Ok(OpCode::BSt),
+            3 => Ok(OpCode::Jnz),
+            4 => Ok(OpCode::Bxc),
+            5 => Ok(OpCode::Out),
+            6 => Ok(OpCode::Bdv),
+            7 => Ok(OpCode::Cdv),
+            _ => Err(Error::parse(value.num.to_string(), "invalid opcode")),
+        }
+    }
+}
+impl TryFrom<char> for ThreeBitNumber {
+    type Error = Error;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '0' => Ok(ThreeBitNumber { num: 0 }),
+            '1' => Ok(ThreeBitNumber { num: 1 }),
+            '2' => Ok(ThreeBitNumber { num: 2 }),
+            '3' => Ok(ThreeBitNumber { num: 3 }),
+            '4' => Ok(ThreeBitNumber { num: 4 }),
+            '5' => Ok(ThreeBitNumber { num: 5 }),
+            '6' => Ok(ThreeBitNumber { num: 6 }),
+            '7' => Ok(ThreeBitNumber { num: 7 }),
+            _ => Err(Error::parse(value.to_string(), "invalid three-bit number")),
+        }
+    }
+}
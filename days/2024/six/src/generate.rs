@@ -0,0 +1,17 @@
+//! A `size` by `size` lab with obstructions scattered over about one tile in ten and the
+//! guard facing up somewhere among them.
+
+use elves::{Grid, Rng};
+
+/// The lab map with the guard drawn as `^`.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    let mut lab = Grid::new(size, size, '.');
+    for coord in lab.coords() {
+        if rng.chance(10) {
+            lab.set(coord, '#');
+        }
+    }
+    lab.set((rng.below(size), rng.below(size)), '^');
+    lab.to_string()
+}
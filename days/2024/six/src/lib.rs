@@ -0,0 +1,317 @@
+//! Day 6: Guard Gallivant. A guard walks the lab on a [`GuardMap`], turning right at every
+//! obstruction, until they walk off the map or round in a loop.
+#![warn(missing_docs)]
+
+use elves::{parallel, Coord, Dir4, Error, Grid, Result, Rng, Solution};
+use std::{collections::HashMap, fmt::Display};
+
+pub mod generate;
+
+/// The puzzle, with the lab map as its input.
+pub struct DaySix;
+impl Solution for DaySix {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+    type Input = GuardMap;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        GuardMap::from_string(input)
+    }
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        let mut guard_map = input.clone();
+        guard_map.move_guard_until_he_leaves_or_loops();
+        Ok(guard_map.count_visited_locations())
+    }
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        Ok(count_trapped_guards(input))
+    }
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+elves::examples!(DaySix);
+
+/// How many spots on the guard's path would trap them in a loop if an obstruction were put there.
+pub fn count_trapped_guards(guard_map: &GuardMap) -> usize {
+    let mut positions_to_check: Vec<Coord> = Vec::new();
+    let mut walked_map = guard_map.clone();
+    walked_map.move_guard_until_he_leaves_or_loops();
+    walked_map.map.iter().for_each(|(coord, loc)| {
+        if let MapLocation::Path(Some(_), _) = loc {
+            positions_to_check.push(coord);
+        }
+    });
+    let variations: Vec<(usize, Coord)> = positions_to_check.into_iter().enumerate().collect();
+    parallel::map_reduce(
+        &variations,
+        |(num, pos)| {
+            let mut new_map = guard_map.clone();
+            new_map.add_obstruction(*pos);
+            new_map.id = *num;
+            usize::from(new_map.move_guard_until_he_leaves_or_loops())
+        },
+        || 0,
+        |a, b| a + b,
+    )
+}
+
+/// How the guard went through a tile they've been on.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VisitedLocation {
+    /// Up or down.
+    MovingVertical,
+    /// Left or right.
+    MovingHorizontal,
+    /// Turning, in front of an obstruction.
+    Corner,
+}
+/// A tile of the lab.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MapLocation {
+    /// Open floor, with how the guard went through it and which way they last faced on it.
+    Path(Option<VisitedLocation>, Option<Dir4>),
+    /// An obstruction the guard turns at.
+    Obstacle,
+}
+impl Display for MapLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MapLocation::Path(Some(direction_passed), _) => match direction_passed {
+                VisitedLocation::MovingVertical => write!(f, "\x1b[33m|\x1b[0m"),
+                VisitedLocation::MovingHorizontal => write!(f, "\x1b[33m-\x1b[0m"),
+                VisitedLocation::Corner => write!(f, "\x1b[33m+\x1b[0m"),
+            },
+            MapLocation::Path(_, Some(guard)) => write!(f, "\x1b[33m{}\x1b[0m", guard),
+            MapLocation::Path(None, _) => write!(f, "\x1b[32m.\x1b[0m"),
+            MapLocation::Obstacle => write!(f, "\x1b[31m#\x1b[0m"),
+        }
+    }
+}
+impl TryFrom<char> for MapLocation {
+    type Error = &'static str;
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(MapLocation::Path(None, None)),
+            '#' => Ok(MapLocation::Obstacle),
+            '^' | 'v' | '<' | '>' => Ok(MapLocation::Path(None, c.try_into().ok())),
+            _ => Err("Invalid character"),
+        }
+    }
+}
+/// The lab, marked with everywhere the guard has been, and where they are now.
+#[derive(Debug, Clone)]
+pub struct GuardMap {
+    id: usize,
+    map: Grid<MapLocation>,
+    last_guard_signal: Option<(Coord, Dir4)>,
+}
+impl GuardMap {
+    /// How many tiles across the lab is.
+    pub fn longest_x(&self) -> usize {
+        self.map.width()
+    }
+    /// How many tiles down the lab is.
+    pub fn longest_y(&self) -> usize {
+        self.map.height()
+    }
+    /// Reads a map drawn with `.`, `#` and the guard as `^`, `v`, `<` or `>`.
+    pub fn from_string(input: &str) -> Result<Self> {
+        let map = Grid::<MapLocation>::parse(input)?;
+        let last_guard_signal = map.iter().find_map(|(coord, location)| {
+            if let MapLocation::Path(_, Some(guard)) = location {
+                Some((coord, *guard))
+            } else {
+                None
+            }
+        });
+        if last_guard_signal.is_none() {
+            return Err(Error::parse("", "the map has no guard on it"));
+        }
+        Ok(GuardMap {
+            id: 0,
+            map,
+            last_guard_signal,
+        })
+    }
+    /// Moves the guard one tile on, or turns them if they're facing an obstruction. Returns
+    /// the tile they were on and what it's marked as now.
+    pub fn move_guard(&mut self) -> (Coord, MapLocation) {
+        let (guard_pos, guard) = self.last_guard_signal.unwrap();
+        // Calculate the new position based on the current guard direction
+        let Some(new_guard) = self.map.step(guard_pos, guard) else {
+            self.last_guard_signal = None;
+            return (guard_pos, MapLocation::Path(None, None));
+        };
+        // Check if the new position is a valid path
+        match self.map.get_mut(new_guard) {
+            Some(new_location) => {
+                // If the new location is a path, update the current guard position
+                // check if the new location ha already been visited and compare the direction of
+                // the guard with the direction of the visited location
+                // if horizontal and vertical meet, turn the location into a corner.
+                match new_location {
+                    MapLocation::Obstacle => {
+                        // If the new location is an obstacle, change the direction of the guard
+                        let new_guard = guard.rotate_right();
+                        self.last_guard_signal = Some((guard_pos, new_guard));
+                        // clone old position to return it because we didnt move
+                        //
+                        // update position with a corner
+                        if let Some(location) = self.map.get_mut(guard_pos) {
+                            *location =
+                                MapLocation::Path(Some(VisitedLocation::Corner), Some(guard));
+                        }
+                        return (
+                            guard_pos,
+                            MapLocation::Path(Some(VisitedLocation::Corner), Some(guard)),
+                        );
+                    }
+                    _ => {
+                        *new_location = MapLocation::Path(
+                            Some(match guard.is_vertical() {
+                                true => VisitedLocation::MovingVertical,
+                                false => VisitedLocation::MovingHorizontal,
+                            }),
+                            Some(guard),
+                        );
+                    }
+                }
+                // Update last_guard_signal to reflect the new position of the guard
+                self.last_guard_signal = Some((new_guard, guard));
+            }
+            _ => panic!("Invalid move"), // If the new location is not valid, panic
+        }
+        // return the last location visited
+        let last_location = self.map[guard_pos].clone();
+        (guard_pos, last_location)
+    }
+    /// Walks the guard until they leave the lab, and returns whether they got stuck in a
+    /// loop instead.
+    pub fn move_guard_until_he_leaves_or_loops(&mut self) -> bool {
+        let mut corners: HashMap<Coord, HashMap<Dir4, usize>> = HashMap::new();
+        let mut guard_was_trapped = false;
+        loop {
+            elves::trace!("Looping map: {}", self.id);
+            elves::profile::count("guard steps", 1);
+            let (last_pos, last_location) = self.move_guard();
+            if let MapLocation::Path(Some(VisitedLocation::Corner), _) = last_location {
+                let entry = corners.entry(last_pos).or_default();
+                let current_guard_direction = self.last_guard_signal.unwrap().1;
+                let counter = entry.entry(current_guard_direction).or_insert(0);
+                *counter += 1;
+                if *counter / 2 >= 2 {
+                    guard_was_trapped = true;
+                    break;
+                }
+            }
+            if self.last_guard_signal.is_none() {
+                break;
+            }
+        }
+        guard_was_trapped
+    }
+    /// Puts an obstruction at `pos`.
+    pub fn add_obstruction(&mut self, pos: Coord) {
+        self.map.set(pos, MapLocation::Obstacle);
+    }
+    /// Prints the map, for debugging.
+    pub fn print_map(&self) {
+        print!("{}", self.map);
+    }
+    /// How many tiles the guard has walked on.
+    pub fn count_visited_locations(&self) -> usize {
+        self.map
+            .iter()
+            .filter(|(_, location)| matches!(location, MapLocation::Path(Some(_), _)))
+            .count()
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_STRING: &str = include_str!("../examples/example.txt");
+    #[test]
+    fn read_guard_map() {
+        let guard_map = GuardMap::from_string(TEST_STRING).unwrap();
+        guard_map.print_map();
+        println!();
+        println!("{:?}", guard_map.last_guard_signal);
+    }
+    #[test]
+    fn move_guard() {
+        let mut guard_map = GuardMap::from_string(TEST_STRING).unwrap();
+        loop {
+            guard_map.move_guard();
+            guard_map.print_map();
+            println!();
+            if guard_map.last_guard_signal.is_none() {
+                break;
+            }
+        }
+        assert_eq!(guard_map.count_visited_locations(), 41);
+    }
+    #[test]
+    fn add_obstruction() {
+        let mut guard_map = GuardMap::from_string(TEST_STRING).unwrap();
+        guard_map.add_obstruction((3, 6));
+        guard_map.print_map();
+        let guard_was_trapped = guard_map.move_guard_until_he_leaves_or_loops();
+        guard_map.print_map();
+        assert!(guard_was_trapped);
+    }
+    #[test]
+    fn make_map_variations() {
+        // I wanto check the map for every open space
+        // i want to creat a copy of the map, with each open space repaced by an Obstacle
+        // only one replacement per copy
+        // i want to collect the copies
+
+        let guard_map = GuardMap::from_string(TEST_STRING).unwrap();
+        let mut variations: Vec<GuardMap> = Vec::new();
+        let mut open_spaces = Vec::new();
+        for (coord, loc) in guard_map.map.iter() {
+            if let MapLocation::Path(_, _) = loc {
+                open_spaces.push(coord);
+            }
+        }
+        for pos in open_spaces {
+            let mut new_map = guard_map.clone();
+            new_map.add_obstruction(pos);
+            variations.push(new_map);
+        }
+        for map in variations.iter() {
+            map.print_map();
+            println!();
+        }
+    }
+    #[test]
+    fn count_looped_guards() {
+        let guard_map = GuardMap::from_string(TEST_STRING).unwrap();
+        let mut variations: Vec<GuardMap> = Vec::new();
+        let mut positions_to_check = Vec::new();
+        let mut trapped_counter = 0;
+        let mut walked_map = guard_map.clone();
+        walked_map.move_guard_until_he_leaves_or_loops();
+        walked_map.map.iter().for_each(|(coord, loc)| {
+            if let MapLocation::Path(Some(_), _) = loc {
+                positions_to_check.push(coord);
+            }
+        });
+        walked_map.print_map();
+
+        for pos in positions_to_check {
+            let mut new_map = guard_map.clone();
+            new_map.add_obstruction(pos);
+            variations.push(new_map);
+        }
+        println!("Variations: {}", variations.len());
+        for map in variations.iter_mut() {
+            let guard_was_trapped = map.move_guard_until_he_leaves_or_loops();
+            if guard_was_trapped {
+                trapped_counter += 1;
+            }
+        }
+        println!("Trapped guards: {}", trapped_counter);
+        assert_eq!(trapped_counter, 6);
+    }
+}
@@ -0,0 +1,25 @@
+//! A maze of `size` by `size` rooms with the start in the bottom left corner and the end in
+//! the top right. It starts out with exactly one path between any two rooms, then has some
+//! walls knocked through so that there are several ways round.
+
+use elves::{random::maze, Rng};
+
+/// The maze with `#` for walls and `S` and `E` marked.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let open = maze(rng, size.max(2), size.max(2));
+    let (width, height) = (open.width(), open.height());
+    let mut tiles = open.map(|open| match open {
+        true => '.',
+        false => '#',
+    });
+    for (x, y) in open.coords() {
+        let inside = x > 0 && y > 0 && x < width - 1 && y < height - 1;
+        // Walls between two rooms in a row or a column, never the corners between four.
+        if inside && (x + y) % 2 == 1 && rng.chance(10) {
+            tiles.set((x, y), '.');
+        }
+    }
+    tiles.set((1, height - 2), 'S');
+    tiles.set((width - 2, 1), 'E');
+    tiles.to_string()
+}
@@ -0,0 +1,213 @@
+//! Day 16: Reindeer Maze. The cheapest way through a maze for a reindeer that pays a point
+//! per step and a thousand per turn, and the tiles on every cheapest way.
+#![warn(missing_docs)]
+
+use elves::{search, Dir4, Error, Grid, Point, Result, Rng, Solution};
+use std::{collections::HashSet, fmt::Display};
+
+/// Turning in place costs as much as a thousand steps forward.
+pub const TURN_COST: usize = 1000;
+
+pub mod generate;
+
+/// The day's [`Solution`]: the input is the maze, with `S` for the start and `E` for the end.
+pub struct DaySixteen;
+impl Solution for DaySixteen {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 16;
+    type Input = Maze;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Maze::try_from(input)
+    }
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        let (_, steps, turns) = input
+            .find_shortest_path()
+            .ok_or_else(|| Error::no_answer("no path through the maze"))?;
+        Ok(steps + turns * TURN_COST)
+    }
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        input
+            .best_seats()
+            .ok_or_else(|| Error::no_answer("no path through the maze"))
+    }
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+elves::examples!(DaySixteen);
+
+#[cfg(test)]
+mod tests {
+    const REINDEER_MAZE: &str = include_str!("../examples/example.txt");
+    #[test]
+    fn read_maze() {
+        let maze = super::Maze::try_from(REINDEER_MAZE).unwrap();
+        assert_eq!(maze.tiles.len(), 15 * 15);
+        assert_eq!(maze.start, super::Point::new(1, 13));
+        assert_eq!(maze.end, super::Point::new(13, 1));
+    }
+    #[test]
+    fn walk_maze() {
+        let maze = super::Maze::try_from(REINDEER_MAZE).unwrap();
+        let shortest_path = maze.find_shortest_path().unwrap();
+        assert_eq!(shortest_path.0.len(), 36);
+        assert_eq!(shortest_path.2, 7);
+        assert_eq!(maze.best_seats(), Some(45));
+    }
+    #[test]
+    fn walks_generated_mazes() {
+        use elves::Solution;
+        for (seed, size) in (0..10).zip([2, 3, 5, 8, 13, 2, 3, 5, 8, 13]) {
+            let input = super::generate::input(&mut super::Rng::new(seed), size);
+            let maze = super::DaySixteen::parse(&input).unwrap();
+            assert!(super::DaySixteen::part_one(&maze).is_ok());
+        }
+    }
+    #[test]
+    fn walled_off_end_has_no_answer() {
+        use elves::{Error, Solution};
+        let maze = super::DaySixteen::parse("#####\n#S#E#\n#####").unwrap();
+        for answer in [
+            super::DaySixteen::part_one(&maze).map(|answer| answer.to_string()),
+            super::DaySixteen::part_two(&maze).map(|answer| answer.to_string()),
+        ] {
+            assert!(matches!(answer, Err(Error::NoAnswer(_))));
+        }
+    }
+}
+/// The maze and where the reindeer starts and ends.
+pub struct Maze {
+    tiles: Grid<MazeTile>,
+    start: Point,
+    end: Point,
+}
+impl Maze {
+    fn initial_reindeer(&self) -> Reindeer {
+        Reindeer {
+            position: self.start,
+            direction: Dir4::Right,
+        }
+    }
+    /// Where a reindeer can go next: a step forward if it isn't facing a wall, or a
+    /// quarter turn either way, each with what it adds to the score.
+    fn reindeer_moves(&self, reindeer: &Reindeer) -> Vec<(Reindeer, usize)> {
+        let mut moves = vec![
+            (reindeer.turn_left(), TURN_COST),
+            (reindeer.turn_right(), TURN_COST),
+        ];
+        if self.check_next_position(reindeer.position, reindeer.direction) {
+            let mut walked = *reindeer;
+            walked.walk_once();
+            moves.push((walked, 1));
+        }
+        moves
+    }
+    /// Cheapest route from start to end, as the positions walked after leaving the start,
+    /// along with how many steps and turns it took.
+    pub fn find_shortest_path(&self) -> Option<(Vec<Point>, usize, usize)> {
+        let (path, score) = search::dijkstra(
+            self.initial_reindeer(),
+            |reindeer| self.reindeer_moves(reindeer),
+            |reindeer| reindeer.position == self.end,
+        )?;
+        let walked: Vec<Point> = path
+            .windows(2)
+            .filter(|pair| pair[0].position != pair[1].position)
+            .map(|pair| pair[1].position)
+            .collect();
+        let steps = walked.len();
+        Some((walked, steps, (score - steps) / TURN_COST))
+    }
+    /// Every tile that sits on at least one of the cheapest routes, or `None` if the end
+    /// can't be reached.
+    pub fn best_seats(&self) -> Option<usize> {
+        search::dijkstra_all(
+            self.initial_reindeer(),
+            |reindeer| self.reindeer_moves(reindeer),
+            |reindeer| reindeer.position == self.end,
+        )
+        .map(|paths| {
+            paths
+                .nodes_on_paths()
+                .iter()
+                .map(|reindeer| reindeer.position)
+                .collect::<HashSet<_>>()
+                .len()
+        })
+    }
+    fn check_next_position(&self, position: Point, direction: Dir4) -> bool {
+        match self.tiles.at(position.step(direction)) {
+            Some(MazeTile::Wall) => false,
+            Some(MazeTile::End) => true,
+            Some(MazeTile::Empty) => true,
+            _ => false,
+        }
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Reindeer {
+    position: Point,
+    direction: Dir4,
+}
+impl Reindeer {
+    fn turn_left(&self) -> Self {
+        Reindeer {
+            direction: self.direction.rotate_left(),
+            ..*self
+        }
+    }
+    fn turn_right(&self) -> Self {
+        Reindeer {
+            direction: self.direction.rotate_right(),
+            ..*self
+        }
+    }
+    fn walk_once(&mut self) {
+        self.position = self.position.step(self.direction);
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum MazeTile {
+    Wall,
+    Empty,
+    Start,
+    End,
+}
+impl TryFrom<char> for MazeTile {
+    type Error = &'static str;
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '#' => Ok(MazeTile::Wall),
+            '.' => Ok(MazeTile::Empty),
+            'S' => Ok(MazeTile::Start),
+            'E' => Ok(MazeTile::End),
+            _ => Err("Invalid character"),
+        }
+    }
+}
+impl TryFrom<&str> for Maze {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let tiles = Grid::parse(value)?;
+        let find_single = |tile: MazeTile, missing: &str, multiple: &str| {
+            let mut positions = tiles.find_all(|t| *t == tile);
+            let position = positions.next().ok_or_else(|| Error::parse("", missing))?;
+            if positions.next().is_some() {
+                return Err(Error::parse("", multiple));
+            }
+            Ok(Point::from(position))
+        };
+        let start = find_single(
+            MazeTile::Start,
+            "the maze has no start",
+            "the maze has more than one start",
+        )?;
+        let end = find_single(
+            MazeTile::End,
+            "the maze has no end",
+            "the maze has more than one end",
+        )?;
+        Ok(Maze { tiles, start, end })
+    }
+}
@@ -0,0 +1,31 @@
+//! A `size` by `size` topographic map of random heights, with hiking trails from 0 to 9
+//! walked into it.
+
+use elves::{Grid, Rng};
+
+/// The map, a digit per tile.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    let mut map = Grid::new(size, size, 0);
+    for coord in map.coords() {
+        map.set(coord, rng.below(10));
+    }
+    for _ in 0..size {
+        let mut trail = vec![(rng.below(size), rng.below(size))];
+        while trail.len() < 10 {
+            let at = trail[trail.len() - 1];
+            let next: Vec<_> = map
+                .neighbours4(at)
+                .filter(|next| !trail.contains(next))
+                .collect();
+            if next.is_empty() {
+                break;
+            }
+            trail.push(*rng.pick(&next));
+        }
+        for (height, at) in trail.into_iter().enumerate() {
+            map.set(at, height);
+        }
+    }
+    map.to_string()
+}
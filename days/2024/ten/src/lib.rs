@@ -0,0 +1,195 @@
+//! Day 10: Hoof It. A topographic map of heights 0 to 9, scored by the peaks each trailhead
+//! can climb to and rated by the distinct trails that get there.
+#![warn(missing_docs)]
+
+use std::{collections::HashSet, fmt::Display};
+
+use colored::Colorize;
+use elves::{search, Error, Grid, Point, Result, Rng, Solution};
+
+pub mod generate;
+
+/// The day's [`Solution`]: the input is a grid of heights.
+pub struct DayTen;
+impl Solution for DayTen {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 10;
+    type Input = TrailMap;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        TrailMap::try_from(input)
+    }
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        Ok(input.check_trailhead_pleasantness())
+    }
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        Ok(input.check_trail_ratings())
+    }
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+elves::examples!(DayTen);
+
+#[cfg(test)]
+mod tests {
+
+    use crate::TrailMap;
+
+    const TEST_TRAILS: &str = include_str!("../examples/example.txt");
+    #[test]
+    fn read_trail_map() {
+        let trail_map = TrailMap::try_from(TEST_TRAILS).unwrap();
+        elves::assert_snapshot!("example", trail_map);
+        let pleasant_trail_count = trail_map.check_trailhead_pleasantness();
+        println!("Pleasant trail count: {}", pleasant_trail_count);
+        assert_eq!(pleasant_trail_count, 36);
+    }
+    #[test]
+    fn check_trail_ratings() {
+        let trail_map = TrailMap::try_from(TEST_TRAILS).unwrap();
+        println!("{}", trail_map);
+        let pleasant_trail_count = trail_map.check_trail_ratings();
+        println!("Pleasant trail count: {}", pleasant_trail_count);
+        assert_eq!(pleasant_trail_count, 81);
+    }
+}
+/// The height of one spot on the map.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum TrailTerrain {
+    /// Height 0, where trails start.
+    TrailHead,
+    /// Heights 1 to 8.
+    Slope(u32),
+    /// Height 9, where trails end.
+    Peak,
+}
+impl TrailTerrain {
+    /// Whether `next` is exactly one higher, so a trail can step onto it.
+    pub fn has_pleasant_slope(&self, next: &TrailTerrain) -> bool {
+        match self {
+            TrailTerrain::TrailHead => {
+                // From TrailHead, we can only go to a Slope with height 1
+                if *next == TrailTerrain::Slope(1) {
+                    return true;
+                }
+                false
+            }
+            TrailTerrain::Slope(height) => {
+                if let TrailTerrain::Slope(next_height) = next {
+                    // Valid transition is from one slope to the next (height + 1)
+                    if *next_height == height + 1 {
+                        return true;
+                    }
+                }
+                if *next == TrailTerrain::Peak && *height == 8 {
+                    // If the current slope is 8, we can transition to a Peak
+                    return true;
+                }
+                false
+            }
+            TrailTerrain::Peak => {
+                // Reached a Peak, valid end point for any path
+                false
+            }
+        }
+    }
+}
+impl TryFrom<char> for TrailTerrain {
+    type Error = &'static str;
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '0' => Ok(TrailTerrain::TrailHead),
+            '1'..='8' => Ok(TrailTerrain::Slope(c.to_digit(10).unwrap())),
+            '9' => Ok(TrailTerrain::Peak),
+            _ => Err("Invalid terrain character"),
+        }
+    }
+}
+impl Display for TrailTerrain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TrailTerrain::TrailHead => write!(f, "{}", 0.to_string().red()),
+            TrailTerrain::Slope(height) => write!(f, "{}", height.to_string().yellow()),
+            TrailTerrain::Peak => write!(f, "{}", 9.to_string().green()),
+        }
+    }
+}
+
+/// The map the trails are on.
+#[derive(Debug)]
+pub struct TrailMap {
+    grid: Grid<TrailTerrain>,
+}
+impl TrailMap {
+    fn terrain(&self, position: &Point) -> Option<&TrailTerrain> {
+        self.grid.at(*position)
+    }
+    /// Every height 0, in reading order.
+    pub fn trailheads(&self) -> Vec<Point> {
+        self.grid
+            .find_all(|terrain| *terrain == TrailTerrain::TrailHead)
+            .map(Point::from)
+            .collect()
+    }
+    /// Every height 9.
+    pub fn peaks(&self) -> HashSet<Point> {
+        self.grid
+            .find_all(|terrain| *terrain == TrailTerrain::Peak)
+            .map(Point::from)
+            .collect()
+    }
+    /// Positions one step further up a pleasant slope.
+    fn climbs(&self, position: &Point) -> Vec<Point> {
+        let Some(terrain) = self.terrain(position) else {
+            return vec![];
+        };
+        position
+            .neighbours4()
+            .filter(|next| {
+                self.terrain(next)
+                    .is_some_and(|next_terrain| terrain.has_pleasant_slope(next_terrain))
+            })
+            .collect()
+    }
+    fn is_peak(&self, position: &Point) -> bool {
+        self.terrain(position) == Some(&TrailTerrain::Peak)
+    }
+    /// The number of peaks each trailhead reaches, added up over the trailheads.
+    pub fn check_trailhead_pleasantness(&self) -> u32 {
+        self.trailheads()
+            .into_iter()
+            .map(|start| {
+                search::bfs_distances(start, |position| self.climbs(position))
+                    .keys()
+                    .filter(|position| self.is_peak(position))
+                    .count() as u32
+            })
+            .sum()
+    }
+    /// The number of distinct trails from each trailhead to any peak, added up.
+    pub fn check_trail_ratings(&self) -> u32 {
+        self.trailheads()
+            .into_iter()
+            .map(|start| {
+                search::count_paths(
+                    start,
+                    |position| self.climbs(position),
+                    |position| self.is_peak(position),
+                ) as u32
+            })
+            .sum()
+    }
+}
+impl TryFrom<&str> for TrailMap {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let grid = Grid::parse(value)?;
+        Ok(TrailMap { grid })
+    }
+}
+impl Display for TrailMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
@@ -0,0 +1,43 @@
+//! `size` claw machines. Most have a prize the buttons reach in at most a hundred presses
+//! each, the rest a prize put anywhere.
+
+use elves::Rng;
+
+/// The machines, a blank line between each.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let machines: Vec<String> = (0..size)
+        .map(|_| {
+            let solvable = rng.chance(75);
+            machine(rng, solvable).0
+        })
+        .collect();
+    machines.join("\n")
+}
+
+/// One machine, and what winning its prize costs when it's `solvable`. The buttons never
+/// move the claw the same way, so there's only ever one way to win.
+pub fn machine(rng: &mut Rng, solvable: bool) -> (String, Option<u64>) {
+    let (a, b) = loop {
+        let a = (rng.between(10, 99), rng.between(10, 99));
+        let b = (rng.between(10, 99), rng.between(10, 99));
+        if a.0 * b.1 != a.1 * b.0 {
+            break (a, b);
+        }
+    };
+    let (prize, cost) = match solvable {
+        true => {
+            let (pushes_a, pushes_b) = (rng.between(0, 100), rng.between(0, 100));
+            let prize = (
+                pushes_a * a.0 + pushes_b * b.0,
+                pushes_a * a.1 + pushes_b * b.1,
+            );
+            (prize, Some(3 * pushes_a as u64 + pushes_b as u64))
+        }
+        false => ((rng.between(100, 20000), rng.between(100, 20000)), None),
+    };
+    let text = format!(
+        "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+        a.0, a.1, b.0, b.1, prize.0, prize.1
+    );
+    (text, cost)
+}
@@ -0,0 +1,489 @@
+//! Day 13: Claw Contraption. Claw machines with two buttons each, won in as few tokens as
+//! possible by searching small press counts or, for the far away prizes, by solving the two
+//! equations outright.
+#![warn(missing_docs)]
+
+use std::fmt::Display;
+
+use elves::{
+    config::DayConfig,
+    parse_lines,
+    parser::{either, int, key_value, map, parse_all, separated_pair, tag},
+    Error, Result, Rng, Solution,
+};
+use serde::Deserialize;
+
+pub mod generate;
+
+/// How far part one searches and where part two's prizes are, from `[2024.13]` in
+/// `aoc.toml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// The most times part one presses each button.
+    pub max_presses: u64,
+    /// Part two moves every prize this far out along both axes.
+    pub prize_offset: u64,
+}
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            max_presses: 100,
+            prize_offset: 10_000_000_000_000,
+        }
+    }
+}
+impl DayConfig for Config {
+    /// Any limit works, since presses that would overflow can't reach a prize anyway, and a
+    /// prize moved out of range is reported by part two.
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// The day's [`Solution`]: the input is one block of button and prize lines per machine.
+pub struct DayThirteen;
+impl Solution for DayThirteen {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 13;
+    type Input = ClawArcade;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        ClawArcade::try_from(input)
+    }
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        let config: Config = Self::config()?;
+        let mut tokens = 0;
+        for game in &input.games {
+            if let Some(cost) = game.token_cost(config.max_presses)? {
+                tokens = add_tokens(tokens, cost)?;
+            }
+        }
+        Ok(tokens)
+    }
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        let config: Config = Self::config()?;
+        let mut tokens = 0;
+        for game in &input.games {
+            if let Some(cost) = game.moved_prize(config.prize_offset)?.evil_token_cost()? {
+                tokens = add_tokens(tokens, cost)?;
+            }
+        }
+        Ok(tokens)
+    }
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+elves::examples!(DayThirteen);
+
+/// `total` plus the tokens spent on A and on B, or an error if that doesn't fit.
+fn add_tokens(total: u64, (a_cost, b_cost): (u64, u64)) -> Result<u64> {
+    total
+        .checked_add(a_cost)
+        .and_then(|total| total.checked_add(b_cost))
+        .ok_or_else(|| Error::no_answer("the tokens add up to more than fit in a u64"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const CLAW_INSTRUCTIONS: &str = include_str!("../examples/example.txt");
+    #[test]
+    fn claw_instructions() {
+        let claw_arcade: ClawArcade = CLAW_INSTRUCTIONS.try_into().unwrap();
+        assert_eq!(claw_arcade.games.len(), 4);
+    }
+    #[test]
+    fn claw_presses() {
+        let claw_arcade: ClawArcade = CLAW_INSTRUCTIONS.try_into().unwrap();
+        assert_eq!(claw_arcade.games.len(), 4);
+        let total = claw_arcade
+            .games
+            .iter()
+            .filter_map(|game| {
+                let (min_a, min_b) = game.token_cost(Config::default().max_presses).unwrap()?;
+                Some(min_a + min_b)
+            })
+            .sum::<u64>();
+        assert_eq!(total, 480);
+    }
+    const EVIL_CLAW_INSTRUCTIONS: &str = include_str!("../examples/far-prizes.txt");
+    #[test]
+    fn evil_presses() {
+        let claw_arcade: ClawArcade = EVIL_CLAW_INSTRUCTIONS.try_into().unwrap();
+        assert_eq!(claw_arcade.games.len(), 4);
+        let total = claw_arcade
+            .games
+            .iter()
+            .filter_map(|game| {
+                let (min_a, min_b) = game.evil_token_cost().unwrap()?;
+                Some(min_a + min_b)
+            })
+            .sum::<u64>();
+        assert_eq!(total, 875318608908);
+    }
+    /// Buttons A and B, and how many times each is pressed to reach the prize.
+    type Presses = ((u64, u64), (u64, u64), (u64, u64));
+    #[test]
+    fn cramer_matches_brute_force() {
+        let game = |&((ax, ay), (bx, by), (a, b)): &Presses| ClawGame {
+            button_a: Button {
+                x_plus: ax,
+                y_plus: ay,
+            },
+            button_b: Button {
+                x_plus: bx,
+                y_plus: by,
+            },
+            prize: Prize {
+                x: a * ax + b * bx,
+                y: a * ay + b * by,
+            },
+        };
+        elves::differential::assert_agree(
+            |rng, _| {
+                // Small enough that buttons often point the same way, and to search every
+                // press count that could win: each press moves the claw at least one step.
+                let mut button = || (rng.below(10) as u64, rng.below(10) as u64);
+                let buttons = (button(), button());
+                (
+                    buttons.0,
+                    buttons.1,
+                    (rng.below(11) as u64, rng.below(11) as u64),
+                )
+            },
+            |input| {
+                let game = game(input);
+                game.brute_forced_min(game.prize.x + game.prize.y)
+            },
+            |input| game(input).evil_button_presses(),
+        );
+    }
+    #[test]
+    fn keeps_repeated_machines() {
+        let machine = CLAW_INSTRUCTIONS.split("\n\n").next().unwrap().trim();
+        let arcade = DayThirteen::parse(&format!("{}\n\n{}\n", machine, machine)).unwrap();
+        assert_eq!(arcade.games().count(), 2);
+        assert_eq!(DayThirteen::part_one(&arcade).unwrap().to_string(), "560");
+    }
+    #[test]
+    fn reports_prizes_moved_out_of_range() {
+        let arcade: ClawArcade = CLAW_INSTRUCTIONS.try_into().unwrap();
+        let game = arcade.games().next().unwrap();
+        assert!(matches!(game.moved_prize(u64::MAX), Err(Error::Config(_))));
+    }
+    #[test]
+    fn locates_bad_blocks_and_overflowing_costs() {
+        let machine = CLAW_INSTRUCTIONS.split("\n\n").next().unwrap().trim();
+        let swapped = "Button B: X+1, Y+1\nButton A: X+1, Y+1\nPrize: X=1, Y=1";
+        let error = DayThirteen::parse(&format!("{}\n\n{}\n", machine, swapped)).unwrap_err();
+        assert_eq!(error.location().map(|location| location.line), Some(5));
+
+        let far = "Button A: X+1, Y+1\nButton B: X+0, Y+0\nPrize: X=18446744073709551615, Y=18446744073709551615";
+        let arcade = DayThirteen::parse(far).unwrap();
+        let game = arcade.games().next().unwrap();
+        assert!(matches!(game.evil_token_cost(), Err(Error::NoAnswer(_))));
+    }
+    #[test]
+    fn parallel_buttons() {
+        let game = |(ax, ay), (bx, by), (x, y)| ClawGame {
+            button_a: Button {
+                x_plus: ax,
+                y_plus: ay,
+            },
+            button_b: Button {
+                x_plus: bx,
+                y_plus: by,
+            },
+            prize: Prize { x, y },
+        };
+        // B is cheaper per step, so as many of it as the prize allows
+        let b_is_cheaper = game((3, 6), (1, 2), (10, 20));
+        assert_eq!(b_is_cheaper.evil_button_presses(), Some((0, 10)));
+        // A goes five times as far for three times the price
+        let a_is_cheaper = game((5, 5), (1, 1), (12, 12));
+        assert_eq!(a_is_cheaper.evil_button_presses(), Some((2, 2)));
+        assert_eq!(game((2, 2), (4, 4), (5, 5)).evil_button_presses(), None);
+        assert_eq!(game((1, 1), (2, 2), (3, 4)).evil_button_presses(), None);
+        assert_eq!(
+            game((0, 0), (0, 3), (0, 9)).evil_button_presses(),
+            Some((0, 3))
+        );
+    }
+    #[test]
+    fn wins_generated_prizes() {
+        for seed in 0..20 {
+            let mut rng = Rng::new(seed);
+            let machines: Vec<_> = (0..10).map(|_| generate::machine(&mut rng, true)).collect();
+            let input: Vec<String> = machines.iter().map(|(text, _)| text.clone()).collect();
+            let arcade = DayThirteen::parse(&input.join("\n")).unwrap();
+            let cost: u64 = machines.iter().filter_map(|(_, cost)| *cost).sum();
+            assert_eq!(
+                DayThirteen::part_one(&arcade).unwrap().to_string(),
+                cost.to_string()
+            );
+        }
+    }
+}
+/// Every claw machine in the arcade.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClawArcade {
+    games: Vec<ClawGame>,
+}
+impl ClawArcade {
+    /// The machines, in the order they're listed.
+    pub fn games(&self) -> impl Iterator<Item = &ClawGame> {
+        self.games.iter()
+    }
+}
+impl TryFrom<&str> for ClawArcade {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let instructions = parse_lines(value, |line| ClawInstruction::try_from(line))?;
+        // The same lines `parse_lines` kept, to point a bad block at its first line
+        let lines: Vec<(usize, &str)> = value
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| (index + 1, line))
+            .collect();
+        Ok(Self {
+            games: instructions
+                .chunks(3)
+                .zip(lines.chunks(3))
+                .map(|(block, lines)| {
+                    let (line, text) = lines[0];
+                    ClawGame::try_from(block).map_err(|error| error.on_line(line, text))
+                })
+                .collect::<Result<Vec<_>>>()?,
+        })
+    }
+}
+/// One claw machine: what each button does to the claw and where the prize is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClawGame {
+    button_a: Button,
+    button_b: Button,
+    prize: Prize,
+}
+impl ClawGame {
+    /// A machine with these buttons and prize.
+    pub fn new(button_a: Button, button_b: Button, prize: Prize) -> Self {
+        Self {
+            button_a,
+            button_b,
+            prize,
+        }
+    }
+    /// Solves the two button equations directly with Cramer's rule, since the
+    /// press counts are far too large to search.
+    pub fn evil_button_presses(&self) -> Option<(u64, u64)> {
+        let (ax, ay) = (self.button_a.x_plus as i128, self.button_a.y_plus as i128);
+        let (bx, by) = (self.button_b.x_plus as i128, self.button_b.y_plus as i128);
+        let (px, py) = (self.prize.x as i128, self.prize.y as i128);
+        let determinant = ax * by - ay * bx;
+        if determinant == 0 {
+            return self.parallel_button_presses();
+        }
+        let a_numerator = px * by - py * bx;
+        let b_numerator = ax * py - ay * px;
+        if a_numerator % determinant != 0 || b_numerator % determinant != 0 {
+            return None;
+        }
+        let a = a_numerator / determinant;
+        let b = b_numerator / determinant;
+        if a < 0 || b < 0 {
+            return None;
+        }
+        Some((a as u64, b as u64))
+    }
+    /// The cheapest presses when both buttons move the claw the same way, so there are
+    /// either no ways to win or a whole line of them. Along one axis `a * A + b * B = P`
+    /// has solutions `a0 + k * B / g` and `b0 - k * A / g`, and the cost only ever rises or
+    /// falls with `k`, so the cheapest is at one end of the presses that stay positive.
+    fn parallel_button_presses(&self) -> Option<(u64, u64)> {
+        let (a_step, b_step, target) = match (self.button_a, self.button_b) {
+            (a, b) if a.x_plus != 0 || b.x_plus != 0 => (a.x_plus, b.x_plus, self.prize.x),
+            (a, b) => (a.y_plus, b.y_plus, self.prize.y),
+        };
+        let (a_step, b_step, target) = (a_step as i128, b_step as i128, target as i128);
+        let (a, b) = match (a_step, b_step) {
+            (0, 0) => (0, 0),
+            // A button that doesn't move the claw is never worth pressing
+            (0, b_step) => (0, target / b_step),
+            (a_step, 0) => (target / a_step, 0),
+            (a_step, b_step) => {
+                let (gcd, x, y) = extended_gcd(a_step, b_step);
+                if target % gcd != 0 {
+                    return None;
+                }
+                let (a0, b0) = (x * (target / gcd), y * (target / gcd));
+                let (a_per_k, b_per_k) = (b_step / gcd, a_step / gcd);
+                let lowest = -a0.div_euclid(a_per_k);
+                let highest = b0.div_euclid(b_per_k);
+                if lowest > highest {
+                    return None;
+                }
+                let k = match 3 * a_per_k >= b_per_k {
+                    true => lowest,
+                    false => highest,
+                };
+                (a0 + k * a_per_k, b0 - k * b_per_k)
+            }
+        };
+        let (a, b) = (u64::try_from(a).ok()?, u64::try_from(b).ok()?);
+        let wins = self.button_a.x_plus * a + self.button_b.x_plus * b == self.prize.x
+            && self.button_a.y_plus * a + self.button_b.y_plus * b == self.prize.y;
+        wins.then_some((a, b))
+    }
+    /// The same machine with its prize `offset` further along both axes.
+    pub fn moved_prize(&self, offset: u64) -> Result<Self> {
+        let moved = |at: u64| {
+            at.checked_add(offset).ok_or_else(|| {
+                Error::config(format!(
+                    "prize_offset {} moves a prize out of range",
+                    offset
+                ))
+            })
+        };
+        Ok(Self {
+            prize: Prize {
+                x: moved(self.prize.x)?,
+                y: moved(self.prize.y)?,
+            },
+            ..*self
+        })
+    }
+    /// The cheapest presses of at most `max_presses` each that win the prize.
+    pub fn brute_forced_min(&self, max_presses: u64) -> Option<(u64, u64)> {
+        // Presses that would overflow go past any prize, so they never win
+        let reaches = |a: u64, b: u64, a_plus: u64, b_plus: u64, prize: u64| {
+            a_plus
+                .checked_mul(a)
+                .zip(b_plus.checked_mul(b))
+                .and_then(|(from_a, from_b)| from_a.checked_add(from_b))
+                == Some(prize)
+        };
+        (0..=max_presses)
+            .flat_map(|a| (0..=max_presses).map(move |b| (a, b)))
+            .filter(|&(a, b)| {
+                reaches(
+                    a,
+                    b,
+                    self.button_a.x_plus,
+                    self.button_b.x_plus,
+                    self.prize.x,
+                ) && reaches(
+                    a,
+                    b,
+                    self.button_a.y_plus,
+                    self.button_b.y_plus,
+                    self.prize.y,
+                )
+            })
+            .min_by_key(|&(a, b)| 3 * a as u128 + b as u128)
+    }
+    /// The tokens spent on A and on B to win with [`ClawGame::brute_forced_min`], or `None`
+    /// if it can't be won.
+    pub fn token_cost(&self, max_presses: u64) -> Result<Option<(u64, u64)>> {
+        self.brute_forced_min(max_presses)
+            .map(Self::press_cost)
+            .transpose()
+    }
+    /// The tokens spent on A and on B to win with [`ClawGame::evil_button_presses`], or
+    /// `None` if it can't be won.
+    pub fn evil_token_cost(&self) -> Result<Option<(u64, u64)>> {
+        self.evil_button_presses().map(Self::press_cost).transpose()
+    }
+    fn press_cost((a, b): (u64, u64)) -> Result<(u64, u64)> {
+        let a_cost = a.checked_mul(3).ok_or_else(|| {
+            Error::no_answer(format!(
+                "{} presses of A cost more tokens than fit in a u64",
+                a
+            ))
+        })?;
+        let b_cost = b;
+        Ok((a_cost, b_cost))
+    }
+}
+impl TryFrom<&[ClawInstruction]> for ClawGame {
+    type Error = Error;
+    fn try_from(value: &[ClawInstruction]) -> Result<Self, Self::Error> {
+        match *value {
+            [ClawInstruction::ButtonA(button_a), ClawInstruction::ButtonB(button_b), ClawInstruction::Prize(prize)] => {
+                Ok(Self {
+                    button_a,
+                    button_b,
+                    prize,
+                })
+            }
+            _ => Err(Error::parse(
+                "",
+                "every game needs a Button A, Button B and Prize line, in that order",
+            )),
+        }
+    }
+}
+
+/// `g`, `x` and `y` with `a * x + b * y = g`, the greatest common divisor of `a` and `b`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    match b {
+        0 => (a, 1, 0),
+        b => {
+            let (gcd, x, y) = extended_gcd(b, a % b);
+            (gcd, y, x - a / b * y)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ClawInstruction {
+    ButtonA(Button),
+    ButtonB(Button),
+    Prize(Prize),
+}
+impl TryFrom<&str> for ClawInstruction {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let axes = |op| {
+            separated_pair(
+                key_value("X", op, int()),
+                tag(", "),
+                key_value("Y", op, int()),
+            )
+        };
+        let button = |key| {
+            map(key_value(key, ": ", axes("+")), |(x_plus, y_plus)| Button {
+                x_plus,
+                y_plus,
+            })
+        };
+        let instruction = either(
+            map(button("Button A"), ClawInstruction::ButtonA),
+            either(
+                map(button("Button B"), ClawInstruction::ButtonB),
+                map(key_value("Prize", ": ", axes("=")), |(x, y)| {
+                    ClawInstruction::Prize(Prize { x, y })
+                }),
+            ),
+        );
+        parse_all(value, instruction)
+    }
+}
+/// How far one press of a button moves the claw.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Button {
+    /// Along X.
+    pub x_plus: u64,
+    /// Along Y.
+    pub y_plus: u64,
+}
+/// Where the claw has to end up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Prize {
+    /// Along X.
+    pub x: u64,
+    /// Along Y.
+    pub y: u64,
+}
@@ -0,0 +1,30 @@
+//! Corrupted memory with `size` instructions in it: `mul(x,y)`, `do()` and `don't()`, broken
+//! look-alikes of them, and junk in between.
+
+use elves::Rng;
+
+const JUNK: &[&str] = &[
+    "x", "%", "&", "!", "@", "^", "+", "*", "<", ">", "[", "]", "(", ")", " ", "'", "?", "mul",
+    "do", "don't", "from()", "what()", "select()", "where(", "mul[", "how()",
+];
+
+/// The memory on one line.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut memory = String::new();
+    for _ in 0..size {
+        let (x, y) = (rng.between(1, 999), rng.between(1, 999));
+        let instruction = match rng.below(10) {
+            0..=4 => format!("mul({},{})", x, y),
+            5 => "do()".to_string(),
+            6 => "don't()".to_string(),
+            7 => format!("mul({},{}]", x, y),
+            8 => format!("mul( {},{})", x, y),
+            _ => format!("mul({}*{})", x, y),
+        };
+        memory.push_str(&instruction);
+        for _ in 0..rng.below(4) {
+            memory.push_str(JUNK[rng.below(JUNK.len())]);
+        }
+    }
+    memory + "\n"
+}
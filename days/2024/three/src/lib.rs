@@ -1,30 +1,38 @@
+//! Day 3: Mull It Over. Corrupted memory with `mul(x,y)` instructions hidden in it, summed
+//! up by [`read_instructions`], and `do()` and `don't()` switching them on and off.
+#![warn(missing_docs)]
+
 use colored::*;
+use elves::{parse_number, Error, Result, Rng, Solution};
 use std::fmt::Display;
 
-fn main() {
-    println!("Hello, advent of code day three!");
-    let input_file = include_str!("../input.txt");
-    println!();
-    println!();
-    println!("Part one");
-    println!();
-    read_instructions(input_file, false);
-    println!();
-    println!();
-    println!("Part two");
-    println!();
-    read_instructions(input_file, true);
-    println!();
+pub mod generate;
+
+/// The puzzle, with the memory read as [`OpChar`]s.
+pub struct DayThree;
+impl Solution for DayThree {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
+    type Input = Vec<OpChar>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.chars().map(OpChar::from).collect())
+    }
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        Ok(read_instructions(input, false))
+    }
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        Ok(read_instructions(input, true))
+    }
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
 }
-fn read_instructions(input: &str, control: bool) {
-    let instructions = input
-        .chars()
-        .map(|c| OpChar::from(c))
-        .collect::<Vec<OpChar>>();
-    //println!("Instructions:");
-    //instructions.iter().for_each(|x| {
-    //    print!("{}", x);
-    //});
+elves::examples!(DayThree);
+
+/// The sum of every `mul` in the memory. With `control` set, only those after a `do()` count
+/// until the next `don't()`.
+pub fn read_instructions(instructions: &[OpChar], control: bool) -> u64 {
     let mut index = 0;
     let mut op_instructions = InstructionSet {
         is_doing: true,
@@ -35,7 +43,10 @@ fn read_instructions(input: &str, control: bool) {
         let remaining = &instructions[index..]; // Slice remaining instructions
 
         // Try to parse the instructions
-        if op_instructions.parse_instructions(remaining, control).is_ok() {
+        if op_instructions
+            .parse_instructions(remaining, control)
+            .is_ok()
+        {
             // Find the closing parenthesis to advance the index properly
             index += remaining
                 .iter()
@@ -48,24 +59,22 @@ fn read_instructions(input: &str, control: bool) {
         }
     }
 
-    let total = op_instructions
+    op_instructions
         .instructions
         .iter()
         .map(|x| x.operate())
-        .sum::<u64>();
-    println!("Total: {}", total);
+        .sum::<u64>()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    const TEST_INSTRUCTIONS: &str =
-        "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))mul( 2 , 2 )";
+    const TEST_INSTRUCTIONS: &str = include_str!("../examples/part-one.txt");
     #[test]
     fn read_instruction_set() {
         let instructions = TEST_INSTRUCTIONS
             .chars()
-            .map(|c| OpChar::from(c))
+            .map(OpChar::from)
             .collect::<Vec<OpChar>>();
         instructions.iter().for_each(|x| {
             print!("{}", x);
@@ -97,13 +106,12 @@ mod tests {
         println!("Total: {}", total);
         assert_eq!(total, 161);
     }
-    const PART_TWO_TEST: &str =
-        r"#xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+    const PART_TWO_TEST: &str = include_str!("../examples/part-two.txt");
     #[test]
     fn read_instruction_set_part_two() {
         let instructions = PART_TWO_TEST
             .chars()
-            .map(|c| OpChar::from(c))
+            .map(OpChar::from)
             .collect::<Vec<OpChar>>();
         instructions.iter().for_each(|x| {
             print!("{}", x);
@@ -136,20 +144,34 @@ mod tests {
         assert_eq!(total, 48);
     }
 }
+/// A character of the memory, as far as the instructions care.
 #[derive(Debug, Clone, PartialEq, Eq)]
-enum OpChar {
+pub enum OpChar {
+    /// `m`
     M,
+    /// `u`
     U,
+    /// `l`
     L,
+    /// `,`
     Comma,
+    /// A digit.
     Number(char),
+    /// `(`
     OpenParen,
+    /// `)`
     CloseParen,
+    /// `d`
     D,
+    /// `o`
     O,
+    /// `n`
     N,
+    /// `t`
     T,
+    /// `'`
     Apostrophe,
+    /// Anything no instruction uses.
     NoOp,
 }
 impl From<&char> for OpChar {
@@ -167,7 +189,7 @@ impl From<&char> for OpChar {
             't' => Self::T,
             '\'' => Self::Apostrophe,
             _ => {
-                if value.is_digit(10) {
+                if value.is_ascii_digit() {
                     Self::Number(*value)
                 } else {
                     Self::NoOp
@@ -191,7 +213,7 @@ impl From<char> for OpChar {
             't' => Self::T,
             '\'' => Self::Apostrophe,
             _ => {
-                if value.is_digit(10) {
+                if value.is_ascii_digit() {
                     Self::Number(value)
                 } else {
                     Self::NoOp
@@ -219,13 +241,17 @@ impl Display for OpChar {
         }
     }
 }
+/// Most of the memory is garbage, this is what's left over when it isn't an instruction.
+fn corrupted() -> Error {
+    Error::parse("", "corrupted instruction")
+}
 enum OpToken {
     Mul,
     Do,
     DoNot,
 }
 impl TryFrom<&[&OpChar]> for OpToken {
-    type Error = ();
+    type Error = Error;
     fn try_from(value: &[&OpChar]) -> Result<Self, Self::Error> {
         match value.len() {
             4 => match value.first() {
@@ -236,7 +262,7 @@ impl TryFrom<&[&OpChar]> for OpToken {
                     {
                         Ok(Self::Do)
                     } else {
-                        Err(())
+                        Err(corrupted())
                     }
                 }
                 Some(&OpChar::M) => {
@@ -246,10 +272,10 @@ impl TryFrom<&[&OpChar]> for OpToken {
                     {
                         Ok(Self::Mul)
                     } else {
-                        Err(())
+                        Err(corrupted())
                     }
                 }
-                _ => Err(()),
+                _ => Err(corrupted()),
             },
             7 => {
                 if value[0] == &OpChar::D
@@ -262,10 +288,10 @@ impl TryFrom<&[&OpChar]> for OpToken {
                 {
                     Ok(Self::DoNot)
                 } else {
-                    Err(())
+                    Err(corrupted())
                 }
             }
-            _ => Err(()),
+            _ => Err(corrupted()),
         }
     }
 }
@@ -293,20 +319,21 @@ impl Display for OpInstruction {
         }
     }
 }
+/// The `mul`s read so far, and whether they're currently switched on.
 pub struct InstructionSet {
     is_doing: bool,
     instructions: Vec<OpInstruction>,
 }
 impl InstructionSet {
-    fn parse_instructions(&mut self, input: &[OpChar], controlablle: bool) -> Result<(), ()> {
+    fn parse_instructions(&mut self, input: &[OpChar], controlablle: bool) -> Result<()> {
         let mut chars = input.iter().peekable();
-        while let Some(op_char) = chars.peek() {
+        if let Some(op_char) = chars.peek() {
             if op_char == &&OpChar::NoOp {
                 chars.next();
-                return Err(());
+                return Err(corrupted());
             }
-            match op_char {
-                &&OpChar::D => {
+            match **op_char {
+                OpChar::D => {
                     let mut word = Vec::new();
                     word.push(*op_char);
                     for _ in 0..7 {
@@ -316,9 +343,9 @@ impl InstructionSet {
                                 word.push(&OpChar::CloseParen);
                                 break;
                             }
-                            Some(OpChar::NoOp) => return Err(()),
+                            Some(OpChar::NoOp) => return Err(corrupted()),
                             Some(next_op_char) => word.push(*next_op_char),
-                            _ => return Err(()),
+                            _ => return Err(corrupted()),
                         }
                     }
                     let word = OpToken::try_from(&word[..])?;
@@ -335,12 +362,12 @@ impl InstructionSet {
                                 return Ok(());
                             }
                         }
-                        _ => return Err(()),
+                        _ => return Err(corrupted()),
                     }
                 }
-                &&OpChar::M => {
+                OpChar::M => {
                     if !self.is_doing {
-                        return Err(());
+                        return Err(corrupted());
                     }
                     let mut word = Vec::new();
                     word.push(*op_char);
@@ -351,9 +378,9 @@ impl InstructionSet {
                                 word.push(&OpChar::OpenParen);
                                 break;
                             }
-                            Some(OpChar::NoOp) => return Err(()),
+                            Some(OpChar::NoOp) => return Err(corrupted()),
                             Some(next_op_char) => word.push(*next_op_char),
-                            _ => return Err(()),
+                            _ => return Err(corrupted()),
                         }
                     }
                     matches!(OpToken::try_from(&word[..])?, OpToken::Mul);
@@ -364,21 +391,17 @@ impl InstructionSet {
 
                     while let Some(c) = chars.peek() {
                         match c {
-                            OpChar::CloseParen => {
-                                if found_comma {
-                                    let new_instruction = OpInstruction::Mul(
-                                        value_one.parse().unwrap(),
-                                        value_two.parse().unwrap(),
-                                    );
-                                    self.instructions.push(new_instruction);
-                                    return Ok(());
-                                } else {
-                                    return Err(()); // Missing comma between values
-                                }
+                            OpChar::CloseParen if found_comma => {
+                                let new_instruction = OpInstruction::Mul(
+                                    parse_number(&value_one, "multiplicand")?,
+                                    parse_number(&value_two, "multiplier")?,
+                                );
+                                self.instructions.push(new_instruction);
+                                return Ok(());
                             }
                             OpChar::Comma => {
                                 if found_comma {
-                                    return Err(()); // Multiple commas found
+                                    return Err(corrupted()); // Multiple commas found
                                 }
                                 found_comma = true;
                                 chars.next(); // consume the comma
@@ -391,7 +414,7 @@ impl InstructionSet {
                                     value_one.push(*c);
                                 }
                             }
-                            _ => return Err(()), // Invalid character found
+                            _ => return Err(corrupted()), // Invalid character found
                         }
                         chars.next();
                     }
@@ -401,9 +424,9 @@ impl InstructionSet {
                 }
             }
             // If no valid "mul", just continue
-            return Err(());
+            return Err(corrupted());
         }
 
-        Err(()) // Return error if no valid "mul()" expression found
+        Err(corrupted()) // Return error if no valid "mul()" expression found
     }
 }
@@ -0,0 +1,19 @@
+//! A `size` by `size` garden of a few crops, planted in patches that often reach round
+//! corners and hold other crops inside them.
+
+use elves::{Grid, Rng};
+
+/// The garden, a letter per plot.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut garden = Grid::new(size, size, 'A');
+    for (x, y) in garden.coords() {
+        let crop = match rng.below(10) {
+            0..=3 if x > 0 => garden[(x - 1, y)],
+            4..=7 if y > 0 => garden[(x, y - 1)],
+            _ => *rng.pick(&['A', 'B', 'C', 'D', 'E', 'F']),
+        };
+        garden.set((x, y), crop);
+    }
+    garden.to_string()
+}
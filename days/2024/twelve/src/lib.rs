@@ -0,0 +1,243 @@
+//! Day 12: Garden Groups. A garden of plots split into regions of one crop, fenced at a price
+//! of area times perimeter, or area times sides in bulk.
+#![warn(missing_docs)]
+
+use elves::{Error, Grid, Point, Result, Rng, Solution};
+use std::{collections::HashSet, fmt::Display};
+
+pub mod generate;
+
+/// The day's [`Solution`]: the input is a grid of plots, one letter per crop.
+pub struct DayTwelve;
+impl Solution for DayTwelve {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 12;
+    type Input = GardenMap;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        GardenMap::try_from(input)
+    }
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        Ok(input.calculate_fencing_cost())
+    }
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        Ok(input.calculate_bulk_fencing_cost())
+    }
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+elves::examples!(DayTwelve);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const GARDEN_MAP: &str = include_str!("../examples/small.txt");
+    #[test]
+    fn read_garden_map() {
+        let garden_map = GardenMap::try_from(GARDEN_MAP).unwrap();
+        println!("{:?}", garden_map);
+        elves::assert_snapshot!("small", garden_map);
+    }
+    #[test]
+    fn find_crop_regions() {
+        let garden_map = GardenMap::try_from(GARDEN_MAP).unwrap();
+        println!("{}", garden_map);
+        assert_eq!(garden_map.map.len(), 5);
+    }
+    const LARGE_GARDEN_MAP: &str = include_str!("../examples/larger.txt");
+    #[test]
+    fn fencing_cost() {
+        let garden_map = GardenMap::try_from(LARGE_GARDEN_MAP).unwrap();
+        elves::assert_snapshot!("larger", garden_map);
+        println!("Regions: {}", garden_map.map.len());
+        garden_map.map.iter().for_each(|region| {
+            println!(
+                "Region {}, Area {} , perimeter {}",
+                region.crop.crop_type,
+                region.region_area(),
+                region.region_perimeter((garden_map.garden_width(), garden_map.garden_height()))
+            );
+            region.positions.iter().for_each(|position| {
+                print!("({}, {})  ", position.x, position.y);
+            });
+            println!();
+        });
+        assert_eq!(garden_map.calculate_fencing_cost(), 1930);
+    }
+    const BULK_GARDEN_MAP: &str = include_str!("../examples/bulk.txt");
+    #[test]
+    fn bulk_fencing() {
+        let garden_map = GardenMap::try_from(BULK_GARDEN_MAP).unwrap();
+        println!("{}", garden_map);
+        println!("Regions: {}", garden_map.map.len());
+        garden_map.map.iter().for_each(|region| {
+            println!(
+                "Region {}, Area {} , faces {}",
+                region.crop.crop_type,
+                region.region_area(),
+                region.calculate_faces(&garden_map)
+            );
+            region.positions.iter().for_each(|position| {
+                print!("({}, {})  ", position.x, position.y);
+            });
+            println!();
+        });
+        assert_eq!(garden_map.calculate_bulk_fencing_cost(), 368);
+    }
+}
+/// What grows on a plot, named by a letter.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Crop {
+    crop_type: char,
+}
+impl From<char> for Crop {
+    fn from(crop_type: char) -> Self {
+        Crop { crop_type }
+    }
+}
+impl Display for Crop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.crop_type)
+    }
+}
+/// Plots of the same crop that touch, directly or through each other.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct CropRegion {
+    crop: Crop,
+    positions: Vec<Point>,
+}
+impl CropRegion {
+    // Check if a position is part of the specified region
+    fn is_part_of_region(&self, position: &Point) -> bool {
+        self.positions.contains(position)
+    }
+    /// The crop grown throughout the region.
+    pub fn crop(&self) -> Crop {
+        self.crop
+    }
+    /// How many plots the region covers.
+    pub fn region_area(&self) -> i32 {
+        self.positions.len() as i32
+    }
+    /// How many plot edges border another region or the edge of a garden of size `bounds`.
+    pub fn region_perimeter(&self, bounds: (i64, i64)) -> i32 {
+        let mut perimeter = 0;
+
+        for position in &self.positions {
+            for neighbor in position.neighbours4() {
+                let is_inbounds = neighbor.x >= 0
+                    && neighbor.x < bounds.0
+                    && neighbor.y >= 0
+                    && neighbor.y < bounds.1;
+                // Check if neighbor is outside the garden or not part of the same region
+                if !self.is_part_of_region(&neighbor) || !is_inbounds {
+                    perimeter += 1;
+                }
+            }
+        }
+
+        perimeter
+    }
+    /// A region has as many straight sides as it has corners, so count those instead.
+    pub fn calculate_faces(&self, region: &GardenMap) -> i32 {
+        let mut total_faces = 0;
+        let in_region = |position: Point| {
+            region.is_within_bounds(&position) && self.is_part_of_region(&position)
+        };
+
+        for position in &self.positions {
+            for (dx, dy) in [(-1, -1), (1, -1), (1, 1), (-1, 1)] {
+                let side = in_region(*position + Point::new(dx, 0));
+                let vertical = in_region(*position + Point::new(0, dy));
+                let diagonal = in_region(*position + Point::new(dx, dy));
+                // Outside corner, or inside corner of an L-shaped bend
+                if (!side && !vertical) || (side && vertical && !diagonal) {
+                    total_faces += 1;
+                }
+            }
+        }
+
+        total_faces
+    }
+}
+/// The garden, with its plots and the regions they make up.
+#[derive(Debug)]
+pub struct GardenMap {
+    map: HashSet<CropRegion>,
+    plots: Grid<Crop>,
+}
+impl GardenMap {
+    fn garden_width(&self) -> i64 {
+        self.plots.width() as i64
+    }
+    fn garden_height(&self) -> i64 {
+        self.plots.height() as i64
+    }
+    /// Every region, in no particular order.
+    pub fn regions(&self) -> impl Iterator<Item = &CropRegion> {
+        self.map.iter()
+    }
+    /// The price of fencing every region, at its area times its perimeter.
+    pub fn calculate_fencing_cost(&self) -> i32 {
+        let garden_width = self.garden_width();
+        let garden_height = self.garden_height();
+        self.map.iter().fold(0, |mut total_cost, region| {
+            let perimeter = region.region_perimeter((garden_width, garden_height));
+            let area = region.region_area();
+            total_cost += perimeter * area;
+            total_cost
+        })
+    }
+    /// The bulk price of fencing every region, at its area times its number of sides.
+    pub fn calculate_bulk_fencing_cost(&self) -> i32 {
+        self.map.iter().fold(0, |mut total_cost, region| {
+            let area = region.region_area();
+            let faces = region.calculate_faces(self);
+            total_cost += area * faces;
+            total_cost
+        })
+    }
+    fn is_within_bounds(&self, position: &Point) -> bool {
+        self.plots.at(*position).is_some()
+    }
+}
+impl TryFrom<&str> for GardenMap {
+    type Error = Error;
+    fn try_from(garden_map: &str) -> Result<Self, Self::Error> {
+        let plots = Grid::<Crop>::parse(garden_map)?;
+        let mut visited = Grid::new(plots.width(), plots.height(), false);
+        let mut map = HashSet::new();
+
+        for (start, crop) in plots.iter() {
+            if visited[start] {
+                continue;
+            }
+            // Flood fill every plot of the same crop reachable from here
+            visited[start] = true;
+            let mut positions = vec![];
+            let mut to_visit = vec![start];
+            while let Some(plot) = to_visit.pop() {
+                positions.push(Point::from(plot));
+                for neighbour in plots.neighbours4(plot) {
+                    if !visited[neighbour] && plots[neighbour] == *crop {
+                        visited[neighbour] = true;
+                        to_visit.push(neighbour);
+                    }
+                }
+            }
+            map.insert(CropRegion {
+                crop: *crop,
+                positions,
+            });
+        }
+
+        Ok(GardenMap { map, plots })
+    }
+}
+
+impl Display for GardenMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.plots)
+    }
+}
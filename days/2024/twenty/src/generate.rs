@@ -0,0 +1,34 @@
+//! A racetrack with a single track from start to end, winding through a maze of `size` by
+//! `size` rooms with walls everywhere else. It's the path between two far apart rooms of a
+//! maze with exactly one path between any two.
+
+use std::collections::HashMap;
+
+use elves::{random::maze, search, Grid, Rng};
+
+/// The racetrack with `S` and `E` at either end of the track.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let open = maze(rng, size.max(2), size.max(2));
+    let neighbours = |&at: &(usize, usize)| -> Vec<(usize, usize)> {
+        open.neighbours4(at).filter(|next| open[*next]).collect()
+    };
+    // Whichever room is furthest from the first, then whichever is furthest from that.
+    let furthest = |from| -> (usize, usize) {
+        let distances: HashMap<_, _> = search::bfs_distances(from, neighbours);
+        let (at, _) = distances
+            .into_iter()
+            .max_by_key(|&((x, y), distance)| (distance, y, x))
+            .expect("the start is always reached");
+        at
+    };
+    let start = furthest((1, 1));
+    let end = furthest(start);
+    let track = search::bfs(start, neighbours, |at| *at == end).unwrap_or_default();
+    let mut tiles = Grid::new(open.width(), open.height(), '#');
+    for at in track {
+        tiles.set(at, '.');
+    }
+    tiles.set(start, 'S');
+    tiles.set(end, 'E');
+    tiles.to_string()
+}
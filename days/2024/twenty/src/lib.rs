@@ -0,0 +1,215 @@
+//! Day 20: Race Condition. A single racetrack through a maze, with cheats that pass through
+//! walls for a few picoseconds counted by how much time they save.
+#![warn(missing_docs)]
+
+use std::fmt::{Display, Formatter};
+
+use colored::Colorize;
+use elves::{config::DayConfig, search, Error, Grid, Point, Result, Rng, Solution};
+use serde::Deserialize;
+
+pub mod generate;
+
+/// Which cheats count, from `[2024.20]` in `aoc.toml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Cheats only count when they save at least this many picoseconds.
+    pub minimum_saving: usize,
+}
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            minimum_saving: 100,
+        }
+    }
+}
+impl DayConfig for Config {
+    /// Any saving works: 0 counts every cheat.
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// The day's [`Solution`]: the input is the racetrack, with `S` for the start and `E` for the
+/// end.
+pub struct DayTwenty;
+impl Solution for DayTwenty {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 20;
+    type Input = Track;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Track::try_from(input.trim_end())
+    }
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        let Config { minimum_saving } = Self::config()?;
+        input.count_cheats(2, minimum_saving)
+    }
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        let Config { minimum_saving } = Self::config()?;
+        input.count_cheats(20, minimum_saving)
+    }
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+elves::examples!(DayTwenty);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_STRING: &str = include_str!("../examples/example.txt");
+    #[test]
+    fn race_cheats() {
+        let mut track = Track::try_from(TEST_STRING.trim()).unwrap();
+        let path = track.race_path().unwrap();
+        assert_eq!(path.len() - 1, 84);
+        track.mark_raced(&path);
+        assert_eq!(track.count_cheats(2, 64).unwrap(), 1);
+        assert_eq!(track.count_cheats(2, 20).unwrap(), 5);
+        assert_eq!(track.count_cheats(20, 76).unwrap(), 3);
+        assert_eq!(track.count_cheats(20, 74).unwrap(), 7);
+    }
+    #[test]
+    fn locates_bad_tiles_below_blank_lines() {
+        let error = DayTwenty::parse("\n\n#S#\n#x#\n#E#\n").err().unwrap();
+        assert_eq!(
+            error.location(),
+            Some(elves::Location { line: 4, column: 2 })
+        );
+    }
+    #[test]
+    fn unreachable_end_and_zero_saving() {
+        let walled_off = Track::try_from("#S#E#").unwrap();
+        assert!(matches!(
+            walled_off.count_cheats(2, 0),
+            Err(Error::NoAnswer(_))
+        ));
+        // Every pair of positions up to two apart, but no position cheating to itself.
+        let straight = Track::try_from("S..E").unwrap();
+        assert_eq!(straight.count_cheats(2, 0).unwrap(), 5);
+    }
+}
+/// The racetrack and where the race starts and ends.
+pub struct Track {
+    tiles: Grid<TrackTile>,
+    start: Point,
+    end: Point,
+}
+impl Track {
+    fn neighbours(&self, position: Point) -> Vec<Point> {
+        position
+            .neighbours4()
+            .filter(|next| {
+                self.tiles
+                    .at(*next)
+                    .is_some_and(|tile| *tile != TrackTile::Wall)
+            })
+            .collect()
+    }
+    /// The single track from start to end, start included.
+    pub fn race_path(&self) -> Result<Vec<Point>> {
+        search::bfs(
+            self.start,
+            |position| self.neighbours(*position),
+            |position| *position == self.end,
+        )
+        .ok_or_else(|| Error::no_answer("the end can't be reached from the start"))
+    }
+    /// Marks a path as raced.
+    pub fn mark_raced(&mut self, path: &[Point]) {
+        for coord in path.iter().filter_map(|position| position.coord()) {
+            self.tiles.set(coord, TrackTile::Raced);
+        }
+    }
+    /// How many cheats of up to `cheat_length` picoseconds through walls save at least
+    /// `minimum_saving` picoseconds over racing the track normally.
+    pub fn count_cheats(&self, cheat_length: usize, minimum_saving: usize) -> Result<usize> {
+        let path = self.race_path()?;
+        let mut picoseconds = Grid::new(self.tiles.width(), self.tiles.height(), None);
+        for (elapsed, position) in path.iter().enumerate() {
+            if let Some(coord) = position.coord() {
+                picoseconds.set(coord, Some(elapsed));
+            }
+        }
+        let reach = cheat_length as i64;
+        Ok(path
+            .iter()
+            .enumerate()
+            .map(|(elapsed, position)| {
+                (-reach..=reach)
+                    .flat_map(|dx| (-reach..=reach).map(move |dy| (dx, dy)))
+                    .map(|(dx, dy)| *position + Point::new(dx, dy))
+                    .filter(|cheat| position.manhattan(*cheat) <= cheat_length as u64)
+                    .filter_map(|cheat| *picoseconds.at(cheat)?)
+                    .filter(|arrival| *arrival != elapsed)
+                    .filter(|arrival| {
+                        let cheated = position.manhattan(path[*arrival]) as usize;
+                        *arrival >= elapsed + cheated + minimum_saving
+                    })
+                    .count()
+            })
+            .sum())
+    }
+}
+impl Display for Track {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.tiles)
+    }
+}
+impl TryFrom<&str> for Track {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let tiles = Grid::parse(value)?;
+        let start = tiles
+            .position(&TrackTile::Start)
+            .ok_or_else(|| Error::parse("", "the track has no start"))?;
+        let end = tiles
+            .position(&TrackTile::End)
+            .ok_or_else(|| Error::parse("", "the track has no end"))?;
+        let (start, end) = (Point::from(start), Point::from(end));
+        Ok(Track { tiles, start, end })
+    }
+}
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+/// What's on one tile of the racetrack.
+pub enum TrackTile {
+    /// Track, `.`.
+    Empty,
+    /// A wall only a cheat gets through, `#`.
+    Wall,
+    /// Where the race starts, `S`.
+    Start,
+    /// Where the race ends, `E`.
+    End,
+    /// Track already raced, shown as `X`.
+    Raced,
+}
+impl TryFrom<char> for TrackTile {
+    type Error = &'static str;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(TrackTile::Empty),
+            '#' => Ok(TrackTile::Wall),
+            'S' => Ok(TrackTile::Start),
+            'E' => Ok(TrackTile::End),
+            _ => Err("invalid track tile"),
+        }
+    }
+}
+impl Display for TrackTile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                TrackTile::Empty => ".".yellow(),
+                TrackTile::Wall => "#".red(),
+                TrackTile::Start => "S".blue(),
+                TrackTile::End => "E".green(),
+                TrackTile::Raced => "X".cyan(),
+            }
+        )
+    }
+}
@@ -0,0 +1,10 @@
+//! `size` door codes, three digits then `A`.
+
+use elves::Rng;
+
+/// A code per line.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{:03}A\n", rng.between(0, 999)))
+        .collect()
+}
@@ -0,0 +1,342 @@
+//! Day 21: Keypad Conundrum. Door codes typed through a chain of robots, each pressing the
+//! directional keypad of the next, costed by the presses on the outermost keypad.
+#![warn(missing_docs)]
+
+use elves::{parse_lines, Dir4, Error, Memo, Point, Result, Rng, Solution};
+use std::{collections::HashMap, fmt::Display};
+
+pub mod generate;
+
+/// The day's [`Solution`]: the input is one door code per line.
+pub struct DayTwentyOne;
+impl Solution for DayTwentyOne {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 21;
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(input, |code| {
+            match code.chars().find(|c| !c.is_ascii_digit() && *c != 'A') {
+                Some(c) => Err(Error::parse(c.to_string(), "not a key on the door keypad")),
+                None => Ok(code.to_string()),
+            }
+        })
+    }
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        Ok(total_complexity(input, 2))
+    }
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        Ok(total_complexity(input, 25))
+    }
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+elves::examples!(DayTwentyOne);
+
+/// Sum over every code of its numeric part times the presses needed on the outermost
+/// keypad, with `robots` directional keypads between us and the door.
+pub fn total_complexity(codes: &[String], robots: usize) -> usize {
+    let lock_keypad = LockKeypad::default();
+    let robot_keypad = RobotKeypad::default();
+    let mut cache = Memo::new();
+    codes
+        .iter()
+        .map(|code| {
+            let code_numeric = code
+                .chars()
+                .filter(|c| c.is_numeric())
+                .collect::<String>()
+                .parse::<usize>()
+                .unwrap_or(0);
+            code_numeric * lock_keypad.code_cost(code, robots, &robot_keypad, &mut cache)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = "980A";
+    const TEST_RESULT: &str = "<A^A>^^AvvvA";
+    const THIRD_RESULT: &str = "<v<A>>^AAAvA^A<vA<AA>>^AvAA<^A>A<v<A>A>^AAAvA<^A>A<vA>^A<A>A";
+    const FINAL_TEST_INPUT: &str = include_str!("../examples/example.txt");
+    #[test]
+    fn test_final_input() {
+        let codes = DayTwentyOne::parse(FINAL_TEST_INPUT).unwrap();
+        assert_eq!(total_complexity(&codes, 2), 126384);
+    }
+    #[test]
+    fn print_keypads() {
+        let lock_keypad = LockKeypad::default();
+        elves::assert_snapshot!("lock-keypad", lock_keypad);
+        let robot_keypad = RobotKeypad::default();
+        elves::assert_snapshot!("robot-keypad", robot_keypad);
+        let mut cache = Memo::new();
+        for (robots, result) in [(0, TEST_RESULT), (2, THIRD_RESULT)] {
+            let result_buttons: Vec<RobotKey> = result
+                .chars()
+                .filter_map(|c| c.try_into().ok())
+                .collect::<Vec<RobotKey>>();
+            assert_eq!(
+                lock_keypad.code_cost(TEST_INPUT, robots, &robot_keypad, &mut cache),
+                result_buttons.len()
+            );
+        }
+        // Moves between the same two keys are only worked out once per robot.
+        assert!(cache.stats().hits > 0);
+    }
+}
+
+/// Every way worth trying to get from one key to another: all horizontal moves then all
+/// vertical ones, or the other way around, skipping any that would cross the gap.
+fn moves_between(from: Point, to: Point, is_gap: impl Fn(&Point) -> bool) -> Vec<Vec<RobotKey>> {
+    let horizontal = if to.x < from.x {
+        RobotKey::Move(Dir4::Left)
+    } else {
+        RobotKey::Move(Dir4::Right)
+    };
+    let vertical = if to.y < from.y {
+        RobotKey::Move(Dir4::Up)
+    } else {
+        RobotKey::Move(Dir4::Down)
+    };
+    let horizontal_moves = vec![horizontal; from.x.abs_diff(to.x) as usize];
+    let vertical_moves = vec![vertical; from.y.abs_diff(to.y) as usize];
+
+    let mut moves = Vec::new();
+    if !is_gap(&Point::new(to.x, from.y)) {
+        moves.push([horizontal_moves.clone(), vertical_moves.clone()].concat());
+    }
+    if !is_gap(&Point::new(from.x, to.y)) {
+        moves.push([vertical_moves, horizontal_moves].concat());
+    }
+    moves.dedup();
+    moves
+        .into_iter()
+        .map(|mut keys| {
+            keys.push(RobotKey::A);
+            keys
+        })
+        .collect()
+}
+
+/// The directional keypad every robot, and you, types on.
+pub struct RobotKeypad {
+    keys: HashMap<Point, RobotKey>,
+}
+impl RobotKeypad {
+    fn is_gap(&self, pos: &Point) -> bool {
+        self.keys.get(pos) == Some(&RobotKey::Gap)
+    }
+    fn position_of(&self, key: RobotKey) -> Point {
+        *self
+            .keys
+            .iter()
+            .find(|(_, k)| **k == key)
+            .expect("Key is on the keypad")
+            .0
+    }
+    /// Presses needed on the outermost keypad to have the robot `robots` keypads away
+    /// type `sequence`, every robot starting on `A`.
+    pub fn sequence_cost(
+        &self,
+        sequence: &[RobotKey],
+        robots: usize,
+        cache: &mut Memo<(RobotKey, RobotKey, usize), usize>,
+    ) -> usize {
+        if robots == 0 {
+            return sequence.len();
+        }
+        let mut current = RobotKey::A;
+        let mut cost = 0;
+        for &next in sequence {
+            cost += self.move_cost(current, next, robots, cache);
+            current = next;
+        }
+        cost
+    }
+    fn move_cost(
+        &self,
+        from: RobotKey,
+        to: RobotKey,
+        robots: usize,
+        cache: &mut Memo<(RobotKey, RobotKey, usize), usize>,
+    ) -> usize {
+        cache.get_or_insert_with((from, to, robots), |cache| {
+            moves_between(self.position_of(from), self.position_of(to), |pos| {
+                self.is_gap(pos)
+            })
+            .iter()
+            .map(|moves| self.sequence_cost(moves, robots - 1, cache))
+            .min()
+            .unwrap_or(usize::MAX)
+        })
+    }
+}
+/// The numeric keypad on the door.
+pub struct LockKeypad {
+    keys: HashMap<Point, LockKey>,
+}
+impl LockKeypad {
+    fn is_gap(&self, pos: &Point) -> bool {
+        self.keys.get(pos) == Some(&LockKey::Gap)
+    }
+    fn position_of(&self, c: char) -> Point {
+        match c {
+            '0' => Point::new(1, 3),
+            '1' => Point::new(0, 2),
+            '2' => Point::new(1, 2),
+            '3' => Point::new(2, 2),
+            '4' => Point::new(0, 1),
+            '5' => Point::new(1, 1),
+            '6' => Point::new(2, 1),
+            '7' => Point::new(0, 0),
+            '8' => Point::new(1, 0),
+            '9' => Point::new(2, 0),
+            'A' => Point::new(2, 3),
+            _ => unreachable!("codes are checked when parsing"),
+        }
+    }
+    /// Fewest presses on the outermost keypad that get `code` typed on the door, with
+    /// `robots` directional keypads in between.
+    pub fn code_cost(
+        &self,
+        code: &str,
+        robots: usize,
+        robot_keypad: &RobotKeypad,
+        cache: &mut Memo<(RobotKey, RobotKey, usize), usize>,
+    ) -> usize {
+        let mut current_position = self.position_of('A');
+        let mut cost = 0;
+        for next_target in code.chars().map(|c| self.position_of(c)) {
+            cost += moves_between(current_position, next_target, |pos| self.is_gap(pos))
+                .iter()
+                .map(|moves| robot_keypad.sequence_cost(moves, robots, cache))
+                .min()
+                .unwrap_or(usize::MAX);
+            current_position = next_target;
+        }
+        cost
+    }
+}
+impl Default for LockKeypad {
+    fn default() -> Self {
+        let mut keys = HashMap::new();
+        keys.insert(Point::new(0, 0), LockKey::Seven);
+        keys.insert(Point::new(1, 0), LockKey::Eight);
+        keys.insert(Point::new(2, 0), LockKey::Nine);
+        keys.insert(Point::new(0, 1), LockKey::Four);
+        keys.insert(Point::new(1, 1), LockKey::Five);
+        keys.insert(Point::new(2, 1), LockKey::Six);
+        keys.insert(Point::new(0, 2), LockKey::One);
+        keys.insert(Point::new(1, 2), LockKey::Two);
+        keys.insert(Point::new(2, 2), LockKey::Three);
+        keys.insert(Point::new(0, 3), LockKey::Gap);
+        keys.insert(Point::new(1, 3), LockKey::Zero);
+        keys.insert(Point::new(2, 3), LockKey::A);
+        LockKeypad { keys }
+    }
+}
+impl Display for LockKeypad {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..4 {
+            for x in 0..3 {
+                let key = self.keys.get(&Point { x, y }).unwrap();
+                write!(f, "* {} * ", key)?;
+            }
+            writeln!(f)?;
+        }
+        writeln!(f)
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum LockKey {
+    Zero,
+    One,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    A,
+    Gap,
+}
+impl Display for LockKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let key = match self {
+            LockKey::Zero => "0",
+            LockKey::One => "1",
+            LockKey::Two => "2",
+            LockKey::Three => "3",
+            LockKey::Four => "4",
+            LockKey::Five => "5",
+            LockKey::Six => "6",
+            LockKey::Seven => "7",
+            LockKey::Eight => "8",
+            LockKey::Nine => "9",
+            LockKey::A => "A",
+            LockKey::Gap => " ",
+        };
+        write!(f, "{}", key)
+    }
+}
+impl Default for RobotKeypad {
+    fn default() -> Self {
+        let mut keys = HashMap::new();
+        keys.insert(Point::new(0, 0), RobotKey::Gap);
+        keys.insert(Point::new(1, 0), RobotKey::Move(Dir4::Up));
+        keys.insert(Point::new(2, 0), RobotKey::A);
+        keys.insert(Point::new(0, 1), RobotKey::Move(Dir4::Left));
+        keys.insert(Point::new(1, 1), RobotKey::Move(Dir4::Down));
+        keys.insert(Point::new(2, 1), RobotKey::Move(Dir4::Right));
+        RobotKeypad { keys }
+    }
+}
+impl Display for RobotKeypad {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..2 {
+            for x in 0..3 {
+                let key = self.keys.get(&Point { x, y }).unwrap();
+                write!(f, "* {} * ", key)?;
+            }
+            writeln!(f)?;
+        }
+        writeln!(f)
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// A key on the directional keypad.
+pub enum RobotKey {
+    /// Moves the next robot's arm one key.
+    Move(Dir4),
+    /// Has the next robot press the key its arm is on.
+    A,
+    /// The empty corner no arm may pass over.
+    Gap,
+}
+impl TryFrom<char> for RobotKey {
+    type Error = &'static str;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '^' | 'v' | '<' | '>' => Dir4::try_from(value)
+                .map(RobotKey::Move)
+                .map_err(|_| "Invalid character"),
+            'A' => Ok(RobotKey::A),
+            ' ' => Ok(RobotKey::Gap),
+            _ => Err("Invalid character"),
+        }
+    }
+}
+impl Display for RobotKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RobotKey::Move(direction) => write!(f, "{}", direction),
+            RobotKey::A => write!(f, "A"),
+            RobotKey::Gap => write!(f, " "),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+//! A network of `size` computers, each linked to a few others at random, with a LAN party
+//! of a fifth of them, and at least six, planted in it: every one of those is linked to every other.
+
+use std::collections::BTreeSet;
+
+use elves::Rng;
+
+/// One `a-b` link per line, in no particular order.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    network(rng, size).0
+}
+
+/// The network, and the planted LAN party sorted by name. It's bigger than any set of
+/// computers that happen to all be linked by chance, so it's the biggest there is.
+pub fn network(rng: &mut Rng, size: usize) -> (String, Vec<String>) {
+    let mut names: Vec<String> = ('a'..='z')
+        .flat_map(|first| ('a'..='z').map(move |second| format!("{}{}", first, second)))
+        .collect();
+    rng.shuffle(&mut names);
+    names.truncate(size.clamp(4, names.len()));
+    let party_size = (names.len() / 5).max(6).min(names.len());
+    let mut links = BTreeSet::new();
+    for (i, a) in names[..party_size].iter().enumerate() {
+        for b in &names[i + 1..party_size] {
+            links.insert((a.clone(), b.clone()));
+        }
+    }
+    for a in &names {
+        for _ in 0..2 {
+            let b = rng.pick(&names);
+            if a != b {
+                links.insert((a.min(b).clone(), a.max(b).clone()));
+            }
+        }
+    }
+    let mut links: Vec<String> = links
+        .into_iter()
+        .map(|(a, b)| match rng.chance(50) {
+            true => format!("{}-{}\n", a, b),
+            false => format!("{}-{}\n", b, a),
+        })
+        .collect();
+    rng.shuffle(&mut links);
+    let mut party = names[..party_size].to_vec();
+    party.sort();
+    (links.concat(), party)
+}
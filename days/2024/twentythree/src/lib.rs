@@ -0,0 +1,251 @@
+//! Day 23: LAN Party. A network of computers connected in pairs, searched for groups of three
+//! and for the largest group where every computer is connected to every other.
+#![warn(missing_docs)]
+
+use elves::{
+    check_lines, parse_lines,
+    parser::{parse_all, separated_pair, tag, try_map, word},
+    Error, Result, Rng, Solution,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+pub mod generate;
+
+/// The day's [`Solution`]: the input is one `ab-cd` connection per line.
+pub struct DayTwentyThree;
+impl Solution for DayTwentyThree {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 23;
+    type Input = NetworkMap;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        NetworkMap::try_from(input)
+    }
+    fn validate(input: &str) -> Vec<Error> {
+        check_lines(input, |line| ComputerConnection::try_from(line))
+    }
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        let mut triplets = input.find_triplets();
+        triplets.retain(|triplet| triplet.has_alias_starting_with_t());
+        Ok(triplets.len())
+    }
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        Ok(input.lan_party_password())
+    }
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+elves::examples!(DayTwentyThree);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_ALIAS_LIST: &str = include_str!("../examples/example.txt");
+    #[test]
+    fn lan_party() {
+        let map = NetworkMap::try_from(TEST_ALIAS_LIST).unwrap();
+        elves::assert_snapshot!("example", map);
+        let triplets = map.find_triplets();
+        assert_eq!(triplets.len(), 12);
+        assert_eq!(
+            triplets
+                .iter()
+                .filter(|triplet| triplet.has_alias_starting_with_t())
+                .count(),
+            7
+        );
+        assert_eq!(map.lan_party_password(), "co,de,ka,ta");
+    }
+    #[test]
+    fn reports_every_bad_connection() {
+        let errors = DayTwentyThree::validate("kh-tc\nqp-khx\nde cg\nka-co\nTa-ub\n");
+        let errors: Vec<String> = errors.iter().map(Error::to_string).collect();
+        assert_eq!(
+            errors,
+            [
+                "line 2, column 4: expected two lowercase letters, found \"khx\"",
+                "line 3, column 3: expected \"-\", found \" cg\"",
+                "line 5, column 1: expected two lowercase letters, found \"Ta-ub\"",
+            ]
+        );
+        assert!(DayTwentyThree::validate(TEST_ALIAS_LIST).is_empty());
+    }
+    #[test]
+    fn finds_planted_party() {
+        for seed in 0..10 {
+            let (input, party) = generate::network(&mut Rng::new(seed), 40 + seed as usize);
+            let map = NetworkMap::try_from(input.as_str()).unwrap();
+            assert_eq!(map.lan_party_password(), party.join(","));
+        }
+    }
+}
+/// Three computers all connected to each other, in sorted order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MultiplayerGame([ComputerAlias; 3]);
+impl MultiplayerGame {
+    /// Whether any of the three might be the Chief Historian's computer.
+    pub fn has_alias_starting_with_t(&self) -> bool {
+        self.0.iter().any(|alias| alias.0[0] == 't')
+    }
+}
+impl Display for MultiplayerGame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.0[0], self.0[1], self.0[2])
+    }
+}
+/// Which computers each computer is connected to.
+pub struct NetworkMap {
+    connections: HashMap<ComputerAlias, HashSet<ComputerAlias>>,
+}
+impl NetworkMap {
+    fn bron_kerbosh(&self) -> Vec<HashSet<ComputerAlias>> {
+        let mut r: HashSet<ComputerAlias> = HashSet::new();
+        let mut p: HashSet<ComputerAlias> = self.connections.keys().cloned().collect();
+        let mut x: HashSet<ComputerAlias> = HashSet::new();
+        let mut cliques: Vec<HashSet<ComputerAlias>> = Vec::new();
+        self.bron_kerbosh_recursive(&mut r, &mut p, &mut x, &mut cliques);
+        cliques
+    }
+
+    fn bron_kerbosh_recursive(
+        &self,
+        r: &mut HashSet<ComputerAlias>,
+        p: &mut HashSet<ComputerAlias>,
+        x: &mut HashSet<ComputerAlias>,
+        cliques: &mut Vec<HashSet<ComputerAlias>>,
+    ) {
+        // Base case: when P and X are both empty, we found a maximal clique
+        if p.is_empty() && x.is_empty() {
+            cliques.push(r.clone());
+            return;
+        }
+
+        let p_copy = p.clone();
+        for v in p_copy.iter() {
+            // Add v to the current clique R
+            r.insert(*v);
+
+            // Get the intersection of P and the neighbors of v (P_v)
+            let p_v = &self.connections[v] & p;
+            // Get the intersection of X and the neighbors of v (X_v)
+            let x_v = &self.connections[v] & x;
+
+            // Recurse with updated P_v and X_v
+            self.bron_kerbosh_recursive(r, &mut p_v.clone(), &mut x_v.clone(), cliques);
+
+            // Backtrack: Remove v from the current clique R
+            r.remove(v);
+
+            // Move v from P to X
+            p.remove(v);
+            x.insert(*v);
+        }
+    }
+    /// Every set of three computers that are all connected to each other.
+    pub fn find_triplets(&self) -> Vec<MultiplayerGame> {
+        let mut triplets: Vec<MultiplayerGame> = Vec::new();
+
+        // Walk each triangle once, from its smallest alias upwards
+        for (a, a_connections) in self.connections.iter() {
+            for b in a_connections.iter().filter(|b| *b > a) {
+                for c in self.connections[b].iter().filter(|c| *c > b) {
+                    if self.is_connected(a, c) {
+                        triplets.push(MultiplayerGame([*a, *b, *c]));
+                    }
+                }
+            }
+        }
+        triplets.sort();
+        triplets
+    }
+    /// Aliases of the largest fully connected group, sorted and joined by commas.
+    pub fn lan_party_password(&self) -> String {
+        let cliques = self.bron_kerbosh();
+        let Some(largest_clique) = cliques.iter().max_by_key(|clique| clique.len()) else {
+            return String::new();
+        };
+        let mut password = largest_clique.iter().collect::<Vec<_>>();
+        password.sort();
+        password
+            .iter()
+            .map(|alias| alias.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// Whether there's a connection between `a` and `b`.
+    pub fn is_connected(&self, a: &ComputerAlias, b: &ComputerAlias) -> bool {
+        if let Some(connections) = self.connections.get(a) {
+            connections.contains(b)
+        } else {
+            false
+        }
+    }
+}
+impl TryFrom<&str> for NetworkMap {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut map: HashMap<ComputerAlias, HashSet<ComputerAlias>> = HashMap::new();
+        for conn in parse_lines(value, |line| ComputerConnection::try_from(line))? {
+            map.entry(conn.0).or_default().insert(conn.1);
+            map.entry(conn.1).or_default().insert(conn.0);
+        }
+        Ok(Self { connections: map })
+    }
+}
+impl Display for NetworkMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Sorted, so the same network always draws the same way.
+        let mut aliases: Vec<_> = self.connections.keys().collect();
+        aliases.sort();
+        for alias in aliases {
+            write!(f, "{}: ", alias)?;
+            let mut connections: Vec<_> = self.connections[alias].iter().collect();
+            connections.sort();
+            for conn in connections {
+                write!(f, "{} ", conn)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+/// A computer's name, two lowercase letters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ComputerAlias([char; 2]);
+impl Display for ComputerAlias {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.0[0], self.0[1])
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct ComputerConnection(ComputerAlias, ComputerAlias);
+impl Display for ComputerConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.0, self.1)
+    }
+}
+impl TryFrom<&str> for ComputerConnection {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let alias = || try_map(word(), ComputerAlias::try_from);
+        let (a, b) = parse_all(value, separated_pair(alias(), tag("-"), alias()))?;
+        Ok(ComputerConnection(a, b))
+    }
+}
+impl TryFrom<&str> for ComputerAlias {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut letters = value.chars();
+        match (letters.next(), letters.next(), letters.next()) {
+            (Some(a), Some(b), None) if a.is_ascii_lowercase() && b.is_ascii_lowercase() => {
+                Ok(ComputerAlias([a, b]))
+            }
+            _ => Err("expected two lowercase letters"),
+        }
+    }
+}
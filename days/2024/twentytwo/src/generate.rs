@@ -0,0 +1,10 @@
+//! `size` initial secret numbers, each below 2^24.
+
+use elves::Rng;
+
+/// A secret number per line.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{}\n", rng.between(1, (1 << 24) - 1)))
+        .collect()
+}
@@ -0,0 +1,322 @@
+//! Day 22: Monkey Market. Buyers' secret numbers evolving into prices, added up after a day
+//! and searched for the run of four price changes that sells for the most bananas.
+#![warn(missing_docs)]
+
+use elves::{
+    check_lines, config::DayConfig, parallel, parse_lines, parse_number, Error, Result, Rng,
+    Solution,
+};
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+};
+
+pub mod generate;
+
+/// How long the market runs, from `[2024.22]` in `aoc.toml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Each buyer's secret number evolves this many times in a day.
+    pub rounds: usize,
+}
+impl Default for Config {
+    fn default() -> Self {
+        Config { rounds: 2000 }
+    }
+}
+impl DayConfig for Config {
+    /// Any number of rounds works, though part two needs four to see a sequence.
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// The day's [`Solution`]: the input is each buyer's first secret number, one per line.
+pub struct DayTwentyTwo;
+impl Solution for DayTwentyTwo {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 22;
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(input, parse_secret)
+    }
+    fn validate(input: &str) -> Vec<Error> {
+        check_lines(input, parse_secret)
+    }
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        let Config { rounds } = Self::config()?;
+        Ok(input
+            .iter()
+            .map(|initial| {
+                let mut secret = SecretNumber::new(*initial);
+                for _ in 0..rounds {
+                    secret.evolve_into_next();
+                }
+                secret.value()
+            })
+            .sum::<i64>())
+    }
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        let Config { rounds } = Self::config()?;
+        Ok(MonkeyOffers::from_secrets(input, rounds)
+            .best_offer()
+            .map_or(0, |(_, bananas)| bananas))
+    }
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+elves::examples!(DayTwentyTwo);
+
+/// Every secret number is pruned to below this.
+pub const PRUNE_MODULUS: i64 = 16777216;
+
+/// A buyer's first secret number, already pruned, so evolving it can't overflow.
+fn parse_secret(value: &str) -> Result<i64> {
+    let secret = parse_number(value, "secret number")?;
+    if !(0..PRUNE_MODULUS).contains(&secret) {
+        return Err(Error::parse(
+            value,
+            format!("secret numbers go from 0 to {}", PRUNE_MODULUS - 1),
+        ));
+    }
+    Ok(secret)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    const TEN_SECRET_NUMBERS: &str = r#"15887950
+16495136
+527345
+704524
+1553684
+12683156
+11100544
+12249484
+7753432
+5908254"#;
+    const SECRET_INITIAL_NUMBERS: &str = include_str!("../examples/example.txt");
+    const SECRET_FINAL_PRICES: &str = r#"8685429
+4700978
+15273692
+8667524"#;
+    #[test]
+    fn find_secret_number() {
+        let mut initial_secrets = SECRET_INITIAL_NUMBERS
+            .trim()
+            .lines()
+            .filter_map(|value| Some(SecretNumber::new(value.trim().parse::<i64>().ok()?)))
+            .collect::<Vec<SecretNumber>>();
+        let test_values = SECRET_FINAL_PRICES
+            .trim()
+            .lines()
+            .filter_map(|line| line.parse::<i64>().ok())
+            .collect::<Vec<i64>>();
+        let mut test_results = Vec::new();
+        initial_secrets.iter_mut().for_each(|secret| {
+            for _ in 0..2000 {
+                secret.evolve_into_next();
+            }
+            test_results.push(secret.latest_number);
+        });
+        assert_eq!(test_values, test_results);
+        assert_eq!(test_values.iter().sum::<i64>(), 37327623);
+    }
+    #[test]
+    fn find_secret_numbers() {
+        let test_values = TEN_SECRET_NUMBERS
+            .lines()
+            .map(|line| line.parse::<i64>().unwrap())
+            .collect::<Vec<i64>>();
+        let mut secret_number = SecretNumber::new(123);
+        let mut result_values = Vec::new();
+        for _ in 0..10 {
+            secret_number.evolve_into_next();
+            result_values.push(secret_number.latest_number);
+        }
+        assert_eq!(test_values, result_values);
+    }
+    const PART_TWO_SECRET_INITIAL_NUMBERS: &str = include_str!("../examples/part-two.txt");
+    #[test]
+    fn rejects_secrets_outside_the_prune_range() {
+        let errors = DayTwentyTwo::validate("1\n9223372036854775807\n-1\n16777215\n16777216");
+        let lines: Vec<_> = errors
+            .iter()
+            .filter_map(|error| error.location())
+            .map(|location| location.line)
+            .collect();
+        assert_eq!(lines, [2, 3, 5]);
+        assert!(DayTwentyTwo::parse("9223372036854775807").is_err());
+    }
+    #[test]
+    fn bananas() {
+        let secret_numbers = DayTwentyTwo::parse(PART_TWO_SECRET_INITIAL_NUMBERS).unwrap();
+        let offers = MonkeyOffers::from_secrets(&secret_numbers, Config::default().rounds);
+        assert_eq!(offers.best_offer(), Some(([-2, 1, -1, 3], 23)));
+    }
+    #[test]
+    fn price_changes() {
+        let mut secret_number = SecretNumber::new(123);
+        let mut test_results = Vec::new();
+        for _ in 0..10 {
+            secret_number.evolve_into_next();
+            test_results.push(secret_number.price);
+        }
+        test_results.iter().for_each(|price| {
+            println!("{}", price);
+        });
+        let mut offers = MonkeyOffers {
+            offers: HashMap::new(),
+        };
+        test_results.windows(4).for_each(|window| {
+            let offer = HidingSpotOffer::from(window);
+            *offers.offers.entry(offer.history).or_insert(0) += offer.price;
+        });
+        offers.offers.iter().for_each(|(offer, count)| {
+            println!("{:?} {}", offer, count);
+        });
+    }
+}
+/// The bananas every sequence of price changes would sell for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonkeyOffers {
+    offers: HashMap<OfferSequence, i64>,
+}
+impl MonkeyOffers {
+    /// Bananas each sequence of four price changes would fetch, summed over every buyer
+    /// selling at the first time the sequence shows up in their prices.
+    pub fn from_secrets(initial_secrets: &[i64], rounds: usize) -> MonkeyOffers {
+        let offers = parallel::map_reduce(
+            initial_secrets,
+            |initial| buyer_offers(*initial, rounds),
+            HashMap::new,
+            |offers, buyer| {
+                // Fold the smaller map into the bigger one.
+                let (mut offers, buyer) = match offers.len() >= buyer.len() {
+                    true => (offers, buyer),
+                    false => (buyer, offers),
+                };
+                for (history, price) in buyer {
+                    *offers.entry(history).or_insert(0) += price;
+                }
+                offers
+            },
+        );
+        MonkeyOffers { offers }
+    }
+    /// The sequence that sells for the most bananas, and how many.
+    pub fn best_offer(&self) -> Option<(OfferSequence, i64)> {
+        self.offers
+            .iter()
+            .max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(a.0)))
+            .map(|(sequence, bananas)| (*sequence, *bananas))
+    }
+}
+/// What one buyer pays for each sequence, the first time it shows up in their prices.
+fn buyer_offers(initial: i64, rounds: usize) -> HashMap<OfferSequence, i64> {
+    let mut secret = SecretNumber::new(initial);
+    let mut new_offers = Vec::new();
+    for _ in 0..rounds {
+        secret.evolve_into_next();
+        new_offers.push(secret.price);
+    }
+    let mut unique_offers: HashMap<OfferSequence, i64> = HashMap::new();
+    new_offers.windows(4).for_each(|window| {
+        let offer = HidingSpotOffer::from(window);
+        unique_offers.entry(offer.history).or_insert(offer.price);
+    });
+    unique_offers
+}
+/// Four price changes in a row, which the monkey waits for before selling.
+pub type OfferSequence = [i64; 4];
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct HidingSpotOffer {
+    price: i64,
+    history: OfferSequence,
+}
+impl From<&[BananaPrice]> for HidingSpotOffer {
+    fn from(prices: &[BananaPrice]) -> Self {
+        HidingSpotOffer {
+            price: prices[3].price,
+            history: [
+                prices[0].price_change.unwrap(),
+                prices[1].price_change.unwrap(),
+                prices[2].price_change.unwrap(),
+                prices[3].price_change.unwrap(),
+            ],
+        }
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct BananaPrice {
+    price: i64,
+    price_change: Option<i64>,
+}
+impl Display for BananaPrice {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "BananaPrice: {} {}",
+            self.price,
+            match self.price_change {
+                Some(value) => format!(" ({})", value),
+                None => "".to_string(),
+            }
+        )
+    }
+}
+/// A buyer's secret number, and the price it gives.
+pub struct SecretNumber {
+    latest_number: i64,
+    price: BananaPrice,
+}
+impl SecretNumber {
+    /// Evolves the number once: mixed with itself times 64, over 32 and times 2048, pruning
+    /// along the way.
+    pub fn evolve_into_next(&mut self) {
+        // calculate self * 64
+        let value = self.latest_number * 64;
+        // then MIX the number
+        self.mix(value);
+        // then PRUNE the number
+        self.prune();
+        let next_value = self.latest_number / 32;
+        self.mix(next_value);
+        let last_value = self.latest_number * 2048;
+
+        self.mix(last_value);
+        self.prune();
+        self.price.price_change = Some(self.last_digit() - self.price.price);
+        self.price.price = self.last_digit();
+    }
+    /// The number as it is now.
+    pub fn value(&self) -> i64 {
+        self.latest_number
+    }
+    fn last_digit(&self) -> i64 {
+        self.latest_number.abs() % 10
+    }
+    fn mix(&mut self, new_value: i64) {
+        // mix the number
+        self.latest_number ^= new_value;
+    }
+    fn prune(&mut self) {
+        // prune the number
+        self.latest_number %= PRUNE_MODULUS;
+    }
+    /// The number a buyer starts the day with.
+    pub fn new(number: i64) -> SecretNumber {
+        SecretNumber {
+            latest_number: number,
+            price: BananaPrice {
+                price: number.abs() % 10,
+                price_change: None,
+            },
+        }
+    }
+}
@@ -0,0 +1,24 @@
+//! `size` reports of five to eight levels, mostly creeping up or down by one to three with
+//! the odd bad step thrown in.
+
+use elves::Rng;
+
+/// A report per line.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let direction = *rng.pick(&[-1, 1]);
+            let mut level = rng.between(40, 60);
+            let mut levels = vec![level.to_string()];
+            for _ in 1..rng.between(5, 8) {
+                let step = match rng.chance(85) {
+                    true => rng.between(1, 3),
+                    false => rng.between(-2, 5),
+                };
+                level += direction * step;
+                levels.push(level.to_string());
+            }
+            levels.join(" ") + "\n"
+        })
+        .collect()
+}
@@ -1,35 +1,58 @@
-fn main() {
-    println!("Hello, advent of code day two!");
-    let input_file = std::fs::read_to_string("days/two/input.txt").unwrap();
-    let lines: Vec<&str> = input_file.lines().collect();
-    let mut danger_reports = vec![];
-    let mut safe_reports = vec![];
-    lines.iter().for_each(|line| {
-        let danger_report: Vec<i32> = line
-            .split_whitespace()
-            .filter_map(|x| x.parse::<i32>().ok())
-            .collect();
-        let is_safe = check_if_report_is_safe(danger_report.clone(), true);
-        if is_safe {
-            safe_reports.push(danger_report);
-        } else {
-            danger_reports.push(danger_report);
-        }
-    });
-    danger_reports.iter_mut().for_each(|report| {
-        report.remove(0);
-        let is_safe = check_if_report_is_safe(report.clone(), false);
-        if is_safe {
-            println!("Danger report: {:?} is safe: {}", report, is_safe);
-        }
-    });
-    // safe_reports.iter().for_each(|report| {
-    //     println!("{:?}", report);
-    // });
-    println!("Safe reports: {}", safe_reports.len());
+//! Day 2: Red-Nosed Reports. Each report is a line of levels, safe when they keep going up or
+//! down by one to three, or, with the Problem Dampener, when dropping one level makes it so.
+#![warn(missing_docs)]
+
+use std::fmt::Display;
+
+use elves::{check_lines, parse_lines, parse_number, Error, Result, Rng, Solution};
+
+pub mod generate;
+
+/// Counts the safe reports, each parsed by [`parse_report`].
+pub struct DayTwo;
+impl Solution for DayTwo {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(input, parse_report)
+    }
+    fn validate(input: &str) -> Vec<Error> {
+        check_lines(input, parse_report)
+    }
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        Ok(input
+            .iter()
+            .filter(|report| check_if_report_is_safe(report.to_vec(), false))
+            .count())
+    }
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        Ok(input
+            .iter()
+            .filter(|report| {
+                // The dampener check never drops the very first level, so try that separately
+                check_if_report_is_safe(report.to_vec(), true)
+                    || check_if_report_is_safe(report[1..].to_vec(), false)
+            })
+            .count())
+    }
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+}
+elves::examples!(DayTwo);
+
+/// One report, its levels separated by spaces.
+pub fn parse_report(line: &str) -> Result<Vec<i32>> {
+    line.split_whitespace()
+        .map(|x| parse_number::<i32>(x, "level"))
+        .collect()
 }
 
-fn check_if_report_is_safe(report: Vec<i32>, first_check: bool) -> bool {
+/// Whether the levels of `report` keep going the same way by one to three at a time. With
+/// `first_check` set, dropping any one level but the first is allowed to make it so.
+pub fn check_if_report_is_safe(report: Vec<i32>, first_check: bool) -> bool {
     let mut is_ascending_or_descending = None;
     let mut is_safe = true;
 
@@ -68,7 +91,6 @@ fn check_if_report_is_safe(report: Vec<i32>, first_check: bool) -> bool {
                     is_safe = true;
                     break;
                 }
-                println!("Failed Report: {:?}", report);
                 break; // Stop processing after trying both removals
             }
 
@@ -0,0 +1,480 @@
+//! Step-by-step terminal animations for puzzle simulations.
+//!
+//! An [`Animation`] steps a simulation and draws it after every step. Live, it redraws in
+//! place at a steady frame rate and takes keyboard controls. Headless, it prints every
+//! Nth frame one after another, for logs and tests.
+
+use std::{
+    collections::VecDeque,
+    fmt::Display,
+    io::{self, IsTerminal, Read, Write},
+    process::{Command, Stdio},
+    sync::{
+        mpsc::{self, Receiver, RecvTimeoutError},
+        Mutex, OnceLock,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Anything that can be drawn as one frame. Everything that implements [`Display`] can.
+pub trait RenderFrame {
+    fn render_frame(&self) -> String;
+}
+impl<T: Display + ?Sized> RenderFrame for T {
+    fn render_frame(&self) -> String {
+        self.to_string()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mode {
+    /// Redraw in place, `fps` times a second.
+    Live { fps: f64 },
+    /// Print every `every`th frame, and the last one, without waiting between them.
+    Headless { every: usize },
+}
+
+/// How to play a simulation. Live at 30 frames a second with keyboard controls by default.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Animation {
+    mode: Mode,
+    /// How many frames are kept to seek back through.
+    history: usize,
+    controls: bool,
+}
+impl Default for Animation {
+    fn default() -> Self {
+        Animation::live(30.0)
+    }
+}
+impl Animation {
+    pub fn live(fps: f64) -> Self {
+        Animation {
+            mode: Mode::Live { fps: fps.max(0.1) },
+            history: 1000,
+            controls: true,
+        }
+    }
+    pub fn headless(every: usize) -> Self {
+        Animation {
+            mode: Mode::Headless {
+                every: every.max(1),
+            },
+            history: 0,
+            controls: false,
+        }
+    }
+    pub fn history(mut self, frames: usize) -> Self {
+        self.history = frames.max(1);
+        self
+    }
+    /// Plays without reading the keyboard, even on a terminal.
+    pub fn without_controls(mut self) -> Self {
+        self.controls = false;
+        self
+    }
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+    /// Draws `state`, then calls `step` and draws it again until `step` returns `false`.
+    /// Returns how many steps were taken.
+    pub fn run<S: RenderFrame + ?Sized>(
+        &self,
+        state: &mut S,
+        step: impl FnMut(&mut S) -> bool,
+    ) -> io::Result<usize> {
+        let controls = self.controls && io::stdin().is_terminal() && io::stdout().is_terminal();
+        self.run_to(&mut io::stdout().lock(), controls, state, step)
+    }
+    /// [`Animation::run`], drawing to `out`, reading the keyboard only if `controls` is set.
+    pub fn run_to<S: RenderFrame + ?Sized>(
+        &self,
+        out: &mut impl Write,
+        controls: bool,
+        state: &mut S,
+        step: impl FnMut(&mut S) -> bool,
+    ) -> io::Result<usize> {
+        match self.mode {
+            Mode::Headless { every } => headless(out, every, state, step),
+            Mode::Live { fps } => {
+                let raw = controls.then(RawInput::enable).flatten();
+                let keys = raw.as_ref().map(|_| keys());
+                Player {
+                    frame_time: Duration::from_secs_f64(1.0 / fps),
+                    history: VecDeque::new(),
+                    capacity: self.history,
+                    dropped: 0,
+                    position: 0,
+                    finished: false,
+                    paused: false,
+                    screen: Screen::default(),
+                }
+                .play(out, keys, state, step)
+            }
+        }
+    }
+}
+
+fn headless<S: RenderFrame + ?Sized>(
+    out: &mut impl Write,
+    every: usize,
+    state: &mut S,
+    mut step: impl FnMut(&mut S) -> bool,
+) -> io::Result<usize> {
+    let mut frame = 0;
+    let mut drawn = true;
+    write_frame(out, frame, state)?;
+    while step(state) {
+        frame += 1;
+        drawn = frame % every == 0;
+        if drawn {
+            write_frame(out, frame, state)?;
+        }
+    }
+    if !drawn {
+        write_frame(out, frame, state)?;
+    }
+    out.flush()?;
+    Ok(frame)
+}
+
+fn write_frame<S: RenderFrame + ?Sized>(
+    out: &mut impl Write,
+    frame: usize,
+    state: &S,
+) -> io::Result<()> {
+    writeln!(out, "--- frame {} ---", frame)?;
+    let rendered = state.render_frame();
+    write!(out, "{}", rendered)?;
+    if !rendered.ends_with('\n') {
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+/// What a key press asks the player to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Control {
+    TogglePause,
+    /// Pause and move this many frames, backwards when negative.
+    Seek(isize),
+    /// Stop drawing and run the simulation to its end.
+    Skip,
+}
+impl Control {
+    const HELP: &'static str = "space pause  . , step  ] [ seek 10  q ^C skip to the end";
+    /// What Ctrl-C types once [`RawInput`] has stopped it interrupting the process.
+    const CTRL_C: u8 = 0x03;
+
+    fn from_key(key: u8) -> Option<Control> {
+        match key {
+            b' ' => Some(Control::TogglePause),
+            b'.' => Some(Control::Seek(1)),
+            b',' => Some(Control::Seek(-1)),
+            b']' => Some(Control::Seek(10)),
+            b'[' => Some(Control::Seek(-10)),
+            b'q' | Control::CTRL_C => Some(Control::Skip),
+            _ => None,
+        }
+    }
+}
+
+struct Player {
+    frame_time: Duration,
+    /// The most recent frames, for seeking back.
+    history: VecDeque<String>,
+    capacity: usize,
+    /// Frames that fell out of the history.
+    dropped: usize,
+    /// Index into `history` of the frame on screen.
+    position: usize,
+    /// The simulation has no steps left.
+    finished: bool,
+    paused: bool,
+    screen: Screen,
+}
+impl Player {
+    fn play<S: RenderFrame + ?Sized>(
+        mut self,
+        out: &mut impl Write,
+        keys: Option<&Mutex<Receiver<u8>>>,
+        state: &mut S,
+        mut step: impl FnMut(&mut S) -> bool,
+    ) -> io::Result<usize> {
+        let keys = keys.map(|keys| keys.lock().unwrap_or_else(|poison| poison.into_inner()));
+        self.history.push_back(state.render_frame());
+        // Only hidden while Ctrl-C comes through as a key, so the cursor is always shown again
+        let hide_cursor = keys.is_some();
+        if hide_cursor {
+            write!(out, "\x1b[?25l")?;
+        }
+        loop {
+            let started = Instant::now();
+            self.draw(out, keys.is_some())?;
+            if self.finished && self.at_newest() && !self.paused {
+                break;
+            }
+            let mut moved = false;
+            while let Some(control) = keys.as_ref().and_then(|keys| {
+                let wait = self.frame_time.saturating_sub(started.elapsed());
+                match keys.recv_timeout(wait) {
+                    Ok(key) => Some(Control::from_key(key)),
+                    Err(RecvTimeoutError::Timeout | RecvTimeoutError::Disconnected) => None,
+                }
+            }) {
+                match control {
+                    Some(Control::TogglePause) => self.paused = !self.paused,
+                    Some(Control::Seek(frames)) => {
+                        self.paused = true;
+                        self.seek(frames, state, &mut step);
+                        moved = true;
+                    }
+                    Some(Control::Skip) => {
+                        self.dropped += self.history.len() - 1;
+                        while !self.finished && step(state) {
+                            self.dropped += 1;
+                        }
+                        self.history.clear();
+                        self.history.push_back(state.render_frame());
+                        self.position = 0;
+                        self.finished = true;
+                        self.paused = false;
+                        moved = true;
+                    }
+                    None => {}
+                }
+                if moved {
+                    break;
+                }
+            }
+            if keys.is_none() {
+                thread::sleep(self.frame_time.saturating_sub(started.elapsed()));
+            }
+            if !self.paused && !moved {
+                self.seek(1, state, &mut step);
+            }
+        }
+        if hide_cursor {
+            write!(out, "\x1b[?25h")?;
+        }
+        out.flush()?;
+        Ok(self.dropped + self.history.len() - 1)
+    }
+    fn at_newest(&self) -> bool {
+        self.position + 1 == self.history.len()
+    }
+    fn seek<S: RenderFrame + ?Sized>(
+        &mut self,
+        frames: isize,
+        state: &mut S,
+        step: &mut impl FnMut(&mut S) -> bool,
+    ) {
+        if frames < 0 {
+            self.position = self.position.saturating_sub(frames.unsigned_abs());
+            return;
+        }
+        for _ in 0..frames {
+            if !self.at_newest() {
+                self.position += 1;
+            } else if !self.finished && step(state) {
+                self.history.push_back(state.render_frame());
+                self.position += 1;
+                if self.history.len() > self.capacity {
+                    self.history.pop_front();
+                    self.dropped += 1;
+                    self.position -= 1;
+                }
+            } else {
+                self.finished = true;
+            }
+        }
+    }
+    fn draw(&mut self, out: &mut impl Write, controls: bool) -> io::Result<()> {
+        let mut frame = self.history[self.position].clone();
+        if !frame.ends_with('\n') {
+            frame.push('\n');
+        }
+        let status = match (self.paused, self.finished && self.at_newest()) {
+            (true, _) => "paused",
+            (false, true) => "done",
+            (false, false) => "playing",
+        };
+        frame.push_str(&format!(
+            "frame {}  {}",
+            self.dropped + self.position,
+            status
+        ));
+        if controls {
+            frame.push_str(&format!("  ({})", Control::HELP));
+        }
+        frame.push('\n');
+        out.write_all(self.screen.draw(&frame).as_bytes())?;
+        out.flush()
+    }
+}
+
+/// What's on screen, so a redraw only rewrites the lines that changed. The whole
+/// redraw is built up front and written in one go, so a frame never shows half drawn.
+#[derive(Debug, Default)]
+struct Screen {
+    shown: Vec<String>,
+}
+impl Screen {
+    fn draw(&mut self, frame: &str) -> String {
+        let mut out = String::new();
+        if !self.shown.is_empty() {
+            out.push_str(&format!("\x1b[{}A", self.shown.len()));
+        }
+        out.push('\r');
+        let lines: Vec<String> = frame.lines().map(String::from).collect();
+        for (index, line) in lines.iter().enumerate() {
+            if self.shown.get(index) == Some(line) {
+                out.push_str("\x1b[1B");
+            } else {
+                out.push_str(line);
+                out.push_str("\x1b[K\r\n");
+            }
+        }
+        // Clear whatever is left of a taller previous frame
+        out.push_str("\x1b[J");
+        self.shown = lines;
+        out
+    }
+}
+
+/// Keys typed on stdin, one byte at a time. Read on a thread of its own, which there is
+/// only ever one of, since it never stops waiting for the next key.
+fn keys() -> &'static Mutex<Receiver<u8>> {
+    static KEYS: OnceLock<Mutex<Receiver<u8>>> = OnceLock::new();
+    KEYS.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for byte in io::stdin().lock().bytes() {
+                match byte {
+                    Ok(byte) if sender.send(byte).is_ok() => {}
+                    _ => break,
+                }
+            }
+        });
+        Mutex::new(receiver)
+    })
+}
+
+/// Has the terminal hand over key presses straight away, without echoing them, until
+/// dropped. Ctrl-C arrives as a key too rather than killing the process before the drop can
+/// put the terminal back. Uses `stty`, so there's nothing to restore, and no controls,
+/// without it.
+struct RawInput {
+    saved: String,
+}
+impl RawInput {
+    fn enable() -> Option<RawInput> {
+        let saved = stty(&["-g"])?;
+        stty(&["-icanon", "-echo", "-isig", "min", "1"])?;
+        Some(RawInput {
+            saved: saved.trim().to_string(),
+        })
+    }
+}
+impl Drop for RawInput {
+    fn drop(&mut self) {
+        stty(&[&self.saved]);
+    }
+}
+fn stty(args: &[&str]) -> Option<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Counter(usize);
+    impl Display for Counter {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            writeln!(f, "count")?;
+            write!(f, "{}", self.0)
+        }
+    }
+    fn count_to(limit: usize) -> impl FnMut(&mut Counter) -> bool {
+        move |counter| {
+            counter.0 += 1;
+            counter.0 <= limit
+        }
+    }
+
+    #[test]
+    fn headless_draws_every_nth_and_the_last_frame() {
+        let mut out = Vec::new();
+        let steps = Animation::headless(3)
+            .run_to(&mut out, false, &mut Counter(0), count_to(7))
+            .unwrap();
+        assert_eq!(steps, 7);
+        let out = String::from_utf8(out).unwrap();
+        let frames: Vec<&str> = out.lines().filter(|line| line.starts_with("---")).collect();
+        assert_eq!(
+            frames,
+            [
+                "--- frame 0 ---",
+                "--- frame 3 ---",
+                "--- frame 6 ---",
+                "--- frame 7 ---"
+            ]
+        );
+        assert!(out.ends_with("--- frame 7 ---\ncount\n8\n"));
+    }
+
+    #[test]
+    fn redraws_only_changed_lines() {
+        let mut screen = Screen::default();
+        assert_eq!(screen.draw("ab\ncd\n"), "\rab\x1b[K\r\ncd\x1b[K\r\n\x1b[J");
+        assert_eq!(
+            screen.draw("ab\nce\n"),
+            "\x1b[2A\r\x1b[1Bce\x1b[K\r\n\x1b[J"
+        );
+    }
+
+    #[test]
+    fn live_plays_every_step_and_seeks_back() {
+        let mut out = Vec::new();
+        let mut counter = Counter(0);
+        let steps = Animation::live(1000.0)
+            .run_to(&mut out, false, &mut counter, count_to(4))
+            .unwrap();
+        assert_eq!(steps, 4);
+        assert!(String::from_utf8(out).unwrap().contains("frame 4  done"));
+
+        let mut player = Player {
+            frame_time: Duration::ZERO,
+            history: VecDeque::from(["0".to_string()]),
+            capacity: 3,
+            dropped: 0,
+            position: 0,
+            finished: false,
+            paused: false,
+            screen: Screen::default(),
+        };
+        let mut counter = Counter(0);
+        let mut step = count_to(10);
+        player.seek(5, &mut counter, &mut step);
+        assert_eq!(
+            (player.dropped, player.position, player.history.len()),
+            (3, 2, 3)
+        );
+        player.seek(-10, &mut counter, &mut step);
+        assert_eq!(player.dropped + player.position, 3);
+        player.seek(1, &mut counter, &mut step);
+        assert_eq!(counter.0, 5);
+        assert_eq!(Control::from_key(b'['), Some(Control::Seek(-10)));
+        assert_eq!(Control::from_key(Control::CTRL_C), Some(Control::Skip));
+    }
+}
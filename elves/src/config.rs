@@ -0,0 +1,286 @@
+//! Puzzle parameters a day would otherwise hardcode, like the size of a grid or how many
+//! rounds to run, read from `aoc.toml` at the root of the workspace.
+//!
+//! Each day has a table keyed by year and then day, as in `answers.toml`, and only needs the
+//! values that differ from the real puzzle:
+//!
+//! ```toml
+//! [2024.14]
+//! width = 11
+//! height = 7
+//! ```
+//!
+//! A day reads its table into a struct of its own with [`Solution::config`], whose `Default`
+//! is the real puzzle and whose fields are all optional with `#[serde(default)]`. The struct
+//! is a [`DayConfig`], which turns away values the day can't run with:
+//!
+//! ```
+//! use elves::{config, DayId, Error, Result};
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! #[serde(default, deny_unknown_fields)]
+//! struct Floor {
+//!     width: i32,
+//!     height: i32,
+//! }
+//! impl Default for Floor {
+//!     fn default() -> Self {
+//!         Floor { width: 101, height: 103 }
+//!     }
+//! }
+//! impl config::DayConfig for Floor {
+//!     fn validate(&self) -> Result<()> {
+//!         match self.width > 0 && self.height > 0 {
+//!             true => Ok(()),
+//!             false => Err(Error::config("the floor needs at least one tile")),
+//!         }
+//!     }
+//! }
+//!
+//! let mut settings = config::Config::new();
+//! settings.set(DayId::new(2024, 14), "width", "11");
+//! config::set(settings);
+//! let floor: Floor = config::get(DayId::new(2024, 14)).unwrap();
+//! assert_eq!((floor.width, floor.height), (11, 103));
+//! ```
+//!
+//! The runner loads the file and its `--set` overrides once with [`set`]. An example can run
+//! at its own size too, with a `config` table next to its answers, which [`scoped`] applies on
+//! the example's thread and on the [`parallel`](crate::parallel) threads it starts.
+//!
+//! [`Solution::config`]: crate::Solution::config
+
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    sync::RwLock,
+};
+
+use serde::{de::DeserializeOwned, Deserialize};
+use toml::{Table, Value};
+
+use crate::{input::workspace_dir, DayId, Error, Result};
+
+static CONFIG: RwLock<Config> = RwLock::new(Config::new());
+
+thread_local! {
+    /// Values for one day that win over [`CONFIG`], on this thread only.
+    static SCOPED: RefCell<Option<Scope>> = const { RefCell::new(None) };
+}
+
+/// A day and the [`scoped`] values for it.
+pub(crate) type Scope = (DayId, Table);
+
+/// A day's parameters, read from its table in `aoc.toml`.
+pub trait DayConfig: DeserializeOwned {
+    /// Turns away values the day can't run with, like an empty grid, with [`Error::config`].
+    fn validate(&self) -> Result<()>;
+}
+
+/// Every day's table from `aoc.toml`.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(transparent)]
+pub struct Config {
+    years: BTreeMap<String, BTreeMap<String, Table>>,
+}
+impl Config {
+    /// No values for any day, so every day runs the real puzzle.
+    pub const fn new() -> Config {
+        Config {
+            years: BTreeMap::new(),
+        }
+    }
+    /// `aoc.toml` at the root of the workspace.
+    pub fn default_path() -> PathBuf {
+        workspace_dir().join("aoc.toml")
+    }
+    /// Reads the file at `path`, or nothing if there isn't one.
+    pub fn load(path: &Path) -> Result<Config, String> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => {
+                Config::parse(&contents).map_err(|error| format!("{}: {}", path.display(), error))
+            }
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Config::new()),
+            Err(error) => Err(format!("could not read {}: {}", path.display(), error)),
+        }
+    }
+    pub fn parse(contents: &str) -> Result<Config, String> {
+        let config: Config = toml::from_str(contents).map_err(|error| error.to_string())?;
+        for (year, days) in &config.years {
+            if year.parse::<u16>().is_err() {
+                return Err(format!("`{}` is not a year", year));
+            }
+            if let Some(day) = days
+                .keys()
+                .find(|day| !matches!(day.parse::<u8>(), Ok(1..=25)))
+            {
+                return Err(format!("`{}.{}` is not a day between 1 and 25", year, day));
+            }
+        }
+        Ok(config)
+    }
+    /// The values given for `id`.
+    pub fn day(&self, id: DayId) -> Option<&Table> {
+        self.years
+            .get(&id.year.to_string())?
+            .get(&id.day.to_string())
+    }
+    /// Sets `key` for `id` to `value`, read as TOML if it is and as a string if it isn't, so
+    /// both `11` and `north` work on the command line.
+    pub fn set(&mut self, id: DayId, key: &str, value: &str) {
+        let value = toml::from_str::<Table>(&format!("value = {}", value))
+            .ok()
+            .and_then(|mut table| table.remove("value"))
+            .unwrap_or_else(|| Value::String(value.to_string()));
+        self.years
+            .entry(id.year.to_string())
+            .or_default()
+            .entry(id.day.to_string())
+            .or_default()
+            .insert(key.to_string(), value);
+    }
+}
+
+/// Uses `config` for every day from now on, across the whole process.
+pub fn set(config: Config) {
+    *CONFIG
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = config;
+}
+
+/// `id`'s table read into `C` and validated, with any [`scoped`] values on this thread over the
+/// ones from [`set`].
+pub fn get<C: DayConfig>(id: DayId) -> Result<C> {
+    let mut table = CONFIG
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .day(id)
+        .cloned()
+        .unwrap_or_default();
+    SCOPED.with(|scoped| {
+        if let Some((scoped_id, values)) = &*scoped.borrow() {
+            if *scoped_id == id {
+                table.extend(values.clone());
+            }
+        }
+    });
+    let config: C = Value::Table(table)
+        .try_into()
+        .map_err(|error| Error::config(error.to_string().trim()))?;
+    config.validate()?;
+    Ok(config)
+}
+
+/// Runs `f` with `values` over `id`'s table, on this thread and any parallel work it starts.
+pub fn scoped<R>(id: DayId, values: Table, f: impl FnOnce() -> R) -> R {
+    with_scope(Some((id, values)), f)
+}
+
+/// This thread's [`scoped`] values, for the threads that work for it to take on.
+pub(crate) fn current_scope() -> Option<Scope> {
+    SCOPED.with(|scoped| scoped.borrow().clone())
+}
+
+/// Runs `f` with `scope` as this thread's [`scoped`] values.
+pub(crate) fn with_scope<R>(scope: Option<Scope>, f: impl FnOnce() -> R) -> R {
+    let previous = SCOPED.with(|scoped| scoped.replace(scope));
+    let result = f();
+    SCOPED.with(|scoped| scoped.replace(previous));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(default, deny_unknown_fields)]
+    struct Rounds {
+        rounds: u32,
+        name: String,
+    }
+    impl Default for Rounds {
+        fn default() -> Self {
+            Rounds {
+                rounds: 2000,
+                name: "real".to_string(),
+            }
+        }
+    }
+    impl DayConfig for Rounds {
+        fn validate(&self) -> Result<()> {
+            match self.name.is_empty() {
+                true => Err(Error::config("the name can't be empty")),
+                false => Ok(()),
+            }
+        }
+    }
+
+    #[test]
+    fn reads_tables_by_year_and_day() {
+        let config = Config::parse("[2024.22]\nrounds = 10\n").unwrap();
+        let rounds = config.day(DayId::new(2024, 22)).unwrap();
+        assert_eq!(rounds["rounds"].as_integer(), Some(10));
+        assert_eq!(config.day(DayId::new(2024, 21)), None);
+        assert!(Config::parse("[2024.26]\nrounds = 10\n")
+            .unwrap_err()
+            .contains("`2024.26` is not a day"));
+    }
+
+    #[test]
+    fn sets_values_as_toml_or_text() {
+        let mut config = Config::new();
+        let day = DayId::new(2024, 22);
+        config.set(day, "rounds", "10");
+        config.set(day, "name", "example");
+        let table = config.day(day).unwrap();
+        assert_eq!(table["rounds"], Value::Integer(10));
+        assert_eq!(table["name"], Value::String("example".to_string()));
+    }
+
+    #[test]
+    fn scoped_values_win_and_defaults_fill_in() {
+        // A day no other test uses, since `set` is shared by the whole process.
+        let day = DayId::new(2015, 3);
+        let mut config = Config::new();
+        config.set(day, "rounds", "10");
+        set(config);
+        assert_eq!(
+            get::<Rounds>(day).unwrap(),
+            Rounds {
+                rounds: 10,
+                name: "real".to_string()
+            }
+        );
+        let values = Table::from_iter([("rounds".to_string(), Value::Integer(3))]);
+        let scoped_rounds = scoped(day, values, || get::<Rounds>(day).unwrap().rounds);
+        assert_eq!(scoped_rounds, 3);
+        assert_eq!(get::<Rounds>(day).unwrap().rounds, 10);
+        // Parallel work sees the scoped values too, on whichever thread it runs
+        let values = Table::from_iter([("rounds".to_string(), Value::Integer(4))]);
+        let items: Vec<u32> = (0..100).collect();
+        let parallel_rounds = scoped(day, values, || {
+            crate::parallel::map(&items, |_| get::<Rounds>(day).unwrap().rounds)
+        });
+        assert!(parallel_rounds.iter().all(|rounds| *rounds == 4));
+
+        let mut empty = Config::new();
+        empty.set(day, "name", "\"\"");
+        set(empty);
+        assert_eq!(
+            get::<Rounds>(day).unwrap_err().to_string(),
+            "bad config: the name can't be empty"
+        );
+
+        let mut typo = Config::new();
+        typo.set(day, "round", "10");
+        set(typo);
+        assert!(get::<Rounds>(day)
+            .unwrap_err()
+            .to_string()
+            .contains("unknown field `round`"));
+        set(Config::new());
+    }
+}
@@ -0,0 +1,274 @@
+//! Checking two ways of solving the same thing against each other on made-up inputs.
+//!
+//! A day with a slow, obviously right solver and a fast, clever one runs both with
+//! [`assert_agree`] over inputs drawn from a seeded [`Rng`]. When they disagree the input is
+//! [shrunk](Shrink) step by step, keeping only the smaller inputs they still disagree on, so
+//! the failure shows the smallest counterexample found rather than whatever the generator
+//! happened to make:
+//!
+//! ```
+//! use elves::differential::assert_agree;
+//!
+//! assert_agree(
+//!     |rng, size| (0..size).map(|_| rng.below(100) as u64).collect::<Vec<_>>(),
+//!     |numbers| numbers.iter().sum::<u64>(),
+//!     |numbers| numbers.iter().fold(0, |total, n| total + n),
+//! );
+//! ```
+//!
+//! Every run tries the same [`CASES`] seeds, or `$AOC_DIFFERENTIAL_CASES` of them for a
+//! longer hunt.
+
+use std::fmt::Debug;
+
+use crate::Rng;
+
+/// How many seeds [`assert_agree`] tries by default.
+pub const CASES: u64 = 100;
+/// Overrides [`CASES`].
+pub const CASES_VAR: &str = "AOC_DIFFERENTIAL_CASES";
+/// Inputs grow from size 1 up to this and start over.
+const MAX_SIZE: usize = 20;
+/// Shrinking gives up after this many smaller inputs, in case it would go on for ever.
+const MAX_SHRINKS: usize = 1000;
+
+/// Smaller versions of a value, to narrow a failing input down. Each one should be simpler
+/// than the value itself, so that shrinking always ends.
+pub trait Shrink: Clone {
+    /// The simpler versions, the most promising first.
+    fn shrink(&self) -> Vec<Self>;
+}
+
+macro_rules! shrink_unsigned {
+    ($($number:ty),*) => {$(
+        impl Shrink for $number {
+            /// Towards zero: zero itself, half and one less.
+            fn shrink(&self) -> Vec<Self> {
+                let mut smaller = vec![0, self / 2, self.saturating_sub(1)];
+                smaller.dedup();
+                smaller.retain(|n| n < self);
+                smaller
+            }
+        }
+    )*};
+}
+shrink_unsigned!(u8, u16, u32, u64, usize);
+
+macro_rules! shrink_signed {
+    ($($number:ty),*) => {$(
+        impl Shrink for $number {
+            /// Towards zero: zero itself, half and one step closer.
+            fn shrink(&self) -> Vec<Self> {
+                let mut smaller = vec![0, self / 2, self - self.signum()];
+                smaller.dedup();
+                smaller.retain(|n| n.unsigned_abs() < self.unsigned_abs());
+                smaller
+            }
+        }
+    )*};
+}
+shrink_signed!(i8, i16, i32, i64);
+
+impl Shrink for bool {
+    fn shrink(&self) -> Vec<Self> {
+        match self {
+            true => vec![false],
+            false => vec![],
+        }
+    }
+}
+
+impl<T: Shrink> Shrink for Vec<T> {
+    /// Shorter lists first, without either half and then without each item, then the same
+    /// list with one item shrunk.
+    fn shrink(&self) -> Vec<Self> {
+        let mut smaller = Vec::new();
+        let half = self.len() / 2;
+        if half > 0 {
+            smaller.push(self[half..].to_vec());
+            smaller.push(self[..half].to_vec());
+        }
+        for i in 0..self.len() {
+            let mut without = self.clone();
+            without.remove(i);
+            smaller.push(without);
+        }
+        for (i, item) in self.iter().enumerate() {
+            for simpler in item.shrink() {
+                let mut list = self.clone();
+                list[i] = simpler;
+                smaller.push(list);
+            }
+        }
+        smaller
+    }
+}
+
+impl<A: Shrink, B: Shrink> Shrink for (A, B) {
+    fn shrink(&self) -> Vec<Self> {
+        let (a, b) = self;
+        let firsts = a.shrink().into_iter().map(|a| (a, b.clone()));
+        let seconds = b.shrink().into_iter().map(|b| (a.clone(), b));
+        firsts.chain(seconds).collect()
+    }
+}
+
+impl<A: Shrink, B: Shrink, C: Shrink> Shrink for (A, B, C) {
+    fn shrink(&self) -> Vec<Self> {
+        let (a, b, c) = self;
+        let firsts = a.shrink().into_iter().map(|a| (a, b.clone(), c.clone()));
+        let seconds = b.shrink().into_iter().map(|b| (a.clone(), b, c.clone()));
+        let thirds = c.shrink().into_iter().map(|c| (a.clone(), b.clone(), c));
+        firsts.chain(seconds).chain(thirds).collect()
+    }
+}
+
+/// An input two solvers gave different answers for, shrunk as far as it would go.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement<T, R> {
+    /// The seed the original input came from.
+    pub seed: u64,
+    /// How many times the input was shrunk.
+    pub shrinks: usize,
+    pub input: T,
+    pub first: R,
+    pub second: R,
+}
+
+/// The first seed in `seeds` whose input `first` and `second` disagree on, with that input
+/// shrunk. `generate` gets a size that grows with the seed.
+pub fn find<T: Shrink, R: PartialEq>(
+    seeds: impl IntoIterator<Item = u64>,
+    generate: impl Fn(&mut Rng, usize) -> T,
+    first: impl Fn(&T) -> R,
+    second: impl Fn(&T) -> R,
+) -> Option<Disagreement<T, R>> {
+    let disagree = |input: &T| {
+        let (a, b) = (first(input), second(input));
+        (a != b).then_some((a, b))
+    };
+    seeds.into_iter().find_map(|seed| {
+        let size = seed as usize % MAX_SIZE + 1;
+        let input = generate(&mut Rng::new(seed), size);
+        let answers = disagree(&input)?;
+        let (input, (first, second), shrinks) = shrink(input, answers, disagree);
+        Some(Disagreement {
+            seed,
+            shrinks,
+            input,
+            first,
+            second,
+        })
+    })
+}
+
+/// Swaps `input` for the first simpler version `check` still fails on, for as long as there
+/// is one. Returns the last input, what `check` said about it, and how many steps it took.
+pub fn shrink<T: Shrink, F>(
+    mut input: T,
+    mut failure: F,
+    check: impl Fn(&T) -> Option<F>,
+) -> (T, F, usize) {
+    let mut shrinks = 0;
+    'shrinking: while shrinks < MAX_SHRINKS {
+        for simpler in input.shrink() {
+            if let Some(simpler_failure) = check(&simpler) {
+                input = simpler;
+                failure = simpler_failure;
+                shrinks += 1;
+                continue 'shrinking;
+            }
+        }
+        break;
+    }
+    (input, failure, shrinks)
+}
+
+/// Panics with the smallest input found if `first` and `second` disagree on any of the
+/// inputs `generate` makes.
+#[track_caller]
+pub fn assert_agree<T: Shrink + Debug, R: PartialEq + Debug>(
+    generate: impl Fn(&mut Rng, usize) -> T,
+    first: impl Fn(&T) -> R,
+    second: impl Fn(&T) -> R,
+) {
+    if let Some(disagreement) = find(0..cases(), generate, first, second) {
+        panic!(
+            "the solvers disagree on {:?} (seed {}, shrunk {} times): {:?} != {:?}",
+            disagreement.input,
+            disagreement.seed,
+            disagreement.shrinks,
+            disagreement.first,
+            disagreement.second
+        );
+    }
+}
+
+/// [`CASES`], unless `$AOC_DIFFERENTIAL_CASES` says otherwise.
+pub fn cases() -> u64 {
+    std::env::var(CASES_VAR)
+        .ok()
+        .and_then(|cases| cases.parse().ok())
+        .unwrap_or(CASES)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shrinks_towards_zero_and_shorter() {
+        assert_eq!(10_u64.shrink(), vec![0, 5, 9]);
+        assert_eq!(1_u64.shrink(), vec![0]);
+        assert!(0_u64.shrink().is_empty());
+        assert_eq!((-7_i64).shrink(), vec![0, -3, -6]);
+        assert_eq!(
+            vec![3_u8, 1].shrink(),
+            vec![
+                vec![1],
+                vec![3],
+                vec![1],
+                vec![3],
+                vec![0, 1],
+                vec![1, 1],
+                vec![2, 1],
+                vec![3, 0]
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_the_smallest_counterexample() {
+        // Forgets any number over 40, so it's wrong as soon as the list has one.
+        let broken_sum = |numbers: &Vec<u64>| numbers.iter().filter(|n| **n <= 40).sum::<u64>();
+        let disagreement = find(
+            0..100,
+            |rng, size| (0..size).map(|_| rng.below(100) as u64).collect(),
+            |numbers: &Vec<u64>| numbers.iter().sum::<u64>(),
+            broken_sum,
+        )
+        .unwrap();
+        assert_eq!(disagreement.input, vec![41]);
+        assert_eq!((disagreement.first, disagreement.second), (41, 0));
+        assert!(disagreement.shrinks > 0);
+    }
+
+    #[test]
+    fn passes_when_they_agree() {
+        assert_agree(
+            |rng, size| (rng.below(size + 1), rng.below(size + 1)),
+            |&(a, b)| a.max(b),
+            |&(a, b)| if a > b { a } else { b },
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the solvers disagree on (0, 1)")]
+    fn panics_with_the_counterexample() {
+        assert_agree(
+            |rng, size| (rng.below(size + 1), rng.below(size + 1)),
+            |&(a, b)| a.max(b),
+            |&(a, _)| a,
+        );
+    }
+}
@@ -0,0 +1,271 @@
+use std::fmt::{Display, Formatter};
+
+use crate::grid::GridError;
+
+/// Shorthand for results whose error is an [`Error`].
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Where in the puzzle input something went wrong, both counted from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+impl Display for Location {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Everything a day can fail with, from malformed input to a puzzle with no answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The input doesn't look like the puzzle said it would.
+    Parse {
+        location: Option<Location>,
+        /// The offending bit of input.
+        text: String,
+        message: String,
+    },
+    /// The input was fine, but there's no answer to be found in it.
+    NoAnswer(String),
+    /// Reading or writing something other than the input failed, e.g. drawing an animation.
+    Io(String),
+    /// The day's values in `aoc.toml` don't fit its [`config`](crate::config).
+    Config(String),
+}
+impl Error {
+    /// A parse error about `text`, to be pointed at its place in the input with
+    /// [`Error::on_line`] when the line it came from is known.
+    pub fn parse(text: impl Into<String>, message: impl Into<String>) -> Error {
+        Error::Parse {
+            location: None,
+            text: text.into(),
+            message: message.into(),
+        }
+    }
+    /// A parse error at a known place in the input.
+    pub fn at(
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Error {
+        Error::Parse {
+            location: Some(Location { line, column }),
+            text: text.into(),
+            message: message.into(),
+        }
+    }
+    pub fn no_answer(message: impl Into<String>) -> Error {
+        Error::NoAnswer(message.into())
+    }
+    pub fn config(message: impl Into<String>) -> Error {
+        Error::Config(message.into())
+    }
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Error::Parse { location, .. } => *location,
+            Error::NoAnswer(_) | Error::Io(_) | Error::Config(_) => None,
+        }
+    }
+    /// Points an error that doesn't know where it is yet at `line_text`, line `line` of
+    /// the input, in the column its text first shows up.
+    pub fn on_line(self, line: usize, line_text: &str) -> Error {
+        match self {
+            Error::Parse {
+                location: None,
+                text,
+                message,
+            } => {
+                let column = line_text
+                    .find(text.as_str())
+                    .map(|offset| line_text[..offset].chars().count() + 1)
+                    .unwrap_or(1);
+                Error::at(line, column, text, message)
+            }
+            error => error,
+        }
+    }
+    /// Moves an error located within a snippet of the input, e.g. by a
+    /// [`parser`](crate::parser), to where that snippet starts.
+    pub fn offset(self, start: Location) -> Error {
+        match self {
+            Error::Parse {
+                location: Some(location),
+                text,
+                message,
+            } => {
+                let column = match location.line {
+                    1 => start.column + location.column - 1,
+                    _ => location.column,
+                };
+                Error::at(start.line + location.line - 1, column, text, message)
+            }
+            error => error,
+        }
+    }
+}
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parse {
+                location,
+                text,
+                message,
+            } => {
+                if let Some(location) = location {
+                    write!(f, "{}: ", location)?;
+                }
+                write!(f, "{}", message)?;
+                if !text.is_empty() {
+                    write!(f, ", found {:?}", text)?;
+                }
+                Ok(())
+            }
+            Error::NoAnswer(message) => write!(f, "no answer: {}", message),
+            Error::Io(message) => write!(f, "{}", message),
+            Error::Config(message) => write!(f, "bad config: {}", message),
+        }
+    }
+}
+impl std::error::Error for Error {}
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error::Io(error.to_string())
+    }
+}
+impl<E: Display> From<GridError<E>> for Error {
+    fn from(error: GridError<E>) -> Self {
+        match error {
+            GridError::Ragged {
+                line,
+                expected,
+                found,
+            } => Error::at(
+                line,
+                expected.min(found) + 1,
+                "",
+                format!("line has {} tiles, expected {}", found, expected),
+            ),
+            GridError::Tile {
+                line,
+                column,
+                character,
+                error,
+            } => Error::at(line, column, character, error.to_string()),
+            GridError::Size { expected, found } => {
+                Error::parse("", format!("expected {} cells, found {}", expected, found))
+            }
+            GridError::TooBig { width, height } => Error::parse(
+                "",
+                format!("a {} by {} grid has too many cells", width, height),
+            ),
+        }
+    }
+}
+
+/// Parses every non-blank line of `input` with `parse`, pointing errors at the line
+/// they came from. Lines are trimmed first, and errors that already know where they are
+/// count from the start of the trimmed line.
+pub fn parse_lines<T>(input: &str, parse: impl FnMut(&str) -> Result<T>) -> Result<Vec<T>> {
+    each_line(input, parse).collect()
+}
+
+/// Like [`parse_lines`], but carries on past bad lines and returns every error, for a
+/// [`Solution::validate`](crate::Solution::validate) pass.
+pub fn check_lines<T>(input: &str, parse: impl FnMut(&str) -> Result<T>) -> Vec<Error> {
+    each_line(input, parse).filter_map(Result::err).collect()
+}
+
+fn each_line<'a, T>(
+    input: &'a str,
+    mut parse: impl FnMut(&str) -> Result<T> + 'a,
+) -> impl Iterator<Item = Result<T>> + 'a {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(move |(index, line)| {
+            let indent = line.chars().take_while(|c| c.is_whitespace()).count();
+            parse(line.trim()).map_err(|error| {
+                error
+                    .offset(Location {
+                        line: index + 1,
+                        column: indent + 1,
+                    })
+                    .on_line(index + 1, line)
+            })
+        })
+}
+
+/// Parses `text` as a number, or says what it was meant to be.
+pub fn parse_number<T: std::str::FromStr>(text: &str, what: &str) -> Result<T> {
+    text.trim()
+        .parse()
+        .map_err(|_| Error::parse(text.trim(), format!("invalid {}", what)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Grid;
+
+    #[test]
+    fn points_at_bad_input() {
+        let input = "\n1 2\n3 x\n";
+        let error = parse_lines(input, |line| {
+            line.split(' ')
+                .map(|number| parse_number::<u32>(number, "number"))
+                .collect::<Result<Vec<_>>>()
+        })
+        .unwrap_err();
+        assert_eq!(error.location(), Some(Location { line: 3, column: 3 }));
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 3: invalid number, found \"x\""
+        );
+
+        let error = parse_lines("1\n  2 3", |line| {
+            crate::parser::parse_all(line, crate::parser::int::<u32>())
+        })
+        .unwrap_err();
+        assert_eq!(error.location(), Some(Location { line: 2, column: 5 }));
+
+        let errors = check_lines("1\nx\n3\n 4y", |line| parse_number::<u32>(line, "number"));
+        let locations: Vec<_> = errors.iter().filter_map(Error::location).collect();
+        assert_eq!(
+            locations,
+            [
+                Location { line: 2, column: 1 },
+                Location { line: 4, column: 2 }
+            ]
+        );
+
+        // Without a line to search, the same text could be anywhere in the input.
+        let error = Error::parse("0", "not a stone");
+        assert_eq!(error.location(), None);
+        assert_eq!(error.to_string(), "not a stone, found \"0\"");
+        assert_eq!(
+            Error::no_answer("no path").to_string(),
+            "no answer: no path"
+        );
+    }
+
+    #[test]
+    fn grid_errors_keep_their_location() {
+        #[derive(Debug)]
+        struct Digit;
+        impl TryFrom<char> for Digit {
+            type Error = &'static str;
+            fn try_from(c: char) -> std::result::Result<Self, Self::Error> {
+                c.is_ascii_digit().then_some(Digit).ok_or("not a digit")
+            }
+        }
+        let error: Error = Grid::<Digit>::parse("12\n3?").unwrap_err().into();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: not a digit, found \"?\""
+        );
+    }
+}
@@ -0,0 +1,242 @@
+//! Sample inputs from the puzzle text, kept as files in each day's `examples/` folder.
+//!
+//! Every `examples/<name>.txt` becomes a test of its own. Answers the puzzle gives for an
+//! example go in `examples/answers.toml`, laid out like the workspace's `answers.toml`;
+//! only the parts with an answer are run, and an example without any only has to parse.
+//!
+//! ```toml
+//! [larger]
+//! one = 18
+//! two = "9"
+//! ```
+//!
+//! An example smaller than the real puzzle says how in a `config` table, which it runs with
+//! over the day's [`config`]:
+//!
+//! ```toml
+//! [example]
+//! one = 12
+//! config = { width = 11, height = 7 }
+//! ```
+//!
+//! A day opts in with a build script that calls [`generate`] and an `examples!` call
+//! naming its solution:
+//!
+//! ```ignore
+//! // build.rs
+//! fn main() {
+//!     elves::examples::generate();
+//! }
+//! // src/lib.rs
+//! elves::examples!(DayFour);
+//! ```
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::{config, DayId, Solution};
+
+/// Adds the tests [`generate`] wrote for the day's examples, run against `$day`.
+#[macro_export]
+macro_rules! examples {
+    ($day:ident) => {
+        #[cfg(test)]
+        mod examples {
+            #[allow(dead_code)]
+            type Day = super::$day;
+            include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+        }
+    };
+}
+
+/// What the puzzle says the answers to an example are.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ExampleAnswers {
+    one: Option<Answer>,
+    two: Option<Answer>,
+    /// The day's parameters for this example.
+    config: Option<toml::Table>,
+}
+/// Answers can be written as numbers or strings.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+enum Answer {
+    Number(i64),
+    Text(String),
+}
+impl Answer {
+    fn as_string(&self) -> String {
+        match self {
+            Answer::Number(number) => number.to_string(),
+            Answer::Text(text) => text.clone(),
+        }
+    }
+}
+
+/// Writes a test for every example of the crate being built, for `examples!` to pick up.
+/// Meant to be called from a day's build script.
+pub fn generate() {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is set");
+    let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR is set");
+    let dir = Path::new(&manifest_dir).join("examples");
+    println!("cargo:rerun-if-changed={}", dir.display());
+    let tests = tests(&dir).unwrap_or_else(|error| panic!("{}", error));
+    let path = PathBuf::from(out_dir).join("examples.rs");
+    std::fs::write(&path, tests)
+        .unwrap_or_else(|error| panic!("could not write {}: {}", path.display(), error));
+}
+
+/// The source of one `#[test]` per `.txt` file in `dir`.
+fn tests(dir: &Path) -> Result<String, String> {
+    let mut names = match std::fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+            .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    names.sort();
+
+    let answers_path = dir.join("answers.toml");
+    let mut answers: BTreeMap<String, ExampleAnswers> = match answers_path.exists() {
+        true => {
+            let contents = std::fs::read_to_string(&answers_path)
+                .map_err(|error| format!("could not read {}: {}", answers_path.display(), error))?;
+            toml::from_str(&contents)
+                .map_err(|error| format!("{}: {}", answers_path.display(), error))?
+        }
+        false => BTreeMap::new(),
+    };
+    if let Some(name) = answers.keys().find(|name| !names.contains(name)) {
+        return Err(format!(
+            "{} has answers for `{}`, but there's no {}.txt next to it",
+            answers_path.display(),
+            name,
+            name
+        ));
+    }
+
+    let mut tests = String::new();
+    for name in names {
+        let answers = answers.remove(&name).unwrap_or_default();
+        let answer = |answer: Option<Answer>| match answer {
+            Some(answer) => format!("Some({:?})", answer.as_string()),
+            None => "None".to_string(),
+        };
+        let (check, config) = match answers.config {
+            Some(config) => ("check_with", format!(", {:?}", config.to_string())),
+            None => ("check", String::new()),
+        };
+        tests.push_str(&format!(
+            "#[test]\nfn {}() {{\n    elves::examples::{}::<Day>({:?}, include_str!({:?}), {}, {}{});\n}}\n",
+            test_name(&name),
+            check,
+            name,
+            dir.join(format!("{}.txt", name)).display().to_string(),
+            answer(answers.one),
+            answer(answers.two),
+            config,
+        ));
+    }
+    Ok(tests)
+}
+
+/// A function name for the example, e.g. `part-two` is tested by `part_two`.
+fn test_name(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| match c.is_ascii_alphanumeric() {
+            true => c.to_ascii_lowercase(),
+            false => '_',
+        })
+        .collect();
+    match name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        true => name,
+        false => format!("example_{}", name),
+    }
+}
+
+/// [`check`] with `config`, a TOML table, over the day's own config.
+pub fn check_with<S: Solution>(
+    name: &str,
+    input: &str,
+    one: Option<&str>,
+    two: Option<&str>,
+    config: &str,
+) {
+    let values = toml::from_str(config)
+        .unwrap_or_else(|error| panic!("the config for examples/{}.txt: {}", name, error));
+    config::scoped(DayId::new(S::YEAR, S::DAY), values, || {
+        check::<S>(name, input, one, two)
+    });
+}
+
+/// Parses the example `name` and checks every part it has an answer for.
+pub fn check<S: Solution>(name: &str, input: &str, one: Option<&str>, two: Option<&str>) {
+    let parsed = S::parse(input).unwrap_or_else(|error| panic!("examples/{}.txt: {}", name, error));
+    if let Some(expected) = one {
+        match S::part_one(&parsed) {
+            Ok(answer) => assert_eq!(
+                answer.to_string(),
+                expected,
+                "part one of examples/{}.txt",
+                name
+            ),
+            Err(error) => panic!("part one of examples/{}.txt: {}", name, error),
+        }
+    }
+    if let Some(expected) = two {
+        match S::part_two(&parsed) {
+            Ok(answer) => assert_eq!(
+                answer.to_string(),
+                expected,
+                "part two of examples/{}.txt",
+                name
+            ),
+            Err(error) => panic!("part two of examples/{}.txt: {}", name, error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_a_test_per_example() {
+        let dir = std::env::temp_dir().join(format!("elves-examples-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("small.txt"), "1 2").unwrap();
+        std::fs::write(dir.join("2-large.txt"), "1 2 3").unwrap();
+        std::fs::write(dir.join("notes.md"), "not an example").unwrap();
+        std::fs::write(dir.join("answers.toml"), "[small]\none = 3\ntwo = \"x\"\n").unwrap();
+
+        let source = tests(&dir).unwrap();
+        let functions: Vec<&str> = source
+            .lines()
+            .filter(|line| line.starts_with("fn"))
+            .collect();
+        assert_eq!(functions, ["fn example_2_large() {", "fn small() {"]);
+        assert!(source.contains(r#"check::<Day>("2-large", include_str!("#));
+        assert!(source.contains(r#"small.txt"), Some("3"), Some("x"));"#));
+
+        std::fs::write(
+            dir.join("answers.toml"),
+            "[small]\none = 3\nconfig = { size = 7 }\n",
+        )
+        .unwrap();
+        let source = tests(&dir).unwrap();
+        assert!(source.contains(r#"check_with::<Day>("small", include_str!("#));
+        assert!(source.contains(r#"small.txt"), Some("3"), None, "size = 7\n");"#));
+
+        std::fs::write(dir.join("answers.toml"), "[tiny]\none = 3\n").unwrap();
+        assert!(tests(&dir).unwrap_err().contains("no tiny.txt"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
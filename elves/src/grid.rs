@@ -0,0 +1,435 @@
+use std::{
+    fmt::{Debug, Display, Formatter},
+    ops::{Index, IndexMut},
+};
+
+use crate::point::{Dir4, Dir8, Point};
+
+/// A cell position in a grid, as `(x, y)` with the origin in the top left corner.
+pub type Coord = (usize, usize);
+
+/// Dense, row-major 2D map with bounds-checked access.
+///
+/// Width and height are stored, so bounds checks are O(1) no matter how big the map is.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+impl<T> Grid<T> {
+    /// Builds a `width` x `height` grid with every cell set to `fill`. Panics if there are
+    /// more cells than fit in a `usize`: use [`Grid::try_new`] for sizes from the input or
+    /// the config.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::try_new(width, height, fill).unwrap_or_else(|error| panic!("{}", error))
+    }
+    /// Like [`Grid::new`], but fails instead when there are too many cells to count.
+    pub fn try_new(width: usize, height: usize, fill: T) -> Result<Self, GridError<()>>
+    where
+        T: Clone,
+    {
+        Ok(Grid {
+            width,
+            height,
+            cells: vec![fill; cell_count(width, height)?],
+        })
+    }
+    /// Builds a grid from row-major cells, failing if they don't fill `width` x `height`.
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Result<Self, GridError<()>> {
+        let expected = cell_count(width, height)?;
+        if cells.len() != expected {
+            return Err(GridError::Size {
+                expected,
+                found: cells.len(),
+            });
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    /// Number of cells in the grid.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+    pub fn in_bounds(&self, (x, y): Coord) -> bool {
+        x < self.width && y < self.height
+    }
+    /// Like [`Grid::in_bounds`] but for signed coordinates, which may step off the top or left.
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+    /// The cell under `point`, `None` if it's off the grid.
+    pub fn at(&self, point: Point) -> Option<&T> {
+        self.get(point.coord()?)
+    }
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.index_of(coord).map(|index| &self.cells[index])
+    }
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.index_of(coord).map(|index| &mut self.cells[index])
+    }
+    /// Replaces the cell at `coord`, returning the old value or `None` when out of bounds.
+    pub fn set(&mut self, coord: Coord, value: T) -> Option<T> {
+        self.get_mut(coord)
+            .map(|cell| std::mem::replace(cell, value))
+    }
+    /// Moves `(dx, dy)` away from `coord`, returning `None` if that leaves the grid.
+    pub fn offset(&self, (x, y): Coord, (dx, dy): (isize, isize)) -> Option<Coord> {
+        let new_coord = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.in_bounds(new_coord).then_some(new_coord)
+    }
+    /// One step from `coord` towards `direction`, `None` if that leaves the grid.
+    pub fn step(&self, coord: Coord, direction: impl Into<Dir8>) -> Option<Coord> {
+        let (dx, dy) = direction.into().delta();
+        self.offset(coord, (dx as isize, dy as isize))
+    }
+    /// In-bounds orthogonal neighbours of `coord`, clockwise from up.
+    pub fn neighbours4(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Dir4::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(coord, direction))
+    }
+    /// In-bounds orthogonal and diagonal neighbours of `coord`, clockwise from up.
+    pub fn neighbours8(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Dir8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(coord, direction))
+    }
+    /// Every coordinate in row-major order.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |index| (index % width, index / width))
+    }
+    /// Every cell with its coordinate, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Coord, &mut T)> {
+        self.coords().zip(self.cells.iter_mut())
+    }
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on zero, but a zero width grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        (x < self.width).then(|| self.cells.iter().skip(x).step_by(self.width))
+    }
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.cells.iter().skip(x).step_by(self.width))
+    }
+    /// First coordinate, in row-major order, whose cell matches `predicate`.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Coord> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(coord, _)| coord)
+    }
+    /// Every coordinate whose cell matches `predicate`.
+    pub fn find_all<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Coord> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(coord, _)| coord)
+    }
+    /// First coordinate holding `value`.
+    pub fn position(&self, value: &T) -> Option<Coord>
+    where
+        T: PartialEq,
+    {
+        self.find(|cell| cell == value)
+    }
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+    fn index_of(&self, coord: Coord) -> Option<usize> {
+        self.in_bounds(coord)
+            .then(|| coord.1 * self.width + coord.0)
+    }
+}
+impl<T: TryFrom<char>> Grid<T> {
+    /// Parses one tile per character, one row per line.
+    ///
+    /// Leading and trailing blank lines and surrounding whitespace on each line are ignored,
+    /// so indented test constants parse the same as puzzle input.
+    pub fn parse(input: &str) -> Result<Self, GridError<T::Error>> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        // Count lines from the top of the untrimmed input, so errors point at the right one
+        let skipped = input[..input.len() - input.trim_start().len()]
+            .matches('\n')
+            .count();
+        for (line_number, line) in input.trim().lines().enumerate() {
+            let line_number = line_number + skipped;
+            let line = line.trim();
+            let mut line_width = 0;
+            for (column, character) in line.chars().enumerate() {
+                let tile = T::try_from(character).map_err(|error| GridError::Tile {
+                    line: line_number + 1,
+                    column: column + 1,
+                    character,
+                    error,
+                })?;
+                cells.push(tile);
+                line_width += 1;
+            }
+            match width {
+                None => width = Some(line_width),
+                Some(expected) if expected != line_width => {
+                    return Err(GridError::Ragged {
+                        line: line_number + 1,
+                        expected,
+                        found: line_width,
+                    })
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+}
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+    fn index(&self, coord: Coord) -> &T {
+        match self.index_of(coord) {
+            Some(index) => &self.cells[index],
+            None => panic!(
+                "{:?} is outside of a {}x{} grid",
+                coord, self.width, self.height
+            ),
+        }
+    }
+}
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        match self.index_of(coord) {
+            Some(index) => &mut self.cells[index],
+            None => panic!(
+                "{:?} is outside of a {}x{} grid",
+                coord, self.width, self.height
+            ),
+        }
+    }
+}
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+impl<T: Debug> Debug for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Grid {}x{}", self.width, self.height)?;
+        for row in self.rows() {
+            writeln!(f, "{:?}", row)?;
+        }
+        Ok(())
+    }
+}
+
+/// `width * height`, or an error if that overflows.
+fn cell_count(width: usize, height: usize) -> Result<usize, GridError<()>> {
+    width
+        .checked_mul(height)
+        .ok_or(GridError::TooBig { width, height })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError<E> {
+    /// A line had a different number of tiles than the first one.
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A character could not be turned into a tile.
+    Tile {
+        line: usize,
+        column: usize,
+        character: char,
+        error: E,
+    },
+    /// `from_vec` got the wrong number of cells.
+    Size { expected: usize, found: usize },
+    /// `width * height` cells are more than a `usize` can count.
+    TooBig { width: usize, height: usize },
+}
+impl<E: Debug> Display for GridError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {} has {} tiles, expected {} like the first line",
+                line, found, expected
+            ),
+            GridError::Tile {
+                line,
+                column,
+                character,
+                error,
+            } => write!(
+                f,
+                "invalid tile {:?} at {}:{}: {:?}",
+                character, line, column, error
+            ),
+            GridError::Size { expected, found } => {
+                write!(f, "expected {} cells, found {}", expected, found)
+            }
+            GridError::TooBig { width, height } => {
+                write!(f, "a {} by {} grid has too many cells", width, height)
+            }
+        }
+    }
+}
+impl<E: Debug> std::error::Error for GridError<E> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_GRID: &str = r#"
+    #..#
+    .#..
+    ..#.
+    "#;
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Tile {
+        Wall,
+        Open,
+    }
+    impl TryFrom<char> for Tile {
+        type Error = &'static str;
+        fn try_from(value: char) -> Result<Self, Self::Error> {
+            match value {
+                '#' => Ok(Tile::Wall),
+                '.' => Ok(Tile::Open),
+                _ => Err("Invalid tile"),
+            }
+        }
+    }
+    impl Display for Tile {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Tile::Wall => write!(f, "#"),
+                Tile::Open => write!(f, "."),
+            }
+        }
+    }
+    #[test]
+    fn parse_grid() {
+        let grid = Grid::<Tile>::parse(TEST_GRID).unwrap();
+        assert_eq!(grid.width(), 4);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid[(0, 0)], Tile::Wall);
+        assert_eq!(grid.get((2, 2)), Some(&Tile::Wall));
+        assert_eq!(grid.get((4, 0)), None);
+        assert_eq!(grid.to_string(), "#..#\n.#..\n..#.\n");
+    }
+    #[test]
+    fn parse_errors() {
+        let error = Grid::<Tile>::parse("#.\n#x").unwrap_err();
+        assert_eq!(
+            error,
+            GridError::Tile {
+                line: 2,
+                column: 2,
+                character: 'x',
+                error: "Invalid tile"
+            }
+        );
+        let error = Grid::<Tile>::parse("#.\n#").unwrap_err();
+        assert_eq!(
+            error,
+            GridError::Ragged {
+                line: 2,
+                expected: 2,
+                found: 1
+            }
+        );
+    }
+    #[test]
+    fn rejects_grids_too_big_to_count() {
+        assert_eq!(
+            Grid::try_new(usize::MAX, 2, 0),
+            Err(GridError::TooBig {
+                width: usize::MAX,
+                height: 2
+            })
+        );
+        assert!(Grid::from_vec(2, usize::MAX, vec![0]).is_err());
+        assert_eq!(Grid::try_new(3, 2, 0).unwrap().len(), 6);
+    }
+    #[test]
+    fn neighbours() {
+        let grid = Grid::<Tile>::parse(TEST_GRID).unwrap();
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8((3, 2)).count(), 3);
+        assert_eq!(grid.step((0, 0), Dir4::Up), None);
+        assert_eq!(grid.step((0, 0), Dir8::DownRight), Some((1, 1)));
+        assert_eq!(grid.at(Point::new(-1, 0)), None);
+        assert_eq!(grid.at(Point::new(0, 0)), Some(&Tile::Wall));
+    }
+    #[test]
+    fn rows_and_columns() {
+        let grid = Grid::<Tile>::parse(TEST_GRID).unwrap();
+        assert_eq!(grid.rows().count(), 3);
+        assert_eq!(
+            grid.row(1).unwrap(),
+            &[Tile::Open, Tile::Wall, Tile::Open, Tile::Open]
+        );
+        let column = grid.column(3).unwrap().copied().collect::<Vec<_>>();
+        assert_eq!(column, vec![Tile::Wall, Tile::Open, Tile::Open]);
+        assert_eq!(grid.columns().count(), 4);
+        assert!(grid.column(4).is_none());
+    }
+    #[test]
+    fn find_tiles() {
+        let mut grid = Grid::<Tile>::parse(TEST_GRID).unwrap();
+        assert_eq!(grid.position(&Tile::Wall), Some((0, 0)));
+        assert_eq!(grid.find_all(|tile| *tile == Tile::Wall).count(), 4);
+        assert_eq!(grid.set((0, 0), Tile::Open), Some(Tile::Wall));
+        assert_eq!(grid.position(&Tile::Wall), Some((3, 0)));
+        assert_eq!(grid.set((9, 9), Tile::Open), None);
+    }
+}
@@ -0,0 +1,235 @@
+use std::{
+    fmt::{Display, Formatter},
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use crate::solution::DayId;
+
+/// Directory to look in for `<year>/<day>.txt` inputs before falling back to each day's own
+/// folder.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+/// Overrides where downloaded inputs are cached, see [`InputCache`].
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+/// Where a day's puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// A file given on the command line.
+    File(PathBuf),
+    Stdin,
+    /// Whatever input is on disk for the day, see [`day_candidates`].
+    Day(DayId),
+}
+impl InputSource {
+    /// Reads `--input <path>` style arguments: `-` means stdin, no path means the day's default.
+    pub fn from_arg(path: Option<&Path>, day: DayId) -> InputSource {
+        match path {
+            Some(path) if path == Path::new("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(path.to_path_buf()),
+            None => InputSource::Day(day),
+        }
+    }
+    pub fn load(&self) -> Result<String, InputError> {
+        match self {
+            InputSource::File(path) => read(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::Stdin)?;
+                Ok(input)
+            }
+            InputSource::Day(day) => {
+                let input_dir = std::env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+                let tried = day_candidates(*day, input_dir.as_deref(), &InputCache::from_env())?;
+                match tried.iter().find(|path| path.is_file()) {
+                    Some(path) => read(path),
+                    None => Err(InputError::Missing { day: *day, tried }),
+                }
+            }
+        }
+    }
+}
+
+/// Places a day's input may live, in the order they're tried: `<input_dir>/<year>/<day>.txt`,
+/// then the download cache, then `days/<year>/<day name>/input.txt` in the workspace.
+pub fn day_candidates(
+    day: DayId,
+    input_dir: Option<&Path>,
+    cache: &InputCache,
+) -> Result<Vec<PathBuf>, InputError> {
+    let dir = day.dir().ok_or(InputError::UnknownDay(day))?;
+    let mut candidates = Vec::new();
+    if let Some(input_dir) = input_dir {
+        candidates.push(InputCache::new(input_dir).path(day));
+    }
+    candidates.push(cache.path(day));
+    candidates.push(workspace_dir().join(dir).join("input.txt"));
+    Ok(candidates)
+}
+
+/// Downloaded inputs, stored as `<dir>/<year>/<day>.txt` so each one is only fetched once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputCache {
+    dir: PathBuf,
+}
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>) -> InputCache {
+        InputCache { dir: dir.into() }
+    }
+    /// The cache in `$AOC_CACHE_DIR`, or `.cache/inputs` in the workspace.
+    pub fn from_env() -> InputCache {
+        match std::env::var_os(CACHE_DIR_VAR) {
+            Some(dir) => InputCache::new(dir),
+            None => InputCache::new(workspace_dir().join(".cache").join("inputs")),
+        }
+    }
+    pub fn path(&self, day: DayId) -> PathBuf {
+        self.dir
+            .join(day.year.to_string())
+            .join(format!("{:02}.txt", day.day))
+    }
+    pub fn get(&self, day: DayId) -> Option<String> {
+        std::fs::read_to_string(self.path(day)).ok()
+    }
+    pub fn store(&self, day: DayId, input: &str) -> Result<PathBuf, InputError> {
+        let path = self.path(day);
+        let write = |path: &Path| {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, input)
+        };
+        write(&path).map_err(|source| InputError::Write {
+            path: path.clone(),
+            source,
+        })?;
+        Ok(path)
+    }
+}
+
+/// Root of the workspace `elves` was built in.
+pub fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default()
+}
+
+fn read(path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path).map_err(|source| InputError::Read {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// There is no day with this number.
+    UnknownDay(DayId),
+    /// None of the default places had an input for the day.
+    Missing {
+        day: DayId,
+        tried: Vec<PathBuf>,
+    },
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    Write {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    Stdin(std::io::Error),
+}
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::UnknownDay(day) => write!(f, "there is no day {}", day),
+            InputError::Missing { day, tried } => {
+                write!(f, "no input for {}, tried", day)?;
+                for path in tried {
+                    write!(f, " {}", path.display())?;
+                }
+                Ok(())
+            }
+            InputError::Read { path, source } => {
+                write!(f, "could not read {}: {}", path.display(), source)
+            }
+            InputError::Write { path, source } => {
+                write!(f, "could not write {}: {}", path.display(), source)
+            }
+            InputError::Stdin(source) => write!(f, "could not read stdin: {}", source),
+        }
+    }
+}
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Read { source, .. }
+            | InputError::Write { source, .. }
+            | InputError::Stdin(source) => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sources_from_arguments() {
+        let day = DayId::new(2024, 3);
+        assert_eq!(InputSource::from_arg(None, day), InputSource::Day(day));
+        assert_eq!(
+            InputSource::from_arg(Some(Path::new("-")), day),
+            InputSource::Stdin
+        );
+        assert_eq!(
+            InputSource::from_arg(Some(Path::new("example.txt")), day),
+            InputSource::File(PathBuf::from("example.txt"))
+        );
+    }
+
+    #[test]
+    fn day_input_locations() {
+        let cache = InputCache::new("/cache");
+        let day = DayId::new(2024, 7);
+        let candidates = day_candidates(day, Some(Path::new("/inputs")), &cache).unwrap();
+        assert_eq!(candidates[0], Path::new("/inputs/2024/07.txt"));
+        assert_eq!(candidates[1], Path::new("/cache/2024/07.txt"));
+        assert!(candidates[2].ends_with("days/2024/seven/input.txt"));
+        assert_eq!(day_candidates(day, None, &cache).unwrap().len(), 2);
+        let next_year = DayId::new(2025, 7);
+        assert_eq!(
+            day_candidates(next_year, None, &cache).unwrap()[0],
+            Path::new("/cache/2025/07.txt")
+        );
+        assert!(matches!(
+            day_candidates(DayId::new(2024, 26), None, &cache),
+            Err(InputError::UnknownDay(_))
+        ));
+    }
+
+    #[test]
+    fn cache_round_trip() {
+        let dir = std::env::temp_dir().join(format!("elves-cache-{}", std::process::id()));
+        let cache = InputCache::new(&dir);
+        let day = DayId::new(2024, 3);
+        assert_eq!(cache.get(day), None);
+        let path = cache.store(day, "mul(2,4)").unwrap();
+        assert_eq!(path, dir.join("2024").join("03.txt"));
+        assert_eq!(cache.get(day).as_deref(), Some("mul(2,4)"));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn missing_file_is_an_error() {
+        let missing = InputSource::File(PathBuf::from("/no/such/input.txt"));
+        let error = missing.load().unwrap_err();
+        assert!(matches!(error, InputError::Read { .. }));
+        assert!(error.to_string().contains("/no/such/input.txt"));
+    }
+}
@@ -1,14 +1,28 @@
-pub fn add(left: u64, right: u64) -> u64 {
-    left + right
-}
+pub mod animation;
+pub mod config;
+pub mod differential;
+pub mod error;
+pub mod examples;
+pub mod grid;
+pub mod input;
+pub mod log;
+pub mod memo;
+pub mod parallel;
+pub mod parser;
+pub mod point;
+pub mod profile;
+pub mod random;
+pub mod render;
+pub mod search;
+pub mod snapshot;
+pub mod solution;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
-}
+pub use animation::{Animation, RenderFrame};
+pub use error::{check_lines, parse_lines, parse_number, Error, Location, Result};
+pub use grid::{Coord, Grid, GridError};
+pub use input::{InputCache, InputError, InputSource};
+pub use memo::{Memo, MemoStats};
+pub use point::{Dir4, Dir8, ParseDirError, Point};
+pub use random::Rng;
+pub use render::{Render, RenderImage, Rgb};
+pub use solution::{day_name, DayId, Part, Report, Runnable, Solution, Unsolved};
@@ -0,0 +1,79 @@
+//! Diagnostics from inside a day, kept off stdout so the answers stay easy to read and script.
+//!
+//! Days log with [`debug!`](crate::debug) for the odd note and [`trace!`](crate::trace) for
+//! something every step. Both go to stderr, and only once the verbosity is turned up, e.g. with
+//! the runner's `-v` and `-vv`.
+
+use std::{
+    fmt::{Arguments, Display},
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+/// Nothing is logged until [`set_verbosity`] is called.
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+/// How chatty a log line is, from least to most.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// Shown from verbosity 1.
+    Debug = 1,
+    /// Shown from verbosity 2, for lines written every step.
+    Trace = 2,
+}
+impl Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Level::Debug => write!(f, "debug"),
+            Level::Trace => write!(f, "trace"),
+        }
+    }
+}
+
+/// Logs every level up to `verbosity` from now on: 0 for nothing, 1 for debug, 2 for trace.
+pub fn set_verbosity(verbosity: u8) {
+    VERBOSITY.store(verbosity, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    VERBOSITY.load(Ordering::Relaxed) >= level as u8
+}
+
+/// Writes a line to stderr if `level` is enabled. Use [`debug!`](crate::debug) or
+/// [`trace!`](crate::trace) instead.
+pub fn write(level: Level, message: Arguments) {
+    if enabled(level) {
+        eprintln!("[{}] {}", level, message);
+    }
+}
+
+/// Logs a line at [`Level::Debug`], formatted like `println!`.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        $crate::log::write($crate::log::Level::Debug, format_args!($($arg)*))
+    };
+}
+
+/// Logs a line at [`Level::Trace`], formatted like `println!`.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        $crate::log::write($crate::log::Level::Trace, format_args!($($arg)*))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn logs_up_to_the_verbosity() {
+        assert!(!enabled(Level::Debug));
+        set_verbosity(1);
+        assert!(enabled(Level::Debug));
+        assert!(!enabled(Level::Trace));
+        set_verbosity(2);
+        assert!(enabled(Level::Trace));
+        set_verbosity(0);
+    }
+}
@@ -0,0 +1,192 @@
+//! Remembering the answers of recursive functions, so each set of arguments is worked out once.
+//!
+//! A recursive function takes a `&mut Memo` and wraps its body in
+//! [`Memo::get_or_insert_with`], which hands the memo back for the recursive calls:
+//!
+//! ```
+//! use elves::Memo;
+//!
+//! fn fibonacci(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+//!     if n < 2 {
+//!         return n;
+//!     }
+//!     memo.get_or_insert_with(n, |memo| fibonacci(n - 1, memo) + fibonacci(n - 2, memo))
+//! }
+//! let mut memo = Memo::new();
+//! assert_eq!(fibonacci(90, &mut memo), 2880067194370816120);
+//! assert_eq!(memo.stats().misses, 89);
+//! ```
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Display,
+    hash::Hash,
+};
+
+use crate::profile;
+
+/// Answers keyed on the arguments that produced them, with counts of how often they were
+/// reused. A bounded memo forgets its oldest answers once it's full.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    values: HashMap<K, V>,
+    /// Keys in the order they were added, for a bounded memo to forget the oldest.
+    order: VecDeque<K>,
+    capacity: Option<usize>,
+    stats: MemoStats,
+}
+/// How a [`Memo`] has been used so far.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoStats {
+    /// Lookups answered from the memo.
+    pub hits: u64,
+    /// Lookups that had to be worked out.
+    pub misses: u64,
+    /// Answers forgotten to stay within the capacity.
+    pub evictions: u64,
+}
+impl MemoStats {
+    /// The share of lookups answered from the memo, between 0 and 1.
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            lookups => self.hits as f64 / lookups as f64,
+        }
+    }
+}
+impl Display for MemoStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate), {} evicted",
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.0,
+            self.evictions
+        )
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo::new()
+    }
+}
+impl<K: Clone + Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Memo {
+            values: HashMap::new(),
+            order: VecDeque::new(),
+            capacity: None,
+            stats: MemoStats::default(),
+        }
+    }
+    /// A memo that keeps at most `capacity` answers, forgetting the oldest first.
+    pub fn bounded(capacity: usize) -> Self {
+        Memo {
+            capacity: Some(capacity.max(1)),
+            ..Memo::new()
+        }
+    }
+    /// The answer for `key`, counted as a hit or a miss.
+    pub fn get(&mut self, key: &K) -> Option<V> {
+        let value = self.values.get(key).cloned();
+        match value {
+            Some(_) => {
+                self.stats.hits += 1;
+                profile::count("memo hits", 1);
+            }
+            None => {
+                self.stats.misses += 1;
+                profile::count("memo misses", 1);
+            }
+        }
+        value
+    }
+    /// Remembers `value` as the answer for `key`.
+    pub fn insert(&mut self, key: K, value: V) {
+        if self.values.insert(key.clone(), value).is_some() {
+            return;
+        }
+        if let Some(capacity) = self.capacity {
+            self.order.push_back(key);
+            while self.values.len() > capacity {
+                let Some(oldest) = self.order.pop_front() else {
+                    break;
+                };
+                self.values.remove(&oldest);
+                self.stats.evictions += 1;
+            }
+        }
+    }
+    /// The answer for `key`, working it out with `f` the first time. `f` gets the memo back
+    /// for any recursive calls it makes.
+    pub fn get_or_insert_with(&mut self, key: K, f: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.get(&key) {
+            return value;
+        }
+        let value = f(self);
+        self.insert(key, value.clone());
+        value
+    }
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+    pub fn stats(&self) -> MemoStats {
+        self.stats
+    }
+    /// Forgets every answer and resets the stats.
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.order.clear();
+        self.stats = MemoStats::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Ways to climb `steps` stairs one or two at a time.
+    fn climbs(steps: u64, memo: &mut Memo<u64, u64>) -> u64 {
+        if steps < 2 {
+            return 1;
+        }
+        memo.get_or_insert_with(steps, |memo| {
+            climbs(steps - 1, memo) + climbs(steps - 2, memo)
+        })
+    }
+
+    #[test]
+    fn works_each_key_out_once() {
+        let mut memo = Memo::new();
+        assert_eq!(climbs(40, &mut memo), 165580141);
+        assert_eq!(memo.len(), 39);
+        assert_eq!(
+            memo.stats(),
+            MemoStats {
+                hits: 37,
+                misses: 39,
+                evictions: 0
+            }
+        );
+        assert_eq!(climbs(40, &mut memo), 165580141);
+        assert_eq!(memo.stats().hits, 38);
+    }
+
+    #[test]
+    fn forgets_the_oldest_when_bounded() {
+        let mut memo = Memo::bounded(2);
+        for key in 0..4 {
+            memo.insert(key, key * 10);
+        }
+        assert_eq!(memo.len(), 2);
+        assert_eq!(memo.stats().evictions, 2);
+        assert_eq!(memo.get(&1), None);
+        assert_eq!(memo.get(&3), Some(30));
+        assert_eq!(climbs(40, &mut Memo::bounded(3)), 165580141);
+    }
+}
@@ -0,0 +1,120 @@
+//! Data-parallel map and reduce over slices, for days that check many independent cases.
+//!
+//! The items are cut into chunks that a fixed pool of scoped threads take from as they finish
+//! their last one, so a slow chunk doesn't hold the rest up. Results always come back in the
+//! items' order, however many threads ran them. The thread count is shared by the whole
+//! process: set it once with [`set_jobs`], e.g. from the runner's `--jobs`. The threads see the
+//! same [`config::scoped`] values as the one that started them.
+
+use std::{
+    num::NonZeroUsize,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::config;
+
+/// 0 until [`set_jobs`] is called, meaning one thread per core.
+static JOBS: AtomicUsize = AtomicUsize::new(0);
+
+/// How many chunks each thread gets on average, so the fast ones can take more.
+const CHUNKS_PER_JOB: usize = 8;
+
+/// Runs parallel work on `jobs` threads from now on. 0 goes back to one per core.
+pub fn set_jobs(jobs: usize) {
+    JOBS.store(jobs, Ordering::Relaxed);
+}
+
+/// How many threads parallel work runs on.
+pub fn jobs() -> usize {
+    match JOBS.load(Ordering::Relaxed) {
+        0 => std::thread::available_parallelism().map_or(1, NonZeroUsize::get),
+        jobs => jobs,
+    }
+}
+
+/// `f` of every item, in the items' order.
+pub fn map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    chunked(items, |chunk| chunk.iter().map(&f).collect::<Vec<R>>())
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// Maps every item and combines the results with `reduce`, starting from `identity`.
+/// The results are combined in the items' order, so `reduce` only has to be associative.
+pub fn map_reduce<T: Sync, R: Send>(
+    items: &[T],
+    map: impl Fn(&T) -> R + Sync,
+    identity: impl Fn() -> R + Sync,
+    reduce: impl Fn(R, R) -> R + Sync,
+) -> R {
+    chunked(items, |chunk| {
+        chunk
+            .iter()
+            .fold(identity(), |total, item| reduce(total, map(item)))
+    })
+    .into_iter()
+    .fold(identity(), &reduce)
+}
+
+/// `work` run on every chunk of `items`, in order.
+fn chunked<T: Sync, R: Send>(items: &[T], work: impl Fn(&[T]) -> R + Sync) -> Vec<R> {
+    let jobs = jobs().min(items.len());
+    if jobs <= 1 {
+        return vec![work(items)];
+    }
+    let chunk_size = items.len().div_ceil(jobs * CHUNKS_PER_JOB);
+    let chunks: Vec<&[T]> = items.chunks(chunk_size).collect();
+    let next = AtomicUsize::new(0);
+    let scope = config::current_scope();
+    let mut done: Vec<(usize, R)> = std::thread::scope(|threads| {
+        let workers: Vec<_> = (0..jobs)
+            .map(|_| {
+                threads.spawn(|| {
+                    config::with_scope(scope.clone(), || {
+                        let mut done = Vec::new();
+                        loop {
+                            let index = next.fetch_add(1, Ordering::Relaxed);
+                            let Some(chunk) = chunks.get(index) else {
+                                return done;
+                            };
+                            done.push((index, work(chunk)));
+                        }
+                    })
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| match worker.join() {
+                Ok(done) => done,
+                Err(panic) => std::panic::resume_unwind(panic),
+            })
+            .collect()
+    });
+    done.sort_unstable_by_key(|(index, _)| *index);
+    done.into_iter().map(|(_, result)| result).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_the_items_order() {
+        let items: Vec<u64> = (0..1000).collect();
+        for jobs in [1, 3, 16] {
+            set_jobs(jobs);
+            assert_eq!(
+                map(&items, |n| n * 2),
+                items.iter().map(|n| n * 2).collect::<Vec<_>>()
+            );
+            // Concatenating isn't commutative, so any reordering would show.
+            let joined = map_reduce(&items[..20], |n| n.to_string(), String::new, |a, b| a + &b);
+            assert_eq!(joined, "012345678910111213141516171819");
+        }
+        assert!(map(&[] as &[u64], |n| *n).is_empty());
+        assert_eq!(map_reduce(&[] as &[u64], |n| *n, || 0, |a, b| a + b), 0);
+        set_jobs(0);
+    }
+}
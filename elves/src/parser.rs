@@ -0,0 +1,496 @@
+//! Small parser combinators for puzzle inputs.
+//!
+//! A parser reads from the front of its input and hands back what it read along with
+//! whatever is left. When it gives up it says where, so [`parse_all`] can turn that into
+//! an [`Error`] pointing at the line and column.
+//!
+//! ```
+//! use elves::parser::{int, key_value, parse_all, separated_pair, tag};
+//!
+//! let xy = || separated_pair(int::<i32>(), tag(","), int());
+//! let robot = separated_pair(key_value("p", "=", xy()), tag(" "), key_value("v", "=", xy()));
+//! assert_eq!(parse_all("p=0,4 v=3,-3", robot).unwrap(), ((0, 4), (3, -3)));
+//! ```
+
+use std::{any::type_name, fmt::Display, str::FromStr};
+
+use crate::{
+    error::{Error, Result},
+    grid::{Grid, GridError},
+};
+
+/// What a parser read, and the input left after it.
+pub type PResult<'a, T> = std::result::Result<(T, &'a str), Failure<'a>>;
+
+/// Where a parser gave up, and why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure<'a> {
+    pub message: String,
+    /// The input from the point the parser gave up.
+    pub rest: &'a str,
+}
+impl<'a> Failure<'a> {
+    pub fn new(message: impl Into<String>, rest: &'a str) -> Self {
+        Failure {
+            message: message.into(),
+            rest,
+        }
+    }
+    /// The parser wanted `what` at the start of `rest`.
+    pub fn expected(what: impl Display, rest: &'a str) -> Self {
+        Failure::new(format!("expected {}", what), rest)
+    }
+    /// Turns the failure into an [`Error`] located within `input`, the string the
+    /// outermost parser was given.
+    pub fn into_error(self, input: &str) -> Error {
+        let token_end = self
+            .rest
+            .char_indices()
+            .skip(1)
+            .find(|(_, c)| c.is_whitespace())
+            .map_or(self.rest.len(), |(index, _)| index);
+        let text = &self.rest[..token_end];
+        let offset = (self.rest.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        match input.get(..offset) {
+            Some(before) => {
+                let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+                Error::at(
+                    before.matches('\n').count() + 1,
+                    before[line_start..].chars().count() + 1,
+                    text,
+                    self.message,
+                )
+            }
+            None => Error::parse(text, self.message),
+        }
+    }
+}
+
+/// Anything that can read a `T` off the front of a string. Implemented for every
+/// `Fn(&str) -> PResult<T>`, so a plain function works as a parser too.
+pub trait Parser<'a, T> {
+    fn parse(&self, input: &'a str) -> PResult<'a, T>;
+}
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: Fn(&'a str) -> PResult<'a, T>,
+{
+    fn parse(&self, input: &'a str) -> PResult<'a, T> {
+        self(input)
+    }
+}
+
+/// Runs `parser` over the whole of `input`, which may only have whitespace left after it.
+pub fn parse_all<'a, T>(input: &'a str, parser: impl Parser<'a, T>) -> Result<T> {
+    let (value, rest) = parser
+        .parse(input)
+        .map_err(|failure| failure.into_error(input))?;
+    let rest = rest.trim_start();
+    if rest.is_empty() {
+        Ok(value)
+    } else {
+        Err(Failure::expected("the end of the input", rest).into_error(input))
+    }
+}
+
+/// Exactly `literal`, returned as is.
+pub fn tag<'a>(literal: &'static str) -> impl Parser<'a, &'a str> {
+    move |input: &'a str| match input.strip_prefix(literal) {
+        Some(rest) => Ok((&input[..literal.len()], rest)),
+        None => Err(Failure::expected(format_args!("{:?}", literal), input)),
+    }
+}
+
+/// A whole number with an optional leading `-`, read as any `T` that parses from one.
+pub fn int<'a, T: FromStr>() -> impl Parser<'a, T> {
+    move |input: &'a str| {
+        let sign = usize::from(input.starts_with('-'));
+        let digits = input[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(input.len() - sign);
+        if digits == 0 {
+            return Err(Failure::expected("an integer", input));
+        }
+        let (number, rest) = input.split_at(sign + digits);
+        match number.parse() {
+            Ok(number) => Ok((number, rest)),
+            Err(_) => Err(Failure::expected(
+                format_args!("an integer that fits in {}", type_name::<T>()),
+                input,
+            )),
+        }
+    }
+}
+
+/// One or more ASCII letters and digits.
+pub fn word<'a>() -> impl Parser<'a, &'a str> {
+    move |input: &'a str| {
+        let end = input
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(input.len());
+        match end {
+            0 => Err(Failure::expected("a word", input)),
+            end => Ok(input.split_at(end)),
+        }
+    }
+}
+
+/// Any number of spaces and tabs, but not newlines.
+pub fn spaces<'a>() -> impl Parser<'a, ()> {
+    move |input: &'a str| Ok(((), input.trim_start_matches([' ', '\t'])))
+}
+
+/// Changes what `parser` read with `f`.
+pub fn map<'a, T, U>(parser: impl Parser<'a, T>, f: impl Fn(T) -> U) -> impl Parser<'a, U> {
+    move |input: &'a str| {
+        let (value, rest) = parser.parse(input)?;
+        Ok((f(value), rest))
+    }
+}
+
+/// Like [`map`], but `f` can reject what was read. The failure points at where `parser`
+/// started.
+pub fn try_map<'a, T, U, E: Display>(
+    parser: impl Parser<'a, T>,
+    f: impl Fn(T) -> std::result::Result<U, E>,
+) -> impl Parser<'a, U> {
+    move |input: &'a str| {
+        let (value, rest) = parser.parse(input)?;
+        match f(value) {
+            Ok(value) => Ok((value, rest)),
+            Err(error) => Err(Failure::new(error.to_string(), input)),
+        }
+    }
+}
+
+/// `first`, then `second`.
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |input: &'a str| {
+        let (a, rest) = first.parse(input)?;
+        let (b, rest) = second.parse(rest)?;
+        Ok(((a, b), rest))
+    }
+}
+
+/// `first` and then `second`, keeping only what `second` read.
+pub fn preceded<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, B> {
+    map(pair(first, second), |(_, b)| b)
+}
+
+/// `first` and then `second`, keeping only what `first` read.
+pub fn terminated<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, A> {
+    map(pair(first, second), |(a, _)| a)
+}
+
+/// `first`, `separator` and `second`, keeping both sides.
+pub fn separated_pair<'a, A, S, B>(
+    first: impl Parser<'a, A>,
+    separator: impl Parser<'a, S>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    pair(first, preceded(separator, second))
+}
+
+/// The literal `key`, then `separator`, then a value, e.g. `p=0,4` or `Prize: X=8400`.
+pub fn key_value<'a, T>(
+    key: &'static str,
+    separator: &'static str,
+    value: impl Parser<'a, T>,
+) -> impl Parser<'a, T> {
+    preceded(pair(tag(key), tag(separator)), value)
+}
+
+/// One or more `item`s with `separator` between them.
+pub fn separated<'a, T, S>(
+    item: impl Parser<'a, T>,
+    separator: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    move |input: &'a str| {
+        let (first, mut rest) = item.parse(input)?;
+        let mut items = vec![first];
+        while let Ok((_, after_separator)) = separator.parse(rest) {
+            let (next, after_item) = item.parse(after_separator)?;
+            items.push(next);
+            rest = after_item;
+        }
+        Ok((items, rest))
+    }
+}
+
+/// `first` if it matches, otherwise `second`. When both fail the one that got further
+/// wins, or both are named if they gave up at the same place.
+pub fn either<'a, T>(first: impl Parser<'a, T>, second: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |input: &'a str| {
+        let first_failure = match first.parse(input) {
+            Ok(parsed) => return Ok(parsed),
+            Err(failure) => failure,
+        };
+        second.parse(input).map_err(|second_failure| {
+            match first_failure.rest.len().cmp(&second_failure.rest.len()) {
+                std::cmp::Ordering::Less => first_failure,
+                std::cmp::Ordering::Greater => second_failure,
+                std::cmp::Ordering::Equal => match (
+                    first_failure.message.starts_with("expected "),
+                    second_failure.message.strip_prefix("expected "),
+                ) {
+                    (true, Some(second)) => Failure::new(
+                        format!("{} or {}", first_failure.message, second),
+                        first_failure.rest,
+                    ),
+                    // Something was read and then rejected, that says more than a mismatch
+                    (true, None) => second_failure,
+                    (false, _) => first_failure,
+                },
+            }
+        })
+    }
+}
+
+/// Splits off the next block of non-blank lines, skipping any blank lines before it.
+fn next_block(input: &str) -> (&str, &str) {
+    let (mut start, mut end) = (0, 0);
+    for line in input.split_inclusive('\n') {
+        if !line.trim().is_empty() {
+            end += line.len();
+        } else if end > start {
+            break;
+        } else {
+            start += line.len();
+            end = start;
+        }
+    }
+    (&input[start..end], &input[end..])
+}
+
+/// `item` over the next block of non-blank lines, which it has to read all of.
+pub fn section<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |input: &'a str| {
+        let (block, rest) = next_block(input);
+        if block.is_empty() {
+            return Err(Failure::expected("another section", rest));
+        }
+        let (value, left) = item.parse(block)?;
+        let left = left.trim_start();
+        if !left.is_empty() {
+            return Err(Failure::expected("the end of the section", left));
+        }
+        Ok((value, rest))
+    }
+}
+
+/// [`section`]s separated by blank lines, up to the end of the input.
+pub fn sections<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    let item = section(item);
+    move |mut input: &'a str| {
+        let mut items = vec![];
+        while !input.trim().is_empty() {
+            let (value, rest) = item.parse(input)?;
+            items.push(value);
+            input = rest;
+        }
+        Ok((items, input))
+    }
+}
+
+/// One `item` per line, each read to its end, up to the next blank line.
+pub fn lines<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |input: &'a str| {
+        let (block, rest) = next_block(input);
+        let items = block
+            .lines()
+            .map(|line| {
+                let line = line.trim_start();
+                let (value, left) = item.parse(line)?;
+                let left = left.trim_start();
+                match left.is_empty() {
+                    true => Ok(value),
+                    false => Err(Failure::expected("the end of the line", left)),
+                }
+            })
+            .collect::<std::result::Result<_, _>>()?;
+        Ok((items, rest))
+    }
+}
+
+/// A [`Grid`] drawn over the next block of non-blank lines.
+pub fn grid<'a, T>() -> impl Parser<'a, Grid<T>>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    move |input: &'a str| {
+        let (block, rest) = next_block(input);
+        // Grid rows are trimmed, so columns count from the first non-blank character
+        let at = |line: usize, column: usize| {
+            let line = block.lines().nth(line - 1).unwrap_or_default().trim_start();
+            let offset = line
+                .char_indices()
+                .nth(column - 1)
+                .map_or(line.len(), |(offset, _)| offset);
+            &line[offset..]
+        };
+        match Grid::<T>::parse(block) {
+            Ok(grid) => Ok((grid, rest)),
+            Err(GridError::Tile {
+                line,
+                column,
+                error,
+                ..
+            }) => Err(Failure::new(error.to_string(), at(line, column))),
+            Err(GridError::Ragged {
+                line,
+                expected,
+                found,
+            }) => Err(Failure::new(
+                format!("line has {} tiles, expected {}", found, expected),
+                at(line, expected.min(found) + 1),
+            )),
+            Err(GridError::Size { expected, found }) => Err(Failure::new(
+                format!("expected {} cells, found {}", expected, found),
+                block,
+            )),
+            Err(GridError::TooBig { width, height }) => Err(Failure::new(
+                format!("a {} by {} grid has too many cells", width, height),
+                block,
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Location;
+
+    #[test]
+    fn one_liners() {
+        let button = key_value(
+            "Button A",
+            ": ",
+            separated_pair(
+                key_value("X", "+", int::<u64>()),
+                tag(", "),
+                key_value("Y", "+", int()),
+            ),
+        );
+        assert_eq!(parse_all("Button A: X+94, Y+34", button).unwrap(), (94, 34));
+
+        let numbers = separated(int::<i64>(), pair(tag(","), spaces()));
+        assert_eq!(parse_all("1, -2,3\n", numbers).unwrap(), [1, -2, 3]);
+
+        let connection = separated_pair(word(), tag("-"), word());
+        assert_eq!(parse_all("kh-tc", connection).unwrap(), ("kh", "tc"));
+    }
+
+    #[test]
+    fn failures_point_at_the_problem() {
+        let error = parse_all(
+            "p=0,4 v=3,x3",
+            separated_pair(tag("p=0,4"), tag(" v=3,"), int::<i32>()),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.location(),
+            Some(Location {
+                line: 1,
+                column: 11
+            })
+        );
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 11: expected an integer, found \"x3\""
+        );
+
+        let error = parse_all("300", int::<u8>()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected an integer that fits in u8, found \"300\""
+        );
+
+        let error = parse_all(
+            "1|2|3",
+            separated_pair(int::<u32>(), tag("|"), int::<u32>()),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 4: expected the end of the input, found \"|3\""
+        );
+
+        let instruction = || {
+            either(
+                map(tag("Button"), |_| 0),
+                either(
+                    map(tag("Prize"), |_| 1),
+                    try_map(int::<u8>(), |n| if n < 2 { Ok(n) } else { Err("too big") }),
+                ),
+            )
+        };
+        assert_eq!(parse_all("Prize", instruction()).unwrap(), 1);
+        let error = parse_all("Claw", instruction()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected \"Button\" or \"Prize\" or an integer, found \"Claw\""
+        );
+        let error = parse_all("7", instruction()).unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 1: too big, found \"7\"");
+    }
+
+    #[test]
+    fn sections_lines_and_grids() {
+        let input = "\n1|2\n3|4\n\n  1,2,3\n4\n";
+        let rules = lines(separated_pair(int::<u32>(), tag("|"), int::<u32>()));
+        let updates = lines(separated(int::<u32>(), tag(",")));
+        let (rules, updates) = parse_all(input, pair(rules, updates)).unwrap();
+        assert_eq!(rules, [(1, 2), (3, 4)]);
+        assert_eq!(updates, [vec![1, 2, 3], vec![4]]);
+
+        let error = parse_all(
+            "1|2\n3|4|5\n",
+            lines(separated_pair(int::<u32>(), tag("|"), int::<u32>())),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: expected the end of the line, found \"|5\""
+        );
+
+        let blocks = sections(lines(int::<u32>()));
+        assert_eq!(
+            parse_all("1\n2\n\n\n3\n", blocks).unwrap(),
+            [vec![1, 2], vec![3]]
+        );
+
+        #[derive(Debug, PartialEq)]
+        struct Digit(u32);
+        impl TryFrom<char> for Digit {
+            type Error = &'static str;
+            fn try_from(c: char) -> std::result::Result<Self, Self::Error> {
+                c.to_digit(10).map(Digit).ok_or("not a digit")
+            }
+        }
+        let warehouse = || pair(grid::<Digit>(), section(word()));
+        let (map, moves) = parse_all("12\n34\n\nab", warehouse()).unwrap();
+        assert_eq!(map[(1, 1)], Digit(4));
+        assert_eq!(moves, "ab");
+        let error = parse_all("  12\n  3x\n\nab", warehouse()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: not a digit, found \"x\""
+        );
+        let error = parse_all("12\n34\n\nab cd", warehouse()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 4: expected the end of the section, found \"cd\""
+        );
+    }
+}
@@ -0,0 +1,293 @@
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use crate::grid::Coord;
+
+/// One of the four orthogonal directions, with `Up` pointing towards row zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+impl Dir4 {
+    /// Clockwise from up.
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    /// A quarter turn counter-clockwise.
+    pub fn rotate_left(self) -> Dir4 {
+        match self {
+            Dir4::Up => Dir4::Left,
+            Dir4::Left => Dir4::Down,
+            Dir4::Down => Dir4::Right,
+            Dir4::Right => Dir4::Up,
+        }
+    }
+    /// A quarter turn clockwise.
+    pub fn rotate_right(self) -> Dir4 {
+        match self {
+            Dir4::Up => Dir4::Right,
+            Dir4::Right => Dir4::Down,
+            Dir4::Down => Dir4::Left,
+            Dir4::Left => Dir4::Up,
+        }
+    }
+    pub fn reverse(self) -> Dir4 {
+        match self {
+            Dir4::Up => Dir4::Down,
+            Dir4::Down => Dir4::Up,
+            Dir4::Left => Dir4::Right,
+            Dir4::Right => Dir4::Left,
+        }
+    }
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Dir4::Left | Dir4::Right)
+    }
+    pub fn is_vertical(self) -> bool {
+        !self.is_horizontal()
+    }
+    /// How far one step moves along `(x, y)`, y growing downwards.
+    pub fn delta(self) -> (i64, i64) {
+        Dir8::from(self).delta()
+    }
+    /// The arrow this direction is drawn with in puzzle inputs.
+    pub fn arrow(self) -> char {
+        match self {
+            Dir4::Up => '^',
+            Dir4::Right => '>',
+            Dir4::Down => 'v',
+            Dir4::Left => '<',
+        }
+    }
+}
+/// Reads arrows, `^v<>`, as well as letters, `UDLR`.
+impl TryFrom<char> for Dir4 {
+    type Error = ParseDirError;
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' | 'U' => Ok(Dir4::Up),
+            '>' | 'R' => Ok(Dir4::Right),
+            'v' | 'D' => Ok(Dir4::Down),
+            '<' | 'L' => Ok(Dir4::Left),
+            _ => Err(ParseDirError(c)),
+        }
+    }
+}
+impl Display for Dir4 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+/// One of the eight compass directions, orthogonal and diagonal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+impl Dir8 {
+    /// Clockwise from up.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    /// An eighth of a turn counter-clockwise.
+    pub fn rotate_left(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 7) % 8]
+    }
+    /// An eighth of a turn clockwise.
+    pub fn rotate_right(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 1) % 8]
+    }
+    pub fn reverse(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 4) % 8]
+    }
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+    /// How far one step moves along `(x, y)`, y growing downwards.
+    pub fn delta(self) -> (i64, i64) {
+        match self {
+            Dir8::Up => (0, -1),
+            Dir8::UpRight => (1, -1),
+            Dir8::Right => (1, 0),
+            Dir8::DownRight => (1, 1),
+            Dir8::Down => (0, 1),
+            Dir8::DownLeft => (-1, 1),
+            Dir8::Left => (-1, 0),
+            Dir8::UpLeft => (-1, -1),
+        }
+    }
+}
+impl From<Dir4> for Dir8 {
+    fn from(direction: Dir4) -> Self {
+        match direction {
+            Dir4::Up => Dir8::Up,
+            Dir4::Right => Dir8::Right,
+            Dir4::Down => Dir8::Down,
+            Dir4::Left => Dir8::Left,
+        }
+    }
+}
+
+/// A character that isn't one of `^v<>` or `UDLR`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseDirError(pub char);
+impl Display for ParseDirError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} is not a direction", self.0)
+    }
+}
+impl std::error::Error for ParseDirError {}
+
+/// A position on an unbounded plane, y growing downwards like grid rows do.
+///
+/// Unlike [`Coord`] it can go negative, so stepping off the top or left edge never underflows.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T = i64> {
+    pub x: T,
+    pub y: T,
+}
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+}
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    /// One step towards `direction`.
+    pub fn step(self, direction: impl Into<Dir8>) -> Point {
+        self.step_by(direction, 1)
+    }
+    /// `distance` steps towards `direction`.
+    pub fn step_by(self, direction: impl Into<Dir8>, distance: i64) -> Point {
+        let (dx, dy) = direction.into().delta();
+        Point::new(self.x + dx * distance, self.y + dy * distance)
+    }
+    /// Orthogonal neighbours, clockwise from up.
+    pub fn neighbours4(self) -> impl Iterator<Item = Point> {
+        Dir4::ALL
+            .into_iter()
+            .map(move |direction| self.step(direction))
+    }
+    /// Orthogonal and diagonal neighbours, clockwise from up.
+    pub fn neighbours8(self) -> impl Iterator<Item = Point> {
+        Dir8::ALL
+            .into_iter()
+            .map(move |direction| self.step(direction))
+    }
+    pub fn manhattan(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+    /// The grid cell this point is on, `None` if it's left of or above the origin.
+    pub fn coord(self) -> Option<Coord> {
+        Some((self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+}
+impl From<Coord> for Point {
+    fn from((x, y): Coord) -> Self {
+        Point::new(x as i64, y as i64)
+    }
+}
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Point<T>;
+    fn add(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Point<T>;
+    fn sub(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Point<T>;
+    fn mul(self, factor: T) -> Point<T> {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Point<T>;
+    fn neg(self) -> Point<T> {
+        Point::new(-self.x, -self.y)
+    }
+}
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Point<T>) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Point<T>) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+/// Written as `x,y`, the way puzzle inputs list positions.
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotations() {
+        for direction in Dir4::ALL {
+            assert_eq!(direction.rotate_left().rotate_right(), direction);
+            assert_eq!(direction.rotate_right().rotate_right(), direction.reverse());
+            assert_eq!(Dir8::from(direction).reverse(), direction.reverse().into());
+        }
+        assert_eq!(Dir4::Up.rotate_right(), Dir4::Right);
+        assert_eq!(Dir8::Up.rotate_left(), Dir8::UpLeft);
+        assert_eq!(Dir8::UpLeft.rotate_right(), Dir8::Up);
+        assert!(Dir8::DownLeft.is_diagonal());
+        assert!(Dir4::Left.is_horizontal());
+    }
+
+    #[test]
+    fn parses_arrows_and_letters() {
+        let arrows: Vec<Dir4> = "^>v<".chars().map(|c| c.try_into().unwrap()).collect();
+        let letters: Vec<Dir4> = "URDL".chars().map(|c| c.try_into().unwrap()).collect();
+        assert_eq!(arrows, Dir4::ALL);
+        assert_eq!(letters, Dir4::ALL);
+        assert_eq!(Dir4::try_from('x'), Err(ParseDirError('x')));
+        assert_eq!(Dir4::Down.to_string(), "v");
+    }
+
+    #[test]
+    fn steps_and_distances() {
+        let point = Point::ORIGIN.step(Dir4::Left).step(Dir8::UpLeft);
+        assert_eq!(point, Point::new(-2, -1));
+        assert_eq!(point.coord(), None);
+        assert_eq!(point.manhattan(Point::new(3, 3)), 9);
+        assert_eq!(Point::new(2, 3).step_by(Dir4::Up, 3), Point::new(2, 0));
+        assert_eq!(Point::new(2, 0).coord(), Some((2, 0)));
+        assert_eq!(Point::new(4, 1) - Point::new(1, 2), Point::new(3, -1));
+        assert_eq!(Point::from((1, 2)) * 2, Point::new(2, 4));
+        assert_eq!(Point::new(1, 1).neighbours8().count(), 8);
+        assert_eq!(Point::new(6, 1).to_string(), "6,1");
+    }
+}
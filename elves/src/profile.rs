@@ -0,0 +1,391 @@
+//! Where a day spends its time and memory, for the runner's `aoc profile`.
+//!
+//! Code marks out a phase with [`span`], which lasts until the end of the scope, and counts
+//! events with [`count`]. The parse and each part are spans already, the
+//! [`search`](crate::search) functions are spans that count the nodes they expand, and a
+//! [`Memo`](crate::Memo) counts its hits and misses:
+//!
+//! ```
+//! use elves::profile;
+//!
+//! profile::start();
+//! {
+//!     let _span = profile::span("walk");
+//!     profile::count("steps", 3);
+//! }
+//! let profile = profile::stop();
+//! assert_eq!(profile.counter("steps"), 3);
+//! ```
+//!
+//! Nothing is recorded between [`stop`] and the next [`start`], so outside a profile a span or
+//! a count costs one atomic load. Each thread keeps its own counts, so counting from inside
+//! [`parallel`](crate::parallel) work doesn't wait on the other threads, and [`stop`] adds
+//! them up. Allocations are only counted in a binary that installs [`CountingAllocator`] as
+//! its global allocator.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::RefCell,
+    fmt::Display,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+    time::{Duration, Instant},
+};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+/// Goes up with every [`start`], so spans and counts left over from an earlier profile
+/// aren't mistaken for this one's.
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+static RECORDER: Mutex<Recorder> = Mutex::new(Recorder::new());
+/// Every thread's counters for the profile of the generation alongside them.
+static THREAD_COUNTERS: Mutex<Vec<(u64, Arc<Mutex<Counters>>)>> = Mutex::new(Vec::new());
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE_BYTES: AtomicU64 = AtomicU64::new(0);
+static PEAK_BYTES: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    /// The spans open on this thread, outermost first.
+    static OPEN: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+    /// This thread's entry in [`THREAD_COUNTERS`], only ever locked by [`stop`] besides.
+    static COUNTERS: RefCell<Option<(u64, Arc<Mutex<Counters>>)>> = const { RefCell::new(None) };
+}
+
+/// Totals by name, in the order they were first counted.
+type Counters = Vec<(&'static str, u64)>;
+
+fn add(counters: &mut Counters, name: &'static str, n: u64) {
+    match counters.iter_mut().find(|(counter, _)| *counter == name) {
+        Some((_, total)) => *total += n,
+        None => counters.push((name, n)),
+    }
+}
+
+/// The system allocator, counting every allocation and the bytes in use as it goes.
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: elves::profile::CountingAllocator = elves::profile::CountingAllocator;
+/// ```
+pub struct CountingAllocator;
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            allocated(layout.size(), 0);
+        }
+        ptr
+    }
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            allocated(layout.size(), 0);
+        }
+        ptr
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE_BYTES.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+    }
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            allocated(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// Counts an allocation of `size` bytes that replaced one of `freed` bytes.
+fn allocated(size: usize, freed: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED_BYTES.fetch_add(size as u64, Ordering::Relaxed);
+    let (size, freed) = (size as u64, freed as u64);
+    if size >= freed {
+        let live = LIVE_BYTES.fetch_add(size - freed, Ordering::Relaxed);
+        PEAK_BYTES.fetch_max(live.wrapping_add(size - freed), Ordering::Relaxed);
+    } else {
+        LIVE_BYTES.fetch_sub(freed - size, Ordering::Relaxed);
+    }
+}
+
+/// Allocations made so far by the whole process.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Allocations {
+    count: u64,
+    bytes: u64,
+}
+fn allocations() -> Allocations {
+    Allocations {
+        count: ALLOCATIONS.load(Ordering::Relaxed),
+        bytes: ALLOCATED_BYTES.load(Ordering::Relaxed),
+    }
+}
+
+/// The spans recorded since [`start`].
+struct Recorder {
+    spans: Vec<SpanReport>,
+}
+impl Recorder {
+    const fn new() -> Recorder {
+        Recorder { spans: Vec::new() }
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // A panic while holding the lock can't leave the totals half updated.
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Starts recording spans and counts, forgetting any earlier ones.
+pub fn start() {
+    *lock(&RECORDER) = Recorder::new();
+    lock(&THREAD_COUNTERS).clear();
+    GENERATION.fetch_add(1, Ordering::Relaxed);
+    PEAK_BYTES.store(LIVE_BYTES.load(Ordering::Relaxed), Ordering::Relaxed);
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Stops recording and returns what was recorded since [`start`].
+pub fn stop() -> Profile {
+    ENABLED.store(false, Ordering::Relaxed);
+    let generation = GENERATION.load(Ordering::Relaxed);
+    let recorder = std::mem::replace(&mut *lock(&RECORDER), Recorder::new());
+    let mut counters = Counters::new();
+    for (_, thread) in std::mem::take(&mut *lock(&THREAD_COUNTERS))
+        .iter()
+        .filter(|(thread_generation, _)| *thread_generation == generation)
+    {
+        for &(name, n) in lock(thread).iter() {
+            add(&mut counters, name, n);
+        }
+    }
+    Profile {
+        spans: recorder.spans,
+        counters,
+        peak_bytes: PEAK_BYTES.load(Ordering::Relaxed),
+    }
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Adds `n` to the counter called `name`.
+pub fn count(name: &'static str, n: u64) {
+    if !enabled() {
+        return;
+    }
+    let generation = GENERATION.load(Ordering::Relaxed);
+    COUNTERS.with(|counters| {
+        let mut counters = counters.borrow_mut();
+        let thread = match &*counters {
+            Some((thread_generation, thread)) if *thread_generation == generation => thread,
+            _ => {
+                let thread = Arc::default();
+                lock(&THREAD_COUNTERS).push((generation, Arc::clone(&thread)));
+                &counters.insert((generation, thread)).1
+            }
+        };
+        add(&mut lock(thread), name, n);
+    });
+}
+
+/// Times the code from here to the end of the scope as `name`, nested under any span still
+/// open on this thread. Keep the guard in a variable: `let _span = span("dfs");`.
+#[must_use = "the span ends as soon as this is dropped"]
+pub fn span(name: &'static str) -> Span {
+    if !enabled() {
+        return Span { open: None };
+    }
+    OPEN.with(|open| open.borrow_mut().push(name));
+    Span {
+        open: Some((
+            GENERATION.load(Ordering::Relaxed),
+            Instant::now(),
+            allocations(),
+        )),
+    }
+}
+
+/// An open [`span`], recorded when it's dropped, unless the profile it was opened in has
+/// stopped by then.
+pub struct Span {
+    open: Option<(u64, Instant, Allocations)>,
+}
+impl Drop for Span {
+    fn drop(&mut self) {
+        let Some((generation, start, before)) = self.open.take() else {
+            return;
+        };
+        let time = start.elapsed();
+        let after = allocations();
+        let path = OPEN.with(|open| {
+            let path = open.borrow().clone();
+            open.borrow_mut().pop();
+            path
+        });
+        if !enabled() || generation != GENERATION.load(Ordering::Relaxed) {
+            return;
+        }
+        let mut recorder = lock(&RECORDER);
+        let index = match recorder.spans.iter().position(|span| span.path == path) {
+            Some(index) => index,
+            None => {
+                recorder.spans.push(SpanReport {
+                    path,
+                    ..SpanReport::default()
+                });
+                recorder.spans.len() - 1
+            }
+        };
+        let span = &mut recorder.spans[index];
+        span.calls += 1;
+        span.time += time;
+        span.allocations += after.count - before.count;
+        span.bytes += after.bytes - before.bytes;
+    }
+}
+
+/// Every call of one span, added up.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpanReport {
+    /// The span's name, after the names of the spans it was opened in.
+    pub path: Vec<&'static str>,
+    pub calls: u64,
+    pub time: Duration,
+    /// Allocations made while the span was open, on any thread.
+    pub allocations: u64,
+    pub bytes: u64,
+}
+
+/// What [`stop`] hands back. Spans and counters are in the order they were first seen.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Profile {
+    pub spans: Vec<SpanReport>,
+    pub counters: Vec<(&'static str, u64)>,
+    /// The most memory in use at once while recording.
+    pub peak_bytes: u64,
+}
+impl Profile {
+    pub fn span(&self, path: &[&str]) -> Option<&SpanReport> {
+        self.spans.iter().find(|span| span.path == path)
+    }
+    pub fn counter(&self, name: &str) -> u64 {
+        self.counters
+            .iter()
+            .find(|(counter, _)| *counter == name)
+            .map_or(0, |(_, total)| *total)
+    }
+}
+impl Display for Profile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:<28} {:>7} {:>12} {:>12} {:>11}",
+            "span", "calls", "time", "allocations", "allocated"
+        )?;
+        // Children right under their parents, in the order the parents were first seen.
+        let mut spans: Vec<&SpanReport> = self.spans.iter().collect();
+        let first_seen = |path: &[&str]| {
+            self.spans
+                .iter()
+                .position(|span| span.path.starts_with(path))
+                .unwrap_or(usize::MAX)
+        };
+        spans.sort_by_cached_key(|span| {
+            (1..=span.path.len())
+                .map(|depth| first_seen(&span.path[..depth]))
+                .collect::<Vec<_>>()
+        });
+        for span in spans {
+            let depth = span.path.len() - 1;
+            let name = format!("{:indent$}{}", "", span.path[depth], indent = 2 * depth);
+            writeln!(
+                f,
+                "{:<28} {:>7} {:>12} {:>12} {:>11}",
+                name,
+                span.calls,
+                format!("{:.1?}", span.time),
+                span.allocations,
+                bytes(span.bytes)
+            )?;
+        }
+        for (name, total) in &self.counters {
+            writeln!(f, "{:<28} {:>7}", name, total)?;
+        }
+        write!(f, "{:<28} {:>7}", "peak memory", bytes(self.peak_bytes))
+    }
+}
+
+/// `1.5 MiB` and the like.
+fn bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nests_spans_and_adds_counts() {
+        // Other tests may record spans of their own while this one runs, so only look at
+        // the names used here.
+        start();
+        for _ in 0..3 {
+            let _outer = span("profiled outer");
+            for _ in 0..2 {
+                let _inner = span("profiled inner");
+                count("profiled steps", 5);
+            }
+        }
+        let profile = stop();
+        count("profiled steps", 1);
+        let outer = profile.span(&["profiled outer"]).unwrap();
+        let inner = profile.span(&["profiled outer", "profiled inner"]).unwrap();
+        assert_eq!((outer.calls, inner.calls), (3, 6));
+        assert!(outer.time >= inner.time);
+        assert_eq!(profile.counter("profiled steps"), 30);
+        assert_eq!(profile.counter("never counted"), 0);
+        assert!(profile.to_string().contains("\n  profiled inner "));
+
+        // Counts from every thread add up, and a span outliving its profile is dropped.
+        start();
+        let stale = span("profiled stale");
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| count("profiled threads", 2));
+            }
+        });
+        count("profiled threads", 1);
+        assert_eq!(stop().counter("profiled threads"), 9);
+        start();
+        drop(stale);
+        count("profiled threads", 1);
+        let profile = stop();
+        assert!(profile.span(&["profiled stale"]).is_none());
+        assert_eq!(profile.counter("profiled threads"), 1);
+    }
+
+    #[test]
+    fn sizes_bytes() {
+        assert_eq!(bytes(12), "12 B");
+        assert_eq!(bytes(1536), "1.5 KiB");
+        assert_eq!(bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
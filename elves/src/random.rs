@@ -0,0 +1,121 @@
+//! A small seeded random number generator for making up puzzle inputs.
+//!
+//! The same seed always gives the same numbers, on every machine, so a generated input can
+//! be passed around as just its seed and size. Not for anything that needs to be
+//! unpredictable.
+
+use crate::grid::{Coord, Grid};
+
+/// SplitMix64, which is plenty random for test inputs and needs no dependencies.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+    /// A number from `0` up to but not including `n`, which must be positive.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "there's no number below 0");
+        ((u128::from(self.next_u64()) * n as u128) >> 64) as usize
+    }
+    /// A number from `low` to `high`, both included.
+    pub fn between(&mut self, low: i64, high: i64) -> i64 {
+        assert!(low <= high, "{} is more than {}", low, high);
+        let span = high.abs_diff(low) as u128 + 1;
+        low.wrapping_add(((u128::from(self.next_u64()) * span) >> 64) as i64)
+    }
+    /// `true` about `percent` times in a hundred.
+    pub fn chance(&mut self, percent: usize) -> bool {
+        self.below(100) < percent
+    }
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// A maze of `width` by `height` rooms, `true` for open tiles. Rooms sit at odd coordinates
+/// with a wall around the whole maze, so the grid is `2 * width + 1` tiles across, and there
+/// is exactly one path between any two rooms.
+pub fn maze(rng: &mut Rng, width: usize, height: usize) -> Grid<bool> {
+    let (width, height) = (width.max(1), height.max(1));
+    let mut open = Grid::new(2 * width + 1, 2 * height + 1, false);
+    let room = |(x, y): Coord| (2 * x + 1, 2 * y + 1);
+    open.set(room((0, 0)), true);
+    let mut path: Vec<Coord> = vec![(0, 0)];
+    while let Some(&(x, y)) = path.last() {
+        let unvisited: Vec<Coord> = [(0, -1), (1, 0), (0, 1), (-1, 0)]
+            .into_iter()
+            .filter_map(|(dx, dy)| Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?)))
+            .filter(|&(x, y)| x < width && y < height && !open[room((x, y))])
+            .collect();
+        if unvisited.is_empty() {
+            path.pop();
+            continue;
+        }
+        let next = *rng.pick(&unvisited);
+        let (from, to) = (room((x, y)), room(next));
+        open.set(((from.0 + to.0) / 2, (from.1 + to.1) / 2), true);
+        open.set(to, true);
+        path.push(next);
+    }
+    open
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeats_for_a_seed() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..5).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+        assert_eq!(Rng::new(1234567).next_u64(), 6457827717110365317);
+    }
+
+    #[test]
+    fn stays_in_range() {
+        let mut rng = Rng::new(0);
+        for _ in 0..1000 {
+            assert!(rng.below(3) < 3);
+            assert!((-2..=2).contains(&rng.between(-2, 2)));
+        }
+        assert_eq!(rng.between(i64::MIN, i64::MIN), i64::MIN);
+        let mut items: Vec<u32> = (0..20).collect();
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn mazes_join_every_room() {
+        let maze = maze(&mut Rng::new(3), 6, 4);
+        assert_eq!((maze.width(), maze.height()), (13, 9));
+        let open = maze.iter().filter(|(_, open)| **open).count();
+        // 24 rooms and the 23 gaps of a spanning tree between them.
+        assert_eq!(open, 24 + 23);
+        let reached = crate::search::bfs_distances((1, 1), |&coord| {
+            maze.neighbours4(coord)
+                .filter(|next| maze[*next])
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(reached.len(), open);
+    }
+}
@@ -0,0 +1,336 @@
+//! Pictures of puzzle states, for sharing and debugging.
+//!
+//! A picture is a [`Grid`] of colours, usually a day's own grid mapped through a palette
+//! with [`Grid::map`]. [`Render`] steps a simulation like an
+//! [`Animation`](crate::Animation) does, but writes it to a PPM or PNG of the final state,
+//! or an animated GIF of every step.
+
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::Grid;
+
+/// A colour as red, green and blue.
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+
+/// Anything that can be drawn as a picture, one colour per tile.
+pub trait RenderImage {
+    fn render_image(&self) -> Grid<Rgb>;
+}
+impl RenderImage for Grid<Rgb> {
+    fn render_image(&self) -> Grid<Rgb> {
+        self.clone()
+    }
+}
+
+/// The kinds of file a [`Render`] can write, picked by extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The final state as a binary PPM.
+    Ppm,
+    /// The final state as a PNG.
+    Png,
+    /// Every step as an animated GIF.
+    Gif,
+}
+impl Format {
+    pub fn from_path(path: &Path) -> Option<Format> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "ppm" => Some(Format::Ppm),
+            "png" => Some(Format::Png),
+            "gif" => Some(Format::Gif),
+            _ => None,
+        }
+    }
+}
+
+/// Where and how to write a simulation as pictures. Each tile is 4 pixels square and GIFs
+/// play at 30 frames a second by default.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Render {
+    path: PathBuf,
+    format: Format,
+    scale: usize,
+    every: usize,
+    fps: f64,
+}
+impl Render {
+    /// Renders to `path`, which has to end in `.ppm`, `.png` or `.gif`.
+    pub fn to_file(path: impl Into<PathBuf>) -> Result<Render, String> {
+        let path = path.into();
+        let format = Format::from_path(&path).ok_or_else(|| {
+            format!(
+                "can't render to {}, expected a .ppm, .png or .gif file",
+                path.display()
+            )
+        })?;
+        Ok(Render {
+            path,
+            format,
+            scale: 4,
+            every: 1,
+            fps: 30.0,
+        })
+    }
+    /// Pixels per side of each tile.
+    pub fn scale(mut self, scale: usize) -> Self {
+        self.scale = scale.max(1);
+        self
+    }
+    /// Only keep every Nth step in a GIF. The first and last are always kept.
+    pub fn every(mut self, every: usize) -> Self {
+        self.every = every.max(1);
+        self
+    }
+    pub fn fps(mut self, fps: f64) -> Self {
+        self.fps = fps.max(0.1);
+        self
+    }
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+    pub fn format(&self) -> Format {
+        self.format
+    }
+    /// Calls `step` on `state` until it returns `false`, writing the pictures to the file.
+    /// Returns how many steps were taken.
+    pub fn run<S: RenderImage + ?Sized>(
+        &self,
+        state: &mut S,
+        step: impl FnMut(&mut S) -> bool,
+    ) -> io::Result<usize> {
+        let mut out = BufWriter::new(File::create(&self.path)?);
+        let steps = self.run_to(&mut out, state, step)?;
+        out.flush()?;
+        Ok(steps)
+    }
+    /// [`Render::run`], writing to `out` instead of the file.
+    pub fn run_to<S: RenderImage + ?Sized>(
+        &self,
+        out: &mut impl Write,
+        state: &mut S,
+        mut step: impl FnMut(&mut S) -> bool,
+    ) -> io::Result<usize> {
+        let mut steps = 0;
+        if self.format != Format::Gif {
+            while step(state) {
+                steps += 1;
+            }
+            let image = state.render_image();
+            return match self.format {
+                Format::Ppm => write_ppm(out, &image, self.scale),
+                _ => write_png(out, &image, self.scale),
+            }
+            .map(|_| steps);
+        }
+        let first = state.render_image();
+        let mut gif = GifWriter::new(out, first.width(), first.height(), self.scale, self.fps)?;
+        gif.add(&first)?;
+        let mut drawn = true;
+        while step(state) {
+            steps += 1;
+            drawn = steps % self.every == 0;
+            if drawn {
+                gif.add(&state.render_image())?;
+            }
+        }
+        if !drawn {
+            gif.add(&state.render_image())?;
+        }
+        Ok(steps)
+    }
+}
+
+/// Writes `image` as a binary PPM, each tile `scale` pixels square.
+pub fn write_ppm(out: &mut impl Write, image: &Grid<Rgb>, scale: usize) -> io::Result<()> {
+    write!(
+        out,
+        "P6\n{} {}\n255\n",
+        image.width() * scale,
+        image.height() * scale
+    )?;
+    out.write_all(&pixels(image, scale))
+}
+
+/// Writes `image` as a PNG, each tile `scale` pixels square.
+pub fn write_png(out: &mut impl Write, image: &Grid<Rgb>, scale: usize) -> io::Result<()> {
+    let width = dimension::<u32>(image.width() * scale)?;
+    let height = dimension::<u32>(image.height() * scale)?;
+    let mut encoder = png::Encoder::new(out, width, height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(&pixels(image, scale)))
+        .map_err(io::Error::other)
+}
+
+/// Writes pictures one at a time as the frames of a looping GIF.
+pub struct GifWriter<W: Write> {
+    encoder: gif::Encoder<W>,
+    width: usize,
+    height: usize,
+    scale: usize,
+    /// In hundredths of a second, as GIFs count it.
+    delay: u16,
+}
+impl<W: Write> GifWriter<W> {
+    /// Every frame has to be `width` by `height` tiles.
+    pub fn new(out: W, width: usize, height: usize, scale: usize, fps: f64) -> io::Result<Self> {
+        let mut encoder = gif::Encoder::new(
+            out,
+            dimension(width * scale)?,
+            dimension(height * scale)?,
+            &[],
+        )
+        .map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+        Ok(GifWriter {
+            encoder,
+            width,
+            height,
+            scale,
+            delay: (100.0 / fps).round().clamp(1.0, u16::MAX as f64) as u16,
+        })
+    }
+    pub fn add(&mut self, image: &Grid<Rgb>) -> io::Result<()> {
+        if (image.width(), image.height()) != (self.width, self.height) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "frame is {}x{} tiles, expected {}x{}",
+                    image.width(),
+                    image.height(),
+                    self.width,
+                    self.height
+                ),
+            ));
+        }
+        let width = dimension(self.width * self.scale)?;
+        let height = dimension(self.height * self.scale)?;
+        let pixels = pixels(image, self.scale);
+        let mut frame = match indexed(&pixels) {
+            Some((palette, indices)) => gif::Frame {
+                width,
+                height,
+                palette: Some(palette),
+                buffer: Cow::Owned(indices),
+                ..gif::Frame::default()
+            },
+            // Too many colours for one palette, let the encoder pick the closest 256.
+            None => gif::Frame::from_rgb_speed(width, height, &pixels, 10),
+        };
+        frame.delay = self.delay;
+        self.encoder.write_frame(&frame).map_err(io::Error::other)
+    }
+}
+
+/// The image's pixels as RGB bytes, row by row, each tile blown up to `scale` pixels square.
+fn pixels(image: &Grid<Rgb>, scale: usize) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(image.len() * scale * scale * 3);
+    for row in image.rows() {
+        for _ in 0..scale {
+            for colour in row {
+                for _ in 0..scale {
+                    bytes.extend_from_slice(colour);
+                }
+            }
+        }
+    }
+    bytes
+}
+
+/// A GIF palette and an index into it for every pixel, if there are 256 colours or fewer.
+fn indexed(pixels: &[u8]) -> Option<(Vec<u8>, Vec<u8>)> {
+    let mut palette: HashMap<&[u8], u8> = HashMap::new();
+    let mut colours = Vec::new();
+    let mut indices = Vec::with_capacity(pixels.len() / 3);
+    for colour in pixels.chunks_exact(3) {
+        let index = match palette.get(colour) {
+            Some(index) => *index,
+            None => {
+                let index = u8::try_from(palette.len()).ok()?;
+                palette.insert(colour, index);
+                colours.extend_from_slice(colour);
+                index
+            }
+        };
+        indices.push(index);
+    }
+    Some((colours, indices))
+}
+
+fn dimension<T: TryFrom<usize>>(pixels: usize) -> io::Result<T> {
+    T::try_from(pixels).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{} pixels is too big for an image", pixels),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkerboard() -> Grid<Rgb> {
+        Grid::from_vec(2, 2, vec![BLACK, WHITE, WHITE, BLACK]).unwrap()
+    }
+
+    #[test]
+    fn writes_scaled_ppm_and_png() {
+        let mut ppm = Vec::new();
+        write_ppm(&mut ppm, &checkerboard(), 2).unwrap();
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 4 * 3);
+        assert_eq!(
+            &ppm[11..23],
+            &[0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255]
+        );
+
+        let mut png = Vec::new();
+        write_png(&mut png, &checkerboard(), 3).unwrap();
+        let mut reader = png::Decoder::new(png.as_slice()).read_info().unwrap();
+        let mut decoded = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut decoded).unwrap();
+        assert_eq!((info.width, info.height), (6, 6));
+        assert_eq!(&decoded[..info.buffer_size()], pixels(&checkerboard(), 3));
+    }
+
+    #[test]
+    fn records_every_nth_step_and_the_last_as_gif() {
+        let mut board = checkerboard();
+        let mut steps = 0;
+        let render = Render::to_file("out.gif").unwrap().scale(1).every(3);
+        let mut gif = Vec::new();
+        let taken = render
+            .run_to(&mut gif, &mut board, |board| {
+                steps += 1;
+                board[(0, 0)] = [steps * 10, 0, 0];
+                steps < 7
+            })
+            .unwrap();
+        assert_eq!(taken, 6);
+
+        let mut decoder = gif::DecodeOptions::new().read_info(gif.as_slice()).unwrap();
+        let mut frames = 0;
+        while decoder.read_next_frame().unwrap().is_some() {
+            frames += 1;
+        }
+        // Steps 0, 3 and 6, where 6 is also the last.
+        assert_eq!(frames, 3);
+        assert!(Render::to_file("out.txt").is_err());
+        assert_eq!(Render::to_file("OUT.PNG").unwrap().format(), Format::Png);
+    }
+}
@@ -0,0 +1,463 @@
+//! Graph searches over any node type, driven by a successor function.
+//!
+//! Nodes are stored once and linked through predecessor indices, so a search never clones
+//! the path so far into its queue. Paths come back start first, goal last.
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use crate::profile;
+
+/// Anything that can be summed up as the cost of a path, like `usize` or `u64`.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+impl<C: Copy + Ord + Default + Add<Output = C>> Cost for C {}
+
+/// Nodes seen so far, each with the index of the node it was first reached from.
+struct Visited<N> {
+    nodes: Vec<N>,
+    parents: Vec<usize>,
+    indices: HashMap<N, usize>,
+}
+impl<N: Clone + Eq + Hash> Visited<N> {
+    fn new(start: N) -> Self {
+        Visited {
+            nodes: vec![start.clone()],
+            parents: vec![0],
+            indices: HashMap::from([(start, 0)]),
+        }
+    }
+    /// Index of `node`, and whether this is the first time it was seen.
+    fn insert(&mut self, node: N, parent: usize) -> (usize, bool) {
+        if let Some(index) = self.indices.get(&node) {
+            return (*index, false);
+        }
+        let index = self.nodes.len();
+        self.nodes.push(node.clone());
+        self.parents.push(parent);
+        self.indices.insert(node, index);
+        (index, true)
+    }
+    fn path(&self, mut index: usize) -> Vec<N> {
+        let mut path = vec![self.nodes[index].clone()];
+        while index != 0 {
+            index = self.parents[index];
+            path.push(self.nodes[index].clone());
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Shortest path by number of steps, from `start` to the first node that satisfies `is_goal`.
+pub fn bfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let _span = profile::span("bfs");
+    let mut visited = Visited::new(start);
+    let mut queue = VecDeque::from([0]);
+    while let Some(index) = queue.pop_front() {
+        profile::count("nodes expanded", 1);
+        let node = visited.nodes[index].clone();
+        if is_goal(&node) {
+            return Some(visited.path(index));
+        }
+        for next in successors(&node) {
+            let (next_index, new) = visited.insert(next, index);
+            if new {
+                queue.push_back(next_index);
+            }
+        }
+    }
+    None
+}
+
+/// Steps from `start` to every node it can reach, `start` itself included at zero.
+pub fn bfs_distances<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let _span = profile::span("bfs_distances");
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        profile::count("nodes expanded", 1);
+        let distance = distances[&node];
+        for next in successors(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+/// Priority queue entry, popped cheapest estimate first.
+struct Frontier<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+impl<C: Ord> PartialEq for Frontier<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl<C: Ord> Eq for Frontier<C> {}
+impl<C: Ord> PartialOrd for Frontier<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<C: Ord> Ord for Frontier<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        Reverse(&self.estimate)
+            .cmp(&Reverse(&other.estimate))
+            .then_with(|| self.index.cmp(&other.index))
+    }
+}
+
+/// Cheapest path from `start` to a goal, where `successors` yields each neighbour along
+/// with the cost of stepping onto it. Returns the path and its total cost.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let _span = profile::span("dijkstra");
+    cheapest_path(start, successors, |_| C::default(), is_goal)
+}
+
+/// [`dijkstra`] guided by `heuristic`, which must never overestimate the cost left to a goal.
+pub fn astar<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let _span = profile::span("astar");
+    cheapest_path(start, successors, heuristic, is_goal)
+}
+
+fn cheapest_path<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut visited = Visited::new(start);
+    let mut costs = vec![C::default()];
+    let mut queue = BinaryHeap::from([Frontier {
+        estimate: heuristic(&visited.nodes[0]),
+        cost: C::default(),
+        index: 0,
+    }]);
+    while let Some(Frontier { cost, index, .. }) = queue.pop() {
+        if cost > costs[index] {
+            continue;
+        }
+        profile::count("nodes expanded", 1);
+        let node = visited.nodes[index].clone();
+        if is_goal(&node) {
+            return Some((visited.path(index), cost));
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            let (next_index, new) = visited.insert(next, index);
+            if new {
+                costs.push(next_cost);
+            } else if next_cost < costs[next_index] {
+                costs[next_index] = next_cost;
+                visited.parents[next_index] = index;
+            } else {
+                continue;
+            }
+            queue.push(Frontier {
+                estimate: next_cost + heuristic(&visited.nodes[next_index]),
+                cost: next_cost,
+                index: next_index,
+            });
+        }
+    }
+    None
+}
+
+/// Every cheapest path to the goals, as found by [`dijkstra_all`].
+pub struct ShortestPaths<N, C> {
+    nodes: Vec<N>,
+    predecessors: Vec<Vec<usize>>,
+    goals: Vec<usize>,
+    cost: C,
+}
+impl<N: Clone + Eq + Hash, C: Copy> ShortestPaths<N, C> {
+    pub fn cost(&self) -> C {
+        self.cost
+    }
+    /// The goals that can be reached at the cheapest cost.
+    pub fn goals(&self) -> Vec<N> {
+        self.goals.iter().map(|i| self.nodes[*i].clone()).collect()
+    }
+    /// Every node that lies on at least one of the cheapest paths.
+    pub fn nodes_on_paths(&self) -> HashSet<N> {
+        let mut seen: HashSet<usize> = self.goals.iter().copied().collect();
+        let mut stack = self.goals.clone();
+        while let Some(index) = stack.pop() {
+            for previous in &self.predecessors[index] {
+                if seen.insert(*previous) {
+                    stack.push(*previous);
+                }
+            }
+        }
+        seen.into_iter().map(|i| self.nodes[i].clone()).collect()
+    }
+    /// How many distinct cheapest paths there are.
+    pub fn count(&self) -> usize {
+        let mut counts = HashMap::new();
+        self.goals
+            .iter()
+            .map(|goal| self.count_from(*goal, &mut counts))
+            .sum()
+    }
+    fn count_from(&self, index: usize, counts: &mut HashMap<usize, usize>) -> usize {
+        if self.predecessors[index].is_empty() {
+            return 1;
+        }
+        if let Some(count) = counts.get(&index) {
+            return *count;
+        }
+        let count = self.predecessors[index]
+            .iter()
+            .map(|previous| self.count_from(*previous, counts))
+            .sum();
+        counts.insert(index, count);
+        count
+    }
+}
+
+/// Like [`dijkstra`], but keeps every predecessor that ties for cheapest, so all of the
+/// cheapest paths to the goal can be inspected afterwards.
+pub fn dijkstra_all<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<ShortestPaths<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let _span = profile::span("dijkstra_all");
+    let mut visited = Visited::new(start);
+    let mut costs = vec![C::default()];
+    let mut predecessors = vec![vec![]];
+    let mut goals = vec![];
+    let mut goal_cost = None;
+    let mut queue = BinaryHeap::from([Frontier {
+        estimate: C::default(),
+        cost: C::default(),
+        index: 0,
+    }]);
+    while let Some(Frontier { cost, index, .. }) = queue.pop() {
+        if cost > costs[index] || goal_cost.is_some_and(|goal_cost| cost > goal_cost) {
+            continue;
+        }
+        profile::count("nodes expanded", 1);
+        let node = visited.nodes[index].clone();
+        if is_goal(&node) {
+            if !goals.contains(&index) {
+                goals.push(index);
+            }
+            goal_cost = Some(cost);
+            continue;
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            let (next_index, new) = visited.insert(next, index);
+            if new {
+                costs.push(next_cost);
+                predecessors.push(vec![index]);
+            } else if next_cost < costs[next_index] {
+                costs[next_index] = next_cost;
+                predecessors[next_index] = vec![index];
+            } else {
+                if next_cost == costs[next_index] && !predecessors[next_index].contains(&index) {
+                    predecessors[next_index].push(index);
+                }
+                continue;
+            }
+            queue.push(Frontier {
+                estimate: next_cost,
+                cost: next_cost,
+                index: next_index,
+            });
+        }
+    }
+    Some(ShortestPaths {
+        nodes: visited.nodes,
+        predecessors,
+        goals,
+        cost: goal_cost?,
+    })
+}
+
+/// Number of distinct paths from `start` to any goal. The graph must be acyclic, every
+/// successor moving strictly closer to where paths end.
+pub fn count_paths<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> usize
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    fn count<N, I>(
+        node: N,
+        successors: &mut impl FnMut(&N) -> I,
+        is_goal: &mut impl FnMut(&N) -> bool,
+        counts: &mut HashMap<N, usize>,
+    ) -> usize
+    where
+        N: Clone + Eq + Hash,
+        I: IntoIterator<Item = N>,
+    {
+        if is_goal(&node) {
+            return 1;
+        }
+        if let Some(count) = counts.get(&node) {
+            return *count;
+        }
+        profile::count("nodes expanded", 1);
+        let total = successors(&node)
+            .into_iter()
+            .map(|next| count(next, successors, is_goal, counts))
+            .sum();
+        counts.insert(node, total);
+        total
+    }
+    let _span = profile::span("count_paths");
+    count(start, &mut successors, &mut is_goal, &mut HashMap::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Grid;
+
+    const MAZE: &str = "
+        S..#....
+        .#.#.##.
+        .#...#..
+        .####.#.
+        ......#E
+    ";
+
+    fn maze() -> Grid<char> {
+        Grid::parse(MAZE).unwrap()
+    }
+    fn open_neighbours(
+        grid: &Grid<char>,
+    ) -> impl FnMut(&(usize, usize)) -> Vec<(usize, usize)> + '_ {
+        |coord| {
+            grid.neighbours4(*coord)
+                .filter(|next| grid[*next] != '#')
+                .collect()
+        }
+    }
+
+    #[test]
+    fn bfs_finds_a_shortest_path() {
+        let grid = maze();
+        let start = grid.position(&'S').unwrap();
+        let end = grid.position(&'E').unwrap();
+        let path = bfs(start, open_neighbours(&grid), |coord| *coord == end).unwrap();
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&end));
+        assert_eq!(path.len() - 1, 15);
+        assert!(bfs(start, open_neighbours(&grid), |coord| *coord == (3, 0)).is_none());
+
+        let distances = bfs_distances(start, open_neighbours(&grid));
+        assert_eq!(distances[&start], 0);
+        assert_eq!(distances[&end], 15);
+        assert!(!distances.contains_key(&(3, 0)));
+    }
+
+    #[test]
+    fn weighted_searches_agree() {
+        // Stepping onto a cell costs its row number plus one, so lower rows are dearer
+        let grid = maze();
+        let start = grid.position(&'S').unwrap();
+        let end = grid.position(&'E').unwrap();
+        let weighted = |coord: &(usize, usize)| {
+            open_neighbours(&grid)(coord)
+                .into_iter()
+                .map(|next| (next, next.1 + 1))
+                .collect::<Vec<_>>()
+        };
+        let (path, cost) = dijkstra(start, weighted, |coord| *coord == end).unwrap();
+        assert_eq!(path.last(), Some(&end));
+        let manhattan = |coord: &(usize, usize)| coord.0.abs_diff(end.0) + coord.1.abs_diff(end.1);
+        let (_, astar_cost) = astar(start, weighted, manhattan, |coord| *coord == end).unwrap();
+        assert_eq!(cost, astar_cost);
+        let summed: usize = path.iter().skip(1).map(|coord| coord.1 + 1).sum();
+        assert_eq!(cost, summed);
+    }
+
+    #[test]
+    fn all_shortest_paths() {
+        // A 3x3 open square has six shortest corner to corner paths, over every cell
+        let grid: Grid<char> = Grid::new(3, 3, '.');
+        let unit = |coord: &(usize, usize)| {
+            grid.neighbours4(*coord)
+                .map(|next| (next, 1usize))
+                .collect::<Vec<_>>()
+        };
+        let paths = dijkstra_all((0, 0), unit, |coord| *coord == (2, 2)).unwrap();
+        assert_eq!(paths.cost(), 4);
+        assert_eq!(paths.goals(), [(2, 2)]);
+        assert_eq!(paths.count(), 6);
+        assert_eq!(paths.nodes_on_paths().len(), 9);
+        assert!(dijkstra_all((0, 0), unit, |_| false).is_none());
+    }
+
+    #[test]
+    fn counts_paths_in_a_dag() {
+        // Only moving right or down through a 3x3 square
+        let paths = count_paths(
+            (0, 0),
+            |(x, y): &(u8, u8)| {
+                [(x + 1, *y), (*x, y + 1)]
+                    .into_iter()
+                    .filter(|(x, y)| *x < 3 && *y < 3)
+                    .collect::<Vec<_>>()
+            },
+            |coord| *coord == (2, 2),
+        );
+        assert_eq!(paths, 6);
+    }
+}
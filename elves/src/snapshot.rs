@@ -0,0 +1,170 @@
+//! Snapshot tests for anything drawn with `Display`, such as a day's grid or the frames of a
+//! simulation.
+//!
+//! `assert_snapshot!("warehouse", warehouse)` compares the drawing, without its colours, to
+//! `snapshots/warehouse.snap` in the crate calling it. A mismatch fails with a line diff. Run
+//! the tests with `AOC_UPDATE_SNAPSHOTS=1` to write the new drawings instead, then look over
+//! the changes to the `.snap` files before committing them.
+
+use std::path::Path;
+
+use crate::{Animation, RenderFrame};
+
+/// Set to anything but `0` to accept the drawings as the new snapshots.
+pub const UPDATE_VAR: &str = "AOC_UPDATE_SNAPSHOTS";
+
+/// Compares how `$value` displays to the crate's `snapshots/$name.snap`.
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($name:expr, $value:expr) => {
+        $crate::snapshot::assert_snapshot(
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("snapshots"),
+            $name,
+            &$value.to_string(),
+        )
+    };
+}
+
+/// Panics unless `drawing` matches `<dir>/<name>.snap`, or writes it there when
+/// [`UPDATE_VAR`] is set. Prefer [`assert_snapshot!`], which finds the crate's `snapshots/`.
+#[track_caller]
+pub fn assert_snapshot(dir: impl AsRef<Path>, name: &str, drawing: &str) {
+    let update = std::env::var(UPDATE_VAR).is_ok_and(|value| value != "0");
+    if let Err(message) = check(dir.as_ref(), name, drawing, update) {
+        panic!("{}", message);
+    }
+}
+
+/// Every frame a headless [`Animation`] draws of `state` while `step` runs, one after another.
+pub fn frames<S: RenderFrame + ?Sized>(
+    state: &mut S,
+    every: usize,
+    step: impl FnMut(&mut S) -> bool,
+) -> String {
+    let mut out = Vec::new();
+    Animation::headless(every)
+        .run_to(&mut out, false, state, step)
+        .expect("drawing to memory doesn't fail");
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn check(dir: &Path, name: &str, drawing: &str, update: bool) -> Result<(), String> {
+    let path = dir.join(format!("{}.snap", name));
+    let actual = strip_colours(drawing);
+    let expected = std::fs::read_to_string(&path).ok();
+    if expected.as_deref() == Some(actual.as_str()) {
+        return Ok(());
+    }
+    if update {
+        std::fs::create_dir_all(dir)
+            .and_then(|_| std::fs::write(&path, &actual))
+            .map_err(|error| format!("could not write {}: {}", path.display(), error))?;
+        return Ok(());
+    }
+    match expected {
+        Some(expected) => Err(format!(
+            "snapshot `{}` doesn't match {}\n{}\nrerun with {}=1 to accept the new drawing",
+            name,
+            path.display(),
+            diff(&expected, &actual),
+            UPDATE_VAR
+        )),
+        None => Err(format!(
+            "there's no snapshot at {}, rerun with {}=1 to write it:\n{}",
+            path.display(),
+            UPDATE_VAR,
+            actual
+        )),
+    }
+}
+
+/// `text` without the escape codes that colour it in a terminal.
+pub fn strip_colours(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            stripped.push(c);
+            continue;
+        }
+        // `ESC [`, any parameters, then a final letter such as the `m` of a colour.
+        if chars.next() == Some('[') {
+            for c in chars.by_ref() {
+                if ('@'..='~').contains(&c) {
+                    break;
+                }
+            }
+        }
+    }
+    stripped
+}
+
+/// The lines of `expected` and `actual`, those only in `expected` marked `-` and those only
+/// in `actual` marked `+`.
+fn diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    // How many lines the rest of each side has in common, from the back.
+    let mut common = vec![vec![0; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            common[i][j] = match expected[i] == actual[j] {
+                true => common[i + 1][j + 1] + 1,
+                false => common[i + 1][j].max(common[i][j + 1]),
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut lines = Vec::new();
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            lines.push(format!("  {}", expected[i]));
+            (i, j) = (i + 1, j + 1);
+        } else if i < expected.len() && (j == actual.len() || common[i + 1][j] >= common[i][j + 1])
+        {
+            lines.push(format!("- {}", expected[i]));
+            i += 1;
+        } else {
+            lines.push(format!("+ {}", actual[j]));
+            j += 1;
+        }
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_colours() {
+        assert_eq!(strip_colours("\x1b[31m#\x1b[0m.\x1b[1;94m@\x1b[0m"), "#.@");
+        assert_eq!(strip_colours("no colour\n"), "no colour\n");
+    }
+
+    #[test]
+    fn diffs_lines() {
+        let diff = diff("#..\n.O.\n..@", "#..\n..O\n..@\n...");
+        assert_eq!(diff, "  #..\n- .O.\n+ ..O\n  ..@\n+ ...");
+    }
+
+    #[test]
+    fn checks_and_updates_snapshots() {
+        let dir = std::env::temp_dir().join(format!("elves-snapshots-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let missing = check(&dir, "grid", "#.\n", false).unwrap_err();
+        assert!(missing.contains("there's no snapshot"));
+        check(&dir, "grid", "\x1b[31m#\x1b[0m.\n", true).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(dir.join("grid.snap")).unwrap(),
+            "#.\n"
+        );
+        check(&dir, "grid", "#.\n", false).unwrap();
+
+        let mismatch = check(&dir, "grid", ".#\n", false).unwrap_err();
+        assert!(mismatch.contains("- #.\n+ .#\n"));
+        assert!(mismatch.ends_with("rerun with AOC_UPDATE_SNAPSHOTS=1 to accept the new drawing"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
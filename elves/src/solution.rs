@@ -0,0 +1,400 @@
+use std::{
+    fmt::{Display, Formatter},
+    path::PathBuf,
+    process::ExitCode,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    animation::Animation,
+    config,
+    error::{Error, Result},
+    input::InputSource,
+    profile,
+    random::Rng,
+    render::Render,
+};
+
+const DAY_NAMES: [&str; 25] = [
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+    "twenty",
+    "twentyone",
+    "twentytwo",
+    "twentythree",
+    "twentyfour",
+    "twentyfive",
+];
+
+/// The spelled-out name a day's crate goes by, e.g. `17` is `"seventeen"`.
+pub fn day_name(day: u8) -> Option<&'static str> {
+    DAY_NAMES.get(usize::from(day).checked_sub(1)?).copied()
+}
+
+/// Which puzzle: the year of the event and the day within it, written `2024/17`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DayId {
+    pub year: u16,
+    pub day: u8,
+}
+impl DayId {
+    pub fn new(year: u16, day: u8) -> DayId {
+        DayId { year, day }
+    }
+    /// The day's crate, e.g. `y2024-seventeen`.
+    pub fn crate_name(&self) -> Option<String> {
+        Some(format!("y{}-{}", self.year, day_name(self.day)?))
+    }
+    /// The day's folder in the workspace, e.g. `days/2024/seventeen`.
+    pub fn dir(&self) -> Option<PathBuf> {
+        Some(
+            PathBuf::from("days")
+                .join(self.year.to_string())
+                .join(day_name(self.day)?),
+        )
+    }
+}
+impl Display for DayId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{:02}", self.year, self.day)
+    }
+}
+impl FromStr for DayId {
+    type Err = String;
+    /// Reads `2024/17`.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (year, day) = value
+            .trim()
+            .split_once('/')
+            .ok_or_else(|| format!("`{}` is not a year/day like 2024/17", value))?;
+        let year = year
+            .parse()
+            .map_err(|_| format!("`{}` is not a year", year))?;
+        match day.parse() {
+            Ok(day @ 1..=25) => Ok(DayId { year, day }),
+            _ => Err(format!("`{}` is not a day between 1 and 25", day)),
+        }
+    }
+}
+
+/// One day's puzzle: parse the input once, then answer both parts from it.
+pub trait Solution {
+    /// The year of the event the puzzle is from.
+    const YEAR: u16;
+    const DAY: u8;
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part_one(input: &Self::Input) -> Result<impl Display>;
+    fn part_two(input: &Self::Input) -> Result<impl Display>;
+    /// Everything wrong with `input`, rather than just the first thing [`Solution::parse`]
+    /// trips over. Days whose input is a line per record report every bad line, e.g. with
+    /// [`check_lines`](crate::check_lines).
+    fn validate(input: &str) -> Vec<Error> {
+        Self::parse(input).err().into_iter().collect()
+    }
+    /// A made-up input that [`Solution::parse`] accepts, about `size` big: usually the number
+    /// of lines, or the side of a grid. `None` for days without a generator.
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+    /// Plays the puzzle step by step, for days with something worth watching. Returns
+    /// `false` for days without an animation.
+    fn animate(_input: &Self::Input, _animation: &Animation) -> Result<bool> {
+        Ok(false)
+    }
+    /// Writes the same steps as [`Solution::animate`] to an image file. Returns `false`
+    /// for days without pictures.
+    fn render(_input: &Self::Input, _render: &Render) -> Result<bool> {
+        Ok(false)
+    }
+    /// The day's parameters from its table in `aoc.toml`, such as the size of its grid. See
+    /// [`config`].
+    fn config<C: config::DayConfig>() -> Result<C> {
+        config::get(DayId::new(Self::YEAR, Self::DAY))
+    }
+}
+
+/// Answer for a part that hasn't been solved yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unsolved")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+impl TryFrom<u8> for Part {
+    type Error = &'static str;
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err("Part must be 1 or 2"),
+        }
+    }
+}
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "one"),
+            Part::Two => write!(f, "two"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartReport {
+    pub part: Part,
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+/// What came out of running a day: its answers and how long each phase took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub id: DayId,
+    pub parse: Duration,
+    pub parts: Vec<PartReport>,
+}
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}  parse     {:>12?}", self.id, self.parse)?;
+        for part in &self.parts {
+            writeln!(
+                f,
+                "         part {:<4} {:>12?}  {}",
+                part.part, part.elapsed, part.answer
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Object-safe view of a [`Solution`], so the runner can keep every day in one list.
+pub trait Runnable: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn id(&self) -> DayId {
+        DayId::new(self.year(), self.day())
+    }
+    /// Runs the requested part, or both when `part` is `None`.
+    fn run(&self, input: &str, part: Option<Part>) -> Result<Report>;
+    /// Every problem with `input`, each pointed at its place in it.
+    fn validate(&self, input: &str) -> Vec<Error>;
+    /// The day's generated input for `seed`, if it has a generator.
+    fn generate(&self, seed: u64, size: usize) -> Option<String>;
+    /// Parses `input` and plays the day's animation, `false` if it has none.
+    fn animate(&self, input: &str, animation: &Animation) -> Result<bool>;
+    /// Parses `input` and renders the day to an image file, `false` if it can't.
+    fn render(&self, input: &str, render: &Render) -> Result<bool>;
+}
+impl<S: Solution + Sync> Runnable for S {
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+    fn run(&self, input: &str, part: Option<Part>) -> Result<Report> {
+        let start = Instant::now();
+        let parsed = {
+            let _span = profile::span("parse");
+            S::parse(input)?
+        };
+        let parse = start.elapsed();
+        let parts = Part::ALL
+            .into_iter()
+            .filter(|p| part.is_none_or(|wanted| wanted == *p))
+            .map(|part| {
+                let start = Instant::now();
+                let answer = match part {
+                    Part::One => {
+                        let _span = profile::span("part one");
+                        S::part_one(&parsed)?.to_string()
+                    }
+                    Part::Two => {
+                        let _span = profile::span("part two");
+                        S::part_two(&parsed)?.to_string()
+                    }
+                };
+                Ok(PartReport {
+                    part,
+                    answer,
+                    elapsed: start.elapsed(),
+                })
+            })
+            .collect::<Result<_>>()?;
+        Ok(Report {
+            id: self.id(),
+            parse,
+            parts,
+        })
+    }
+    fn validate(&self, input: &str) -> Vec<Error> {
+        S::validate(input)
+    }
+    fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        S::generate(&mut Rng::new(seed), size)
+    }
+    fn animate(&self, input: &str, animation: &Animation) -> Result<bool> {
+        let parsed = S::parse(input)?;
+        S::animate(&parsed, animation)
+    }
+    fn render(&self, input: &str, render: &Render) -> Result<bool> {
+        let parsed = S::parse(input)?;
+        S::render(&parsed, render)
+    }
+}
+
+/// Entry point for a day's own binary: runs both parts on the file given with
+/// `--input <path>` (`-` for stdin), or on the day's default input, and prints the report.
+/// `--jobs <threads>` caps the threads the day's parallel work runs on, and the day's
+/// parameters come from `aoc.toml` or `--config <path>`, with `--set <key>=<value>` over them.
+/// Bad input is reported as an error rather than a panic.
+pub fn main<S: Solution + Sync>(solution: S) -> ExitCode {
+    let usage = || {
+        eprintln!(
+            "Usage: [--input <path>] [--jobs <threads>] [--config <path>] [--set <key>=<value>]..."
+        );
+        ExitCode::FAILURE
+    };
+    let mut args = std::env::args().skip(1);
+    let mut path = None;
+    let mut config_path = config::Config::default_path();
+    let mut settings = Vec::new();
+    while let Some(arg) = args.next() {
+        match (arg.as_str(), args.next()) {
+            ("--input", Some(input)) => path = Some(PathBuf::from(input)),
+            ("--config", Some(config)) => config_path = PathBuf::from(config),
+            ("--set", Some(setting)) => match setting.split_once('=') {
+                Some((key, value)) => settings.push((key.trim().to_string(), value.to_string())),
+                None => return usage(),
+            },
+            ("--jobs", Some(jobs)) => match jobs.parse() {
+                Ok(jobs) => crate::parallel::set_jobs(jobs),
+                Err(_) => return usage(),
+            },
+            _ => return usage(),
+        }
+    }
+    let id = DayId::new(S::YEAR, S::DAY);
+    match config::Config::load(&config_path) {
+        Ok(mut loaded) => {
+            for (key, value) in &settings {
+                loaded.set(id, key, value.trim());
+            }
+            config::set(loaded);
+        }
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    }
+    let report = match InputSource::from_arg(path.as_deref(), id).load() {
+        Ok(input) => solution.run(&input, None),
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    match report {
+        Ok(report) => {
+            print!("{}", report);
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{}: {}", id, error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Summing;
+    impl Solution for Summing {
+        const YEAR: u16 = 2024;
+        const DAY: u8 = 1;
+        type Input = Vec<u32>;
+        fn parse(input: &str) -> Result<Self::Input> {
+            crate::parse_lines(input, |line| crate::parse_number(line, "number"))
+        }
+        fn part_one(input: &Self::Input) -> Result<impl Display> {
+            Ok(input.iter().sum::<u32>())
+        }
+        fn part_two(_input: &Self::Input) -> Result<impl Display> {
+            Ok(Unsolved)
+        }
+    }
+
+    #[test]
+    fn runs_requested_parts() {
+        let report = Summing.run("1\n2\n3", None).unwrap();
+        assert_eq!(report.id, DayId::new(2024, 1));
+        assert!(report.to_string().starts_with("2024/01  parse"));
+        let answers: Vec<_> = report.parts.iter().map(|p| p.answer.as_str()).collect();
+        assert_eq!(answers, ["6", "unsolved"]);
+
+        let report = Summing.run("1\n2\n3", Some(Part::Two)).unwrap();
+        assert_eq!(report.parts.len(), 1);
+        assert_eq!(report.parts[0].part, Part::Two);
+
+        let error = Summing.run("1\n2\nthree", None).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: invalid number, found \"three\""
+        );
+        let errors = Summing.validate("1\ntwo\nthree");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].location().map(|at| at.line), Some(2));
+    }
+
+    #[test]
+    fn day_names() {
+        assert_eq!(day_name(1), Some("one"));
+        assert_eq!(day_name(17), Some("seventeen"));
+        assert_eq!(day_name(0), None);
+        assert_eq!(day_name(26), None);
+    }
+
+    #[test]
+    fn day_ids() {
+        let id: DayId = "2024/7".parse().unwrap();
+        assert_eq!(id, DayId::new(2024, 7));
+        assert_eq!(id.to_string(), "2024/07");
+        assert_eq!(id.crate_name().as_deref(), Some("y2024-seven"));
+        assert_eq!(id.dir(), Some(PathBuf::from("days/2024/seven")));
+        assert!("2024/26".parse::<DayId>().is_err());
+        assert!("17".parse::<DayId>().is_err());
+        assert!(DayId::new(2024, 25) < DayId::new(2025, 1));
+    }
+}